@@ -0,0 +1,48 @@
+use crate::looksyk::index::block_id::BLOCK_ID_LENGTH;
+
+pub fn generate_block_id() -> Result<String, getrandom::Error> {
+    let mut bytes = [0u8; BLOCK_ID_LENGTH / 2];
+    getrandom::getrandom(&mut bytes)?;
+    Ok(bytes.iter().map(|b| format!("{b:02x}")).collect())
+}
+
+/// Generates ids until one is found that is not taken yet.
+pub fn generate_unique_block_id(
+    is_taken: impl Fn(&str) -> bool,
+) -> Result<String, getrandom::Error> {
+    loop {
+        let block_id = generate_block_id()?;
+        if !is_taken(&block_id) {
+            return Ok(block_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::index::block_id::is_block_id;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_generate_block_id_should_be_64_bit_hex() {
+        let block_id = generate_block_id().unwrap();
+
+        assert_eq!(block_id.len(), 16);
+        assert!(is_block_id(&block_id));
+    }
+
+    #[test]
+    fn test_generate_unique_block_id_should_skip_taken_ids() {
+        let checked = Cell::new(0);
+
+        let block_id = generate_unique_block_id(|_| {
+            checked.set(checked.get() + 1);
+            checked.get() < 3
+        })
+        .unwrap();
+
+        assert_eq!(checked.get(), 3);
+        assert!(is_block_id(&block_id));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::io::fs::basic_file::exists_file;
+use crate::io::fs::config::{config_path, read_config_from_file};
+use crate::io::fs::pages::{read_all_journal_files, read_all_page_file_names, read_all_user_files};
+use crate::looksyk::health::{create_health_report, ALL_HEALTH_FINDING_TYPES};
+use crate::looksyk::index::block_properties::create_block_properties_index;
+use crate::looksyk::index::tag::create_tag_index;
+use crate::looksyk::index::userpage::{create_journal_page_index, create_user_page_index};
+use crate::looksyk::model::PageType;
+use crate::state::application_state::GraphRootLocation;
+use crate::state::encryption::EncryptionState;
+
+/// Checks the graph read-only. Encrypted pages can not be read and are reported as locked.
+pub fn check_graph_health(data_root_location: &GraphRootLocation) -> bool {
+    if !exists_file(config_path(data_root_location)) {
+        println!(
+            "No graph found at {}",
+            data_root_location.path.to_string_lossy()
+        );
+        return false;
+    }
+
+    let config = read_config_from_file(data_root_location);
+    let encryption_state = EncryptionState {
+        key: None,
+        encrypted_namespaces: config.encryption.namespaces.clone(),
+    };
+    let user_pages =
+        create_user_page_index(&read_all_user_files(data_root_location, &encryption_state));
+    let journal_pages = create_journal_page_index(&read_all_journal_files(
+        data_root_location,
+        &encryption_state,
+    ));
+    let health_report = create_health_report(
+        &user_pages,
+        &journal_pages,
+        &create_tag_index(&user_pages, &journal_pages),
+        &create_block_properties_index(&journal_pages, &user_pages, &config.property_schema),
+        &read_all_page_file_names(data_root_location),
+    );
+
+    for finding_type in ALL_HEALTH_FINDING_TYPES {
+        let findings = health_report.findings_of_type(finding_type);
+        println!("{} ({})", finding_type.title(), findings.len());
+        for finding in findings {
+            let page_id = &finding.reference.page_id;
+            let page_type = match page_id.page_type {
+                PageType::UserPage => "page",
+                PageType::JournalPage => "journal",
+            };
+            println!(
+                "  {page_type} {} (block {}): {}",
+                page_id.name.name,
+                finding.reference.block_number + 1,
+                finding.message
+            );
+        }
+    }
+
+    if health_report.is_healthy() {
+        println!("No problems found in the graph.");
+    }
+    health_report.is_healthy()
+}
@@ -10,4 +10,6 @@ pub struct CliArgsDto {
     pub static_path: Option<String>,
     #[arg(long, value_name = "external-app")]
     pub external_app: Option<bool>,
+    #[arg(long, value_name = "check")]
+    pub check: Option<bool>,
 }
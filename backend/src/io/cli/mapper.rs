@@ -7,5 +7,6 @@ pub fn map_to_io_cli(dto: CliArgsDto) -> CliArgs {
         port: dto.port,
         static_path: dto.static_path,
         external_app: dto.external_app,
+        check: dto.check,
     }
 }
@@ -1,3 +1,4 @@
+pub mod check;
 mod dtos;
 pub mod endpoints;
 mod mapper;
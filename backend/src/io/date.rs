@@ -1,8 +1,8 @@
 use crate::looksyk::model::SimplePageName;
 use crate::looksyk::renderer::title::DateType;
-#[cfg(test)]
-use chrono::Datelike;
-use chrono::{Local, NaiveDate};
+use chrono::{Datelike, Days, Local, Months, NaiveDate};
+
+const QUERY_DATE_FORMATS: [&str; 2] = ["%Y-%m-%d", "%Y_%m_%d"];
 
 pub fn today() -> TodayContainer {
     TodayContainer {
@@ -14,6 +14,101 @@ pub struct TodayContainer {
     today: NaiveDate,
 }
 
+impl TodayContainer {
+    pub fn date(&self) -> NaiveDate {
+        self.today
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum QueryDate {
+    Absolute(NaiveDate),
+    Today,
+    Offset(i32, DateUnit),
+    StartOf(DateUnit),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DateUnit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl QueryDate {
+    pub fn parse(value: &str) -> Option<QueryDate> {
+        let value = value.trim();
+        if let Some(date) = QUERY_DATE_FORMATS
+            .iter()
+            .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+        {
+            return Some(QueryDate::Absolute(date));
+        }
+        if value == "today" {
+            return Some(QueryDate::Today);
+        }
+        if let Some(unit) = value.strip_prefix("start-of-") {
+            return match unit {
+                "week" => Some(QueryDate::StartOf(DateUnit::Week)),
+                "month" => Some(QueryDate::StartOf(DateUnit::Month)),
+                "year" => Some(QueryDate::StartOf(DateUnit::Year)),
+                _ => None,
+            };
+        }
+        if !value.starts_with(['-', '+']) {
+            return None;
+        }
+        let (unit_index, unit) = value.char_indices().last()?;
+        let unit = match unit {
+            'd' => DateUnit::Day,
+            'w' => DateUnit::Week,
+            'm' => DateUnit::Month,
+            'y' => DateUnit::Year,
+            _ => return None,
+        };
+        let amount = &value[..unit_index];
+        amount
+            .parse::<i32>()
+            .ok()
+            .map(|amount| QueryDate::Offset(amount, unit))
+    }
+
+    pub fn resolve(&self, today: &TodayContainer) -> Option<NaiveDate> {
+        let today = today.today;
+        match self {
+            QueryDate::Absolute(date) => Some(*date),
+            QueryDate::Today | QueryDate::StartOf(DateUnit::Day) => Some(today),
+            QueryDate::StartOf(DateUnit::Week) => {
+                today.checked_sub_days(Days::new(today.weekday().num_days_from_monday() as u64))
+            }
+            QueryDate::StartOf(DateUnit::Month) => today.with_day(1),
+            QueryDate::StartOf(DateUnit::Year) => NaiveDate::from_ymd_opt(today.year(), 1, 1),
+            QueryDate::Offset(amount, unit) => {
+                let (days, months) = match unit {
+                    DateUnit::Day => (amount.unsigned_abs() as u64, 0),
+                    DateUnit::Week => (amount.unsigned_abs() as u64 * 7, 0),
+                    DateUnit::Month => (0, amount.unsigned_abs()),
+                    DateUnit::Year => (0, amount.unsigned_abs().checked_mul(12)?),
+                };
+                if *amount < 0 {
+                    today
+                        .checked_sub_days(Days::new(days))?
+                        .checked_sub_months(Months::new(months))
+                } else {
+                    today
+                        .checked_add_days(Days::new(days))?
+                        .checked_add_months(Months::new(months))
+                }
+            }
+        }
+    }
+}
+
+pub fn resolve_query_date(value: &str, today: &TodayContainer) -> Option<NaiveDate> {
+    QueryDate::parse(value)?.resolve(today)
+}
+
 pub fn calculate_journal_date_property(name: &SimplePageName, today: &TodayContainer) -> DateType {
     let splitted_date: Vec<&str> = name.name.split("_").collect();
     let year = splitted_date[0];
@@ -83,9 +178,12 @@ impl TodayContainer {
 #[cfg(test)]
 mod tests {
     use crate::io::date::builder::today;
-    use crate::io::date::calculate_journal_date_property;
+    use crate::io::date::{
+        calculate_journal_date_property, resolve_query_date, DateUnit, QueryDate,
+    };
     use crate::looksyk::model::SimplePageName;
     use crate::looksyk::renderer::title::DateType;
+    use chrono::NaiveDate;
 
     #[test]
     fn test_get_journal_date_property_for_today() {
@@ -133,4 +231,60 @@ mod tests {
             DateType::Other
         );
     }
+
+    #[test]
+    fn test_parse_query_date_should_accept_absolute_and_relative_dates() {
+        assert_eq!(
+            QueryDate::parse("2024-01-31"),
+            Some(QueryDate::Absolute(
+                NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()
+            ))
+        );
+        assert_eq!(QueryDate::parse("today"), Some(QueryDate::Today));
+        assert_eq!(
+            QueryDate::parse("-30d"),
+            Some(QueryDate::Offset(-30, DateUnit::Day))
+        );
+        assert_eq!(
+            QueryDate::parse("start-of-year"),
+            Some(QueryDate::StartOf(DateUnit::Year))
+        );
+        assert_eq!(QueryDate::parse("30d"), None);
+        assert_eq!(QueryDate::parse("-d"), None);
+        assert_eq!(QueryDate::parse("start-of-decade"), None);
+    }
+
+    #[test]
+    fn test_parse_query_date_should_reject_non_ascii_input() {
+        assert_eq!(QueryDate::parse("-3ä"), None);
+        assert_eq!(QueryDate::parse("-ä"), None);
+        assert_eq!(QueryDate::parse("+€d"), None);
+        assert_eq!(QueryDate::parse("-"), None);
+    }
+
+    #[test]
+    fn test_resolve_query_date_should_resolve_against_today() {
+        let today = today();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+
+        assert_eq!(resolve_query_date("today", &today), date(2024, 6, 15));
+        assert_eq!(resolve_query_date("-30d", &today), date(2024, 5, 16));
+        assert_eq!(resolve_query_date("+2w", &today), date(2024, 6, 29));
+        assert_eq!(resolve_query_date("-6m", &today), date(2023, 12, 15));
+        assert_eq!(resolve_query_date("-1y", &today), date(2023, 6, 15));
+        assert_eq!(
+            resolve_query_date("start-of-week", &today),
+            date(2024, 6, 10)
+        );
+        assert_eq!(
+            resolve_query_date("start-of-month", &today),
+            date(2024, 6, 1)
+        );
+        assert_eq!(
+            resolve_query_date("start-of-year", &today),
+            date(2024, 1, 1)
+        );
+        assert_eq!(resolve_query_date("2024_02_29", &today), date(2024, 2, 29));
+        assert_eq!(resolve_query_date("yesterday-ish", &today), None);
+    }
 }
@@ -0,0 +1,125 @@
+use crate::state::encryption::EncryptionKey;
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use pbkdf2::pbkdf2_hmac;
+use sha3::Sha3_256;
+
+pub const ENCRYPTED_PAGE_HEADER: &str = "looksyk-encrypted:v1";
+#[cfg(not(test))]
+const KEY_DERIVATION_ROUNDS: u32 = 100_000;
+#[cfg(test)]
+const KEY_DERIVATION_ROUNDS: u32 = 1_000;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+
+pub fn derive_key(passphrase: &str, salt: &str) -> EncryptionKey {
+    let mut bytes = [0u8; 32];
+    pbkdf2_hmac::<Sha3_256>(
+        passphrase.as_bytes(),
+        salt.as_bytes(),
+        KEY_DERIVATION_ROUNDS,
+        &mut bytes,
+    );
+    EncryptionKey { bytes }
+}
+
+pub fn generate_salt() -> String {
+    let mut salt = [0u8; SALT_LENGTH];
+    OsRng.fill_bytes(&mut salt);
+    to_hex(&salt)
+}
+
+pub fn is_encrypted(content: &str) -> bool {
+    content.starts_with(ENCRYPTED_PAGE_HEADER)
+}
+
+pub fn encrypt(plain_text: &str, key: &EncryptionKey) -> String {
+    let cipher = Aes256Gcm::new(&key.bytes.into());
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let cipher_text = cipher
+        .encrypt(&nonce, plain_text.as_bytes())
+        .expect("Failed to encrypt page");
+    format!(
+        "{}\n{}{}\n",
+        ENCRYPTED_PAGE_HEADER,
+        to_hex(nonce.as_slice()),
+        to_hex(&cipher_text)
+    )
+}
+
+pub fn decrypt(content: &str, key: &EncryptionKey) -> Option<String> {
+    let payload = from_hex(content.strip_prefix(ENCRYPTED_PAGE_HEADER)?.trim())?;
+    if payload.len() < NONCE_LENGTH {
+        return None;
+    }
+    let (nonce, cipher_text) = payload.split_at(NONCE_LENGTH);
+    let cipher = Aes256Gcm::new(&key.bytes.into());
+    let plain_text = cipher.decrypt(Nonce::from_slice(nonce), cipher_text).ok()?;
+    String::from_utf8(plain_text).ok()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut result = String::new();
+    bytes
+        .iter()
+        .for_each(|b| result.push_str(&format!("{b:02x}")));
+    result
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::encryption::builder::any_encryption_key;
+
+    #[test]
+    fn test_encrypt_and_decrypt_should_roundtrip() {
+        let key = any_encryption_key();
+
+        let encrypted = encrypt("- secret:: 1234\n", &key);
+
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.contains("secret"));
+        assert_eq!(decrypt(&encrypted, &key).unwrap(), "- secret:: 1234\n");
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_key_should_fail() {
+        let encrypted = encrypt("secret", &any_encryption_key());
+
+        let result = decrypt(&encrypted, &EncryptionKey { bytes: [1; 32] });
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_decrypt_plain_text_should_fail() {
+        assert!(decrypt("- plain text", &any_encryption_key()).is_none());
+    }
+
+    #[test]
+    fn test_derive_key_should_depend_on_salt() {
+        let key1 = derive_key("passphrase", "salt1");
+        let key2 = derive_key("passphrase", "salt2");
+
+        assert_eq!(key1.bytes, derive_key("passphrase", "salt1").bytes);
+        assert_ne!(key1.bytes, key2.bytes);
+    }
+
+    #[test]
+    fn test_hex_roundtrip() {
+        assert_eq!(from_hex(&to_hex(&[0, 15, 255])).unwrap(), vec![0, 15, 255]);
+        assert!(from_hex("abc").is_none());
+        assert!(from_hex("zz").is_none());
+    }
+}
@@ -1,9 +1,14 @@
-use crate::io::fs::basic_file::{is_text_file, read_file};
+use crate::io::fs::basic_file::{is_text_file, read_binary_file, read_file};
 use crate::io::fs::media::MediaState::Found;
 use crate::io::fs::media::{create_absolute_media_path, read_media_state, MediaOnDisk};
 use crate::looksyk::data::config::startup_configuration::MAX_INLINE_FILESIZE;
+use crate::looksyk::media::media_type::{get_media_type_from_extension, MediaType};
 use crate::state::application_state::GraphRootLocation;
-use crate::state::asset_cache::{AssetFileContent, AssetState, FileSizeViolation};
+use crate::state::asset_cache::{
+    AssetFileContent, AssetState, AssetTextState, AssetTextUnit, ExtractedAssetText,
+    FileSizeViolation,
+};
+use std::panic;
 
 pub fn load_cachable_asset(
     media_on_disk: &MediaOnDisk,
@@ -27,3 +32,45 @@ pub fn load_cachable_asset(
     }
     AssetState::NotFound
 }
+
+pub fn load_asset_text(
+    media_on_disk: &MediaOnDisk,
+    data_root_location: &GraphRootLocation,
+) -> AssetTextState {
+    let Found(file_info) = read_media_state(media_on_disk, data_root_location) else {
+        return AssetTextState::NotSearchable;
+    };
+    if file_info.size > MAX_INLINE_FILESIZE {
+        return AssetTextState::NotSearchable;
+    }
+
+    let abs_path = create_absolute_media_path(media_on_disk, data_root_location);
+    match get_media_type_from_extension(&media_on_disk.as_asset_descriptor()) {
+        MediaType::Text | MediaType::Code | MediaType::Table => {
+            if !is_text_file(abs_path.clone()) {
+                return AssetTextState::NotSearchable;
+            }
+            AssetTextState::Extracted(ExtractedAssetText {
+                unit: AssetTextUnit::Line,
+                segments: read_file(abs_path).lines().map(str::to_string).collect(),
+            })
+        }
+        MediaType::Pdf => extract_pdf_text(&read_binary_file(abs_path)),
+        _ => AssetTextState::NotSearchable,
+    }
+}
+
+fn extract_pdf_text(content: &[u8]) -> AssetTextState {
+    // the pdf parser panics on some malformed documents, those are treated as not searchable
+    let pages = panic::catch_unwind(|| pdf_extract::extract_text_from_mem_by_pages(content));
+    match pages {
+        Ok(Ok(pages)) => AssetTextState::Extracted(ExtractedAssetText {
+            unit: AssetTextUnit::Page,
+            segments: pages,
+        }),
+        _ => {
+            println!("could not extract text from pdf");
+            AssetTextState::NotSearchable
+        }
+    }
+}
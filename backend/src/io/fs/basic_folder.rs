@@ -1,4 +1,4 @@
-use dirs::{config_dir, home_dir};
+use dirs::{cache_dir, config_dir, home_dir};
 use std::path::PathBuf;
 
 pub fn home_directory() -> PathBuf {
@@ -17,6 +17,15 @@ pub fn config_directory() -> PathBuf {
     }
 }
 
+pub fn cache_directory() -> PathBuf {
+    let path = cache_dir();
+    if let Some(path) = path {
+        path.join("looksyk")
+    } else {
+        home_directory().join(".cache").join("looksyk")
+    }
+}
+
 pub fn documents_directory() -> PathBuf {
     let path = dirs::document_dir();
     if let Some(path) = path {
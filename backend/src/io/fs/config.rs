@@ -1,9 +1,13 @@
 use crate::io::fs::basic_file::read_file;
 use crate::io::fs::paths::REL_CONFIG_PATH;
 use crate::looksyk::data::config::runtime_graph_configuration::{
-    Appearance, Config, Design, Favourite, JournalConfigration, JournalTitleFormat,
-    ShowWeekdayInTitle,
+    Appearance, Config, Design, EncryptionConfiguration, Favourite, JournalConfigration,
+    JournalTitleFormat, ShowWeekdayInTitle,
 };
+use crate::looksyk::media::upload_policy::{
+    ImageTargetFormat, UploadPolicy, DEFAULT_UPLOAD_QUALITY,
+};
+use crate::looksyk::property_schema::{PropertyDefinition, PropertySchema, PropertyType};
 use crate::state::application_state::GraphRootLocation;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -15,6 +19,12 @@ pub struct ConfigOnDisk {
     pub design: DesignOnDisk,
     pub title: Option<String>,
     pub journal_configuration: JournalConfigrationOnDisk,
+    #[serde(default)]
+    pub encryption: EncryptionConfigurationOnDisk,
+    #[serde(default)]
+    pub upload_policy: UploadPolicyOnDisk,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub property_schema: Vec<PropertyDefinitionOnDisk>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -27,6 +37,33 @@ pub struct DesignOnDisk {
     pub appearance: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct EncryptionConfigurationOnDisk {
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+    pub salt: Option<String>,
+    pub verification: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadPolicyOnDisk {
+    pub max_dimension: Option<u32>,
+    pub target_format: Option<String>,
+    pub quality: Option<u8>,
+    #[serde(default)]
+    pub strip_metadata: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PropertyDefinitionOnDisk {
+    pub key: String,
+    #[serde(rename = "type")]
+    pub property_type: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct JournalConfigrationOnDisk {
     pub journal_title_format: String,
@@ -62,6 +99,50 @@ fn convert_config_on_disk_to_config(config_on_disk: ConfigOnDisk) -> Config {
             .expect("Failed to parse show weekday in title from config"),
         },
         title: config_on_disk.title,
+        encryption: EncryptionConfiguration {
+            namespaces: config_on_disk.encryption.namespaces,
+            salt: config_on_disk.encryption.salt,
+            verification: config_on_disk.encryption.verification,
+        },
+        upload_policy: UploadPolicy {
+            max_dimension: config_on_disk.upload_policy.max_dimension,
+            target_format: config_on_disk
+                .upload_policy
+                .target_format
+                .and_then(|format| match ImageTargetFormat::from_str(&format) {
+                    Ok(target_format) => Some(target_format),
+                    Err(error) => {
+                        println!("ignoring upload target format from config: {error}");
+                        None
+                    }
+                }),
+            quality: config_on_disk
+                .upload_policy
+                .quality
+                .unwrap_or(DEFAULT_UPLOAD_QUALITY),
+            strip_metadata: config_on_disk.upload_policy.strip_metadata,
+        },
+        property_schema: PropertySchema {
+            definitions: config_on_disk
+                .property_schema
+                .into_iter()
+                .filter_map(|definition| {
+                    match PropertyType::from_name(&definition.property_type, definition.values) {
+                        Ok(property_type) => Some(PropertyDefinition {
+                            key: definition.key,
+                            property_type,
+                        }),
+                        Err(error) => {
+                            println!(
+                                "ignoring property definition \"{}\" from config: {error}",
+                                definition.key
+                            );
+                            None
+                        }
+                    }
+                })
+                .collect(),
+        },
     }
 }
 
@@ -86,6 +167,31 @@ fn convert_config_to_on_disk(config: &Config) -> ConfigOnDisk {
                 .to_string(),
         },
         title: config.title.clone(),
+        encryption: EncryptionConfigurationOnDisk {
+            namespaces: config.encryption.namespaces.clone(),
+            salt: config.encryption.salt.clone(),
+            verification: config.encryption.verification.clone(),
+        },
+        upload_policy: UploadPolicyOnDisk {
+            max_dimension: config.upload_policy.max_dimension,
+            target_format: config
+                .upload_policy
+                .target_format
+                .as_ref()
+                .map(|format| format.to_string()),
+            quality: Some(config.upload_policy.quality),
+            strip_metadata: config.upload_policy.strip_metadata,
+        },
+        property_schema: config
+            .property_schema
+            .definitions
+            .iter()
+            .map(|definition| PropertyDefinitionOnDisk {
+                key: definition.key.clone(),
+                property_type: definition.property_type.to_string(),
+                values: definition.property_type.allowed_values(),
+            })
+            .collect(),
     }
 }
 
@@ -104,8 +210,12 @@ pub fn config_path(data_path: &GraphRootLocation) -> PathBuf {
 mod tests {
     use crate::io::fs::config::convert_config_to_on_disk;
     use crate::looksyk::data::config::runtime_graph_configuration::{
-        Appearance, Config, Design, JournalConfigration, JournalTitleFormat, ShowWeekdayInTitle,
+        Appearance, Config, Design, EncryptionConfiguration, JournalConfigration,
+        JournalTitleFormat, ShowWeekdayInTitle,
     };
+    use crate::looksyk::media::upload_policy::{ImageTargetFormat, UploadPolicy};
+    use crate::looksyk::property_schema::builder::property_schema_with;
+    use crate::looksyk::property_schema::PropertyType;
 
     #[test]
     fn test_convert_config_to_on_disk() {
@@ -123,6 +233,21 @@ mod tests {
                 journal_title_format: JournalTitleFormat::World,
                 show_weekday_in_title: ShowWeekdayInTitle::AsPrefix,
             },
+            encryption: EncryptionConfiguration {
+                namespaces: vec!["hr".to_string()],
+                salt: None,
+                verification: None,
+            },
+            upload_policy: UploadPolicy {
+                max_dimension: Some(1920),
+                target_format: Some(ImageTargetFormat::Png),
+                quality: 80,
+                strip_metadata: true,
+            },
+            property_schema: property_schema_with(
+                "status",
+                PropertyType::Enum(vec!["todo".to_string(), "done".to_string()]),
+            ),
         });
 
         assert_eq!(result.journal_configuration.journal_title_format, "world");
@@ -131,6 +256,15 @@ mod tests {
             "as_prefix"
         );
         assert_eq!(result.title, Some("My Graph".to_string()));
+        assert_eq!(result.encryption.namespaces, vec!["hr".to_string()]);
+        assert_eq!(result.upload_policy.max_dimension, Some(1920));
+        assert_eq!(result.upload_policy.target_format, Some("png".to_string()));
+        assert_eq!(result.upload_policy.quality, Some(80));
+        assert!(result.upload_policy.strip_metadata);
+        assert_eq!(result.property_schema.len(), 1);
+        assert_eq!(result.property_schema[0].key, "status");
+        assert_eq!(result.property_schema[0].property_type, "enum");
+        assert_eq!(result.property_schema[0].values, vec!["todo", "done"]);
         assert_eq!(result.design.appearance, "light");
         assert_eq!(result.design.primary_color, "blue".to_string());
         assert_eq!(result.design.foreground_color, "black".to_string());
@@ -154,6 +288,20 @@ mod tests {
                 journal_title_format: "world".to_string(),
                 show_weekday_in_title: "as_prefix".to_string(),
             },
+            encryption: super::EncryptionConfigurationOnDisk::default(),
+            upload_policy: super::UploadPolicyOnDisk::default(),
+            property_schema: vec![
+                super::PropertyDefinitionOnDisk {
+                    key: "hours".to_string(),
+                    property_type: "number".to_string(),
+                    values: vec![],
+                },
+                super::PropertyDefinitionOnDisk {
+                    key: "count".to_string(),
+                    property_type: "integer".to_string(),
+                    values: vec![],
+                },
+            ],
         });
 
         assert_eq!(
@@ -165,6 +313,11 @@ mod tests {
             ShowWeekdayInTitle::AsPrefix
         );
         assert_eq!(result.title, Some("My Graph".to_string()));
+        assert_eq!(result.upload_policy, UploadPolicy::default());
+        assert_eq!(
+            result.property_schema,
+            property_schema_with("hours", PropertyType::Number)
+        );
         assert_eq!(result.design.appearance, Appearance::Light);
         assert_eq!(result.design.primary_color, "blue".to_string());
         assert_eq!(result.design.foreground_color, "black".to_string());
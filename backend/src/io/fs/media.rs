@@ -5,7 +5,7 @@ use actix_files::NamedFile;
 use chrono::Utc;
 
 use crate::io::fs::basic_file::{exists_file, get_file_size, read_binary_file, read_file};
-use crate::io::fs::paths::{REL_MEDIA_CONFIG_PATH, REL_MEDIA_LOCATION};
+use crate::io::fs::paths::{REL_MEDIA_CONFIG_PATH, REL_MEDIA_LOCATION, REL_TRASH_LOCATION};
 use crate::io::hash::hash_file_content;
 use crate::looksyk::datatypes::AssetDescriptor;
 use crate::looksyk::index::media::{find_file, IndexedMedia, MediaIndex};
@@ -118,6 +118,46 @@ pub fn create_absolute_media_path(
         .join(file.name.clone())
 }
 
+pub fn move_media_to_trash(
+    file: &MediaOnDisk,
+    data_root_location: &GraphRootLocation,
+) -> std::io::Result<()> {
+    let trash_folder = data_root_location.path.clone().join(REL_TRASH_LOCATION);
+    std::fs::create_dir_all(&trash_folder)?;
+    let trash_name = unique_trash_name(&file.name, |name| trash_folder.join(name).exists());
+    println!("moving {} to trash as {}", file.name, trash_name);
+    std::fs::rename(
+        create_absolute_media_path(file, data_root_location),
+        trash_folder.join(trash_name),
+    )
+}
+
+fn unique_trash_name(file_name: &str, exists: impl Fn(&str) -> bool) -> String {
+    if !exists(file_name) {
+        return file_name.to_string();
+    }
+    let parsed_file_name = parse_name(file_name);
+    (1..)
+        .map(|counter| match parsed_file_name.file_ending.as_str() {
+            "" => format!("{}_{}", parsed_file_name.filestem, counter),
+            file_ending => format!("{}_{}.{}", parsed_file_name.filestem, counter, file_ending),
+        })
+        .find(|candidate| !exists(candidate))
+        .unwrap()
+}
+
+pub fn rename_media(
+    old_file: &MediaOnDisk,
+    new_file: &MediaOnDisk,
+    data_root_location: &GraphRootLocation,
+) -> std::io::Result<()> {
+    println!("renaming media {} to {}", old_file.name, new_file.name);
+    std::fs::rename(
+        create_absolute_media_path(old_file, data_root_location),
+        create_absolute_media_path(new_file, data_root_location),
+    )
+}
+
 pub fn create_hash(file: MediaOnDisk, data_root_location: &GraphRootLocation) -> String {
     let file_conent = read_binary_file(create_absolute_media_path(&file, data_root_location));
     hash_file_content(LoadedMedia {
@@ -209,7 +249,7 @@ pub struct ParsedFilenName {
 
 #[cfg(test)]
 mod tests {
-    use crate::io::fs::media::{escape_stem, parse_name};
+    use crate::io::fs::media::{escape_stem, parse_name, unique_trash_name};
 
     #[test]
     fn test_parse_name_with_extension() {
@@ -225,6 +265,24 @@ mod tests {
         assert_eq!(parsed_file_name.file_ending, "");
     }
 
+    #[test]
+    fn test_unique_trash_name_should_keep_name_if_free() {
+        assert_eq!(unique_trash_name("photo.png", |_| false), "photo.png");
+    }
+
+    #[test]
+    fn test_unique_trash_name_should_add_counter_if_taken() {
+        let taken = ["photo.png", "photo_1.png", "notes"];
+        assert_eq!(
+            unique_trash_name("photo.png", |name| taken.contains(&name)),
+            "photo_2.png"
+        );
+        assert_eq!(
+            unique_trash_name("notes", |name| taken.contains(&name)),
+            "notes_1"
+        );
+    }
+
     #[test]
     fn test_escape_filestem() {
         assert_eq!(escape_stem(parse_name("test[1].txt")).filestem, "test_1_");
@@ -7,4 +7,5 @@ pub mod media;
 pub mod pages;
 pub mod paths;
 pub mod root_path;
+pub mod thumbnail;
 pub mod version;
@@ -1,17 +1,25 @@
+use std::fmt::Display;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use crate::io::encryption::{decrypt, encrypt, is_encrypted};
 use crate::io::fs::basic_file::{delete_file, read_file};
 use crate::io::fs::paths::path_for_page_type;
-use crate::looksyk::model::{PageType, SimplePageName};
+use crate::looksyk::builder::page_name;
+use crate::looksyk::encryption::should_encrypt_page;
+use crate::looksyk::model::{PageId, PageType, SimplePageName};
 use crate::state::application_state::GraphRootLocation;
+use crate::state::encryption::EncryptionState;
 
-pub fn read_all_user_files(data_root_location: &GraphRootLocation) -> Vec<PageOnDisk> {
+pub fn read_all_user_files(
+    data_root_location: &GraphRootLocation,
+    encryption_state: &EncryptionState,
+) -> Vec<PageOnDisk> {
     let page_path = data_root_location
         .path
         .clone()
         .join(path_for_page_type(&PageType::UserPage));
-    read_all_files(page_path.to_str().unwrap())
+    read_all_files(page_path.to_str().unwrap(), encryption_state)
 }
 
 pub fn delete_user_file(data_root_location: &GraphRootLocation, simple_page_name: SimplePageName) {
@@ -25,15 +33,18 @@ pub fn delete_user_file(data_root_location: &GraphRootLocation, simple_page_name
     delete_file(destination);
 }
 
-pub fn read_all_journal_files(data_root_location: &GraphRootLocation) -> Vec<PageOnDisk> {
+pub fn read_all_journal_files(
+    data_root_location: &GraphRootLocation,
+    encryption_state: &EncryptionState,
+) -> Vec<PageOnDisk> {
     let journal_path = data_root_location
         .path
         .clone()
         .join(path_for_page_type(&PageType::JournalPage));
-    read_all_files(journal_path.to_str().unwrap())
+    read_all_files(journal_path.to_str().unwrap(), encryption_state)
 }
 
-fn read_all_files(data_path: &str) -> Vec<PageOnDisk> {
+fn read_all_files(data_path: &str, encryption_state: &EncryptionState) -> Vec<PageOnDisk> {
     println!("Reading {data_path}");
     let directory_list = fs::read_dir(data_path).unwrap();
     let mut all_files = vec![];
@@ -56,7 +67,13 @@ fn read_all_files(data_path: &str) -> Vec<PageOnDisk> {
                 continue;
             }
 
-            let file_content = read_file(path.clone());
+            let file_content = match decrypt_if_needed(read_file(path.clone()), encryption_state) {
+                Some(content) => content,
+                None => {
+                    println!("Skipping encrypted file while graph is locked: {path:?}");
+                    continue;
+                }
+            };
             let file_stem = Path::file_stem(path.as_path()).unwrap().to_str().unwrap();
             all_files.push(PageOnDisk {
                 name: unescape_page_name(file_stem.to_string().as_str()),
@@ -71,17 +88,133 @@ fn read_all_files(data_path: &str) -> Vec<PageOnDisk> {
     all_files
 }
 
-pub fn write_page(page: PageOnDisk, data_path: &GraphRootLocation, page_type: &PageType) {
-    let page_name = page.name;
-    let encoded_page_name = escape_page_name(&page_name);
-    let destination = data_path
+pub fn read_all_page_file_names(data_root_location: &GraphRootLocation) -> Vec<PageFileName> {
+    let mut result = vec![];
+    for page_type in [PageType::UserPage, PageType::JournalPage] {
+        let page_path = data_root_location
+            .path
+            .clone()
+            .join(path_for_page_type(&page_type));
+        let Ok(directory_list) = fs::read_dir(page_path) else {
+            continue;
+        };
+        for dir in directory_list.flatten() {
+            let file_name = dir.file_name().to_string_lossy().to_string();
+            if dir.path().is_dir() || !file_name.ends_with(".md") {
+                continue;
+            }
+            let file_stem = file_name.trim_end_matches(".md");
+            result.push(PageFileName {
+                page_id: page_name(unescape_page_name(file_stem)).as_page_id(&page_type),
+                file_name,
+            });
+        }
+    }
+    result
+}
+
+fn decrypt_if_needed(file_content: String, encryption_state: &EncryptionState) -> Option<String> {
+    if !is_encrypted(&file_content) {
+        return Some(file_content);
+    }
+    decrypt(&file_content, encryption_state.key.as_ref()?)
+}
+
+pub fn write_page(
+    page: PageOnDisk,
+    data_path: &GraphRootLocation,
+    page_type: &PageType,
+    encryption_state: &EncryptionState,
+) -> Result<(), PageLockedError> {
+    let should_encrypt = check_page_writable(&page, data_path, page_type, encryption_state)?;
+    let destination = page_destination(&page.name, data_path, page_type);
+    let content_with_newline = format!("{}\n", page.content);
+
+    println!("writing to {}", destination.to_str().unwrap());
+    match (&encryption_state.key, should_encrypt) {
+        (Some(key), true) => fs::write(destination, encrypt(&content_with_newline, key)).unwrap(),
+        _ => fs::write(destination, content_with_newline).unwrap(),
+    }
+    Ok(())
+}
+
+/// Fails if the page is or would be encrypted while the graph is locked, returns whether the
+/// page has to be encrypted otherwise. Nothing is written to disk.
+pub fn check_page_writable(
+    page: &PageOnDisk,
+    data_path: &GraphRootLocation,
+    page_type: &PageType,
+    encryption_state: &EncryptionState,
+) -> Result<bool, PageLockedError> {
+    let destination = page_destination(&page.name, data_path, page_type);
+    let content_with_newline = format!("{}\n", page.content);
+
+    let encrypted_on_disk = destination.exists() && is_encrypted(&read_file(destination.clone()));
+    let should_encrypt = should_encrypt_page(
+        &page.name,
+        page_type,
+        &content_with_newline,
+        encryption_state,
+    );
+    if (encrypted_on_disk || should_encrypt) && encryption_state.is_locked() {
+        println!(
+            "refusing to write {} while graph is locked",
+            destination.to_str().unwrap()
+        );
+        return Err(PageLockedError {
+            page_name: page.name.clone(),
+        });
+    }
+    Ok(should_encrypt)
+}
+
+fn page_destination(
+    page_name: &str,
+    data_path: &GraphRootLocation,
+    page_type: &PageType,
+) -> PathBuf {
+    data_path
         .path
         .clone()
         .join(path_for_page_type(page_type))
-        .join(encoded_page_name + ".md");
-    println!("writing to {}", destination.to_str().unwrap());
-    let content_with_newline = format!("{}\n", page.content);
-    fs::write(destination, content_with_newline).unwrap();
+        .join(escape_page_name(page_name) + ".md")
+}
+
+pub fn encrypt_pending_pages(
+    data_root_location: &GraphRootLocation,
+    encryption_state: &EncryptionState,
+) -> Vec<PageId> {
+    let mut encrypted_pages = vec![];
+    for page_type in [PageType::UserPage, PageType::JournalPage] {
+        let page_path = data_root_location
+            .path
+            .clone()
+            .join(path_for_page_type(&page_type));
+        for page in read_all_files(page_path.to_str().unwrap(), &locked(encryption_state)) {
+            if should_encrypt_page(&page.name, &page_type, &page.content, encryption_state) {
+                let page_id = page_name(page.name.clone()).as_page_id(&page_type);
+                write_page(
+                    PageOnDisk {
+                        name: page.name,
+                        content: page.content.trim_end_matches('\n').to_string(),
+                    },
+                    data_root_location,
+                    &page_type,
+                    encryption_state,
+                )
+                .unwrap();
+                encrypted_pages.push(page_id);
+            }
+        }
+    }
+    encrypted_pages
+}
+
+fn locked(encryption_state: &EncryptionState) -> EncryptionState {
+    EncryptionState {
+        key: None,
+        encrypted_namespaces: encryption_state.encrypted_namespaces.clone(),
+    }
 }
 
 fn escape_page_name(page_name: &str) -> String {
@@ -96,3 +229,23 @@ pub struct PageOnDisk {
     pub name: String,
     pub content: String,
 }
+
+pub struct PageFileName {
+    pub file_name: String,
+    pub page_id: PageId,
+}
+
+#[derive(Debug)]
+pub struct PageLockedError {
+    pub page_name: String,
+}
+
+impl Display for PageLockedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Page {} is encrypted, unlock the graph first",
+            self.page_name
+        )
+    }
+}
@@ -6,6 +6,7 @@ pub const REL_CONFIG_PATH: &str = "config/config.json";
 pub const REL_USER_PAGE_LOCATION: &str = "pages/";
 pub const REL_JOURNAL_PAGE_LOCATION: &str = "journals/";
 pub const REL_MEDIA_LOCATION: &str = "assets/";
+pub const REL_TRASH_LOCATION: &str = "trash/";
 pub const VERSION_FILE_NAME: &str = "version.txt";
 
 pub fn path_for_page_type(page_type: &PageType) -> &'static str {
@@ -0,0 +1,59 @@
+use crate::io::fs::basic_file::{create_folder, exists_file};
+use crate::io::fs::basic_folder::cache_directory;
+use crate::io::fs::media::{create_absolute_media_path, MediaOnDisk};
+use crate::looksyk::media::thumbnail::thumbnail_file_name;
+use crate::state::application_state::GraphRootLocation;
+use std::fs::{remove_file, rename};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static TEMPORARY_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub fn load_or_create_thumbnail(
+    media: &MediaOnDisk,
+    sha3: &str,
+    size: u32,
+    data_root_location: &GraphRootLocation,
+) -> Option<PathBuf> {
+    let thumbnail_folder = cache_directory().join("thumbnails");
+    let thumbnail_path = thumbnail_folder.join(thumbnail_file_name(sha3, size));
+    if exists_file(thumbnail_path.clone()) {
+        return Some(thumbnail_path);
+    }
+
+    let source_path = create_absolute_media_path(media, data_root_location);
+    println!(
+        "generating thumbnail for {} with size {}",
+        source_path.to_str().unwrap(),
+        size
+    );
+    let image = match image::open(&source_path) {
+        Ok(image) => image,
+        Err(error) => {
+            println!("unable to generate thumbnail: {error}");
+            return None;
+        }
+    };
+    create_folder(thumbnail_folder.clone());
+    // Concurrent requests for the same image each write their own file, the rename is atomic
+    let temporary_path = thumbnail_folder.join(format!(
+        "{}.{}-{}.tmp",
+        thumbnail_file_name(sha3, size),
+        std::process::id(),
+        TEMPORARY_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    if let Err(error) = image
+        .thumbnail(size, size)
+        .save_with_format(&temporary_path, image::ImageFormat::Png)
+    {
+        println!("unable to write thumbnail: {error}");
+        let _ = remove_file(&temporary_path);
+        return None;
+    }
+    if let Err(error) = rename(&temporary_path, &thumbnail_path) {
+        println!("unable to write thumbnail: {error}");
+        let _ = remove_file(&temporary_path);
+        return None;
+    }
+    Some(thumbnail_path)
+}
@@ -1,12 +1,59 @@
-use crate::state::application_state::AppState;
-use actix_web::web::Data;
-use actix_web::{get, web, Responder};
+use crate::io::fs::pages::{write_page, PageOnDisk};
+use crate::looksyk::index::index_operations::update_index_for_file;
+use crate::looksyk::index::property_rename::{rename_property_across_all_files, PropertyRename};
+use crate::looksyk::model::PageType;
+use crate::looksyk::property_statistics::{property_value_statistics, PropertyValueStatistic};
+use crate::looksyk::serializer::serialize_page;
+use crate::state::application_state::{
+    AppState, CurrentPageAssociatedState, CurrentPageOnDiskState,
+};
+use crate::state::block_properties::{BlockPropertyKey, BlockPropertyValue};
+use crate::sync::io::sync_application_port::{document_change, GraphChange, GraphChangesState};
+use actix_web::web::{Data, Json, Path, Query};
+use actix_web::{error, get, post, web, Responder};
 
 #[derive(serde::Serialize)]
 pub struct BlockPropertiesDto {
     pub properties: Vec<String>,
 }
 
+#[derive(serde::Deserialize)]
+pub struct PropertyValuesQueryDto {
+    pub prefix: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+pub struct PropertyValuesDto {
+    pub key: String,
+    pub values: Vec<PropertyValueDto>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyValueDto {
+    pub value: String,
+    pub count: usize,
+    pub journal_pages: Vec<String>,
+    pub user_pages: Vec<String>,
+    /// Only journal pages carry a date, values used on user pages only have none.
+    pub last_used_in_journal: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameBlockPropertyRequestDto {
+    pub key: String,
+    pub new_key: Option<String>,
+    pub value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameBlockPropertyResultDto {
+    pub changed_pages: usize,
+}
+
 #[get("/api/block_properties/")]
 async fn get_block_properties(data: Data<AppState>) -> actix_web::Result<impl Responder> {
     let block_properties_guard = data.h_block_properties.lock().unwrap();
@@ -18,3 +65,156 @@ async fn get_block_properties(data: Data<AppState>) -> actix_web::Result<impl Re
 
     Ok(web::Json(BlockPropertiesDto { properties }))
 }
+
+#[get("/api/block_properties/{key}/values")]
+async fn get_block_property_values(
+    key: Path<String>,
+    query: Query<PropertyValuesQueryDto>,
+    data: Data<AppState>,
+) -> actix_web::Result<impl Responder> {
+    let key = BlockPropertyKey {
+        value: key.into_inner(),
+    };
+    let block_properties_guard = data.h_block_properties.lock().unwrap();
+    let values = property_value_statistics(
+        &block_properties_guard,
+        &key,
+        query.prefix.as_deref().unwrap_or_default(),
+    )
+    .into_iter()
+    .map(map_to_property_value_dto)
+    .collect();
+
+    Ok(Json(PropertyValuesDto {
+        key: key.value,
+        values,
+    }))
+}
+
+#[post("/api/block_properties/rename")]
+async fn rename_block_property(
+    body: Json<RenameBlockPropertyRequestDto>,
+    data: Data<AppState>,
+    graph_changes: Data<GraphChangesState>,
+) -> actix_web::Result<impl Responder> {
+    let rename = map_to_property_rename(body.into_inner());
+    rename.validate().map_err(error::ErrorBadRequest)?;
+
+    let encryption_in_use = data.g_config.lock().unwrap().encryption.salt.is_some();
+    if encryption_in_use && data.j_encryption.lock().unwrap().is_locked() {
+        return Err(error::ErrorLocked(
+            "unlock the graph first, encrypted pages may contain the property",
+        ));
+    }
+
+    let mut page_guard = data.a_user_pages.lock().unwrap();
+    let mut journal_guard = data.b_journal_pages.lock().unwrap();
+    let mut todo_guard = data.c_todo_index.lock().unwrap();
+    let mut tag_guard = data.d_tag_index.lock().unwrap();
+    let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
+
+    let rename_result = rename_property_across_all_files(
+        &rename,
+        &block_properties_guard,
+        CurrentPageOnDiskState {
+            user_pages: &page_guard,
+            journal_pages: &journal_guard,
+        },
+    );
+
+    *page_guard = rename_result.new_page_associated_state.user_pages;
+    *journal_guard = rename_result.new_page_associated_state.journal_pages;
+
+    let changed_pages = rename_result.file_changes.changed_files.len();
+    for file_to_save in rename_result.file_changes.changed_files {
+        let page = match file_to_save.page_type {
+            PageType::UserPage => page_guard.find(&file_to_save.name).unwrap(),
+            PageType::JournalPage => journal_guard.find(&file_to_save.name).unwrap(),
+        };
+
+        let current_page_associated_state = CurrentPageAssociatedState {
+            user_pages: &page_guard,
+            journal_pages: &journal_guard,
+            todo_index: &todo_guard,
+            tag_index: &tag_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+        };
+
+        write_page(
+            PageOnDisk {
+                name: file_to_save.name.name.clone(),
+                content: serialize_page(page).join("\n"),
+            },
+            &data.data_path,
+            &file_to_save.page_type,
+            &data.j_encryption.lock().unwrap(),
+        )?;
+
+        let new_page_associated_state =
+            update_index_for_file(file_to_save, page, current_page_associated_state);
+
+        *todo_guard = new_page_associated_state.todo_index;
+        *tag_guard = new_page_associated_state.tag_index;
+        *page_guard = new_page_associated_state.user_pages;
+        *journal_guard = new_page_associated_state.journal_pages;
+        *block_properties_guard = new_page_associated_state.block_properties_index;
+        *page_properties_guard = new_page_associated_state.page_properties_index;
+    }
+
+    drop(page_guard);
+    drop(journal_guard);
+    drop(todo_guard);
+    drop(tag_guard);
+    drop(block_properties_guard);
+    drop(page_properties_guard);
+
+    if changed_pages > 0 {
+        document_change(
+            graph_changes,
+            GraphChange::property_renamed(
+                rename.describe_old_property(),
+                rename.describe_new_property(),
+            ),
+        );
+    }
+
+    Ok(Json(RenameBlockPropertyResultDto { changed_pages }))
+}
+
+fn map_to_property_rename(dto: RenameBlockPropertyRequestDto) -> PropertyRename {
+    let old_key = BlockPropertyKey {
+        value: dto.key.trim().to_string(),
+    };
+    PropertyRename {
+        new_key: dto
+            .new_key
+            .map(|key| BlockPropertyKey {
+                value: key.trim().to_string(),
+            })
+            .unwrap_or_else(|| old_key.clone()),
+        old_key,
+        old_value: dto.value.map(|value| BlockPropertyValue { value }),
+        new_value: dto.new_value.map(|value| BlockPropertyValue { value }),
+    }
+}
+
+fn map_to_property_value_dto(statistic: PropertyValueStatistic) -> PropertyValueDto {
+    let (journal_pages, user_pages): (Vec<_>, Vec<_>) = statistic
+        .pages
+        .into_iter()
+        .partition(|page_id| page_id.page_type == PageType::JournalPage);
+    PropertyValueDto {
+        value: statistic.value,
+        count: statistic.occurences,
+        journal_pages: journal_pages
+            .into_iter()
+            .map(|page| page.name.name)
+            .collect(),
+        user_pages: user_pages.into_iter().map(|page| page.name.name).collect(),
+        last_used_in_journal: statistic
+            .last_used
+            .map(|date| date.format("%Y-%m-%d").to_string()),
+    }
+}
@@ -6,3 +6,27 @@ pub struct JournalConfigurationDto {
     pub journal_title_format: String,
     pub show_weekday_in_title: String,
 }
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadPolicyDto {
+    pub max_dimension: Option<u32>,
+    pub target_format: Option<String>,
+    pub quality: u8,
+    pub strip_metadata: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertySchemaDto {
+    pub definitions: Vec<PropertyDefinitionDto>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyDefinitionDto {
+    pub key: String,
+    pub property_type: String,
+    #[serde(default)]
+    pub values: Vec<String>,
+}
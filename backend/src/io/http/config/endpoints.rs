@@ -1,6 +1,9 @@
 use crate::io::fs::config::save_config_to_file;
-use crate::io::http::config::dtos::JournalConfigurationDto;
+use crate::io::http::config::dtos::{JournalConfigurationDto, PropertySchemaDto, UploadPolicyDto};
+use crate::looksyk::property_schema::PropertySchema;
 use crate::state::application_state::AppState;
+use crate::sync::io::sync_application_port::{document_change, GraphChange, GraphChangesState};
+use actix_web::error::ErrorBadRequest;
 use actix_web::web::Data;
 use actix_web::{get, post, web, Responder};
 
@@ -24,3 +27,62 @@ async fn set_journal_title_format(
     let dto: JournalConfigurationDto = (&config.journal_configuration).into();
     Ok(web::Json(dto))
 }
+
+#[get("/api/config/upload")]
+async fn get_upload_policy(data: Data<AppState>) -> actix_web::Result<impl Responder> {
+    let dto: UploadPolicyDto = (&data.g_config.lock().unwrap().upload_policy).into();
+    Ok(web::Json(dto))
+}
+
+#[post("/api/config/upload")]
+async fn set_upload_policy(
+    data: Data<AppState>,
+    new_policy: web::Json<UploadPolicyDto>,
+    graph_changes: Data<GraphChangesState>,
+) -> actix_web::Result<impl Responder> {
+    let mut config = data.g_config.lock().unwrap();
+
+    config.upload_policy = new_policy.into_inner().into();
+    save_config_to_file(&data.data_path, &config);
+
+    let dto: UploadPolicyDto = (&config.upload_policy).into();
+    drop(config);
+
+    document_change(
+        graph_changes,
+        GraphChange::configuration_changed("upload policy".to_string()),
+    );
+    Ok(web::Json(dto))
+}
+
+#[get("/api/config/property-schema")]
+async fn get_property_schema(data: Data<AppState>) -> actix_web::Result<impl Responder> {
+    let dto: PropertySchemaDto = (&data.g_config.lock().unwrap().property_schema).into();
+    Ok(web::Json(dto))
+}
+
+#[post("/api/config/property-schema")]
+async fn set_property_schema(
+    data: Data<AppState>,
+    new_schema: web::Json<PropertySchemaDto>,
+    graph_changes: Data<GraphChangesState>,
+) -> actix_web::Result<impl Responder> {
+    let property_schema =
+        PropertySchema::try_from(new_schema.into_inner()).map_err(ErrorBadRequest)?;
+
+    let mut config = data.g_config.lock().unwrap();
+    let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    config.property_schema = property_schema;
+    save_config_to_file(&data.data_path, &config);
+    block_properties_guard.apply_schema(config.property_schema.clone());
+
+    let dto: PropertySchemaDto = (&config.property_schema).into();
+    drop(config);
+    drop(block_properties_guard);
+
+    document_change(
+        graph_changes,
+        GraphChange::configuration_changed("property schema".to_string()),
+    );
+    Ok(web::Json(dto))
+}
@@ -1,7 +1,11 @@
-use crate::io::http::config::dtos::JournalConfigurationDto;
+use crate::io::http::config::dtos::{
+    JournalConfigurationDto, PropertyDefinitionDto, PropertySchemaDto, UploadPolicyDto,
+};
 use crate::looksyk::data::config::runtime_graph_configuration::{
     JournalConfigration, JournalTitleFormat, ShowWeekdayInTitle,
 };
+use crate::looksyk::media::upload_policy::{ImageTargetFormat, UploadPolicy};
+use crate::looksyk::property_schema::{PropertyDefinition, PropertySchema, PropertyType};
 use std::str::FromStr;
 
 impl From<&JournalConfigration> for JournalConfigurationDto {
@@ -23,3 +27,61 @@ impl From<JournalConfigurationDto> for JournalConfigration {
         }
     }
 }
+
+impl From<&UploadPolicy> for UploadPolicyDto {
+    fn from(val: &UploadPolicy) -> Self {
+        UploadPolicyDto {
+            max_dimension: val.max_dimension,
+            target_format: val.target_format.as_ref().map(|format| format.to_string()),
+            quality: val.quality,
+            strip_metadata: val.strip_metadata,
+        }
+    }
+}
+
+impl From<UploadPolicyDto> for UploadPolicy {
+    fn from(val: UploadPolicyDto) -> Self {
+        UploadPolicy {
+            max_dimension: val.max_dimension,
+            target_format: val
+                .target_format
+                .and_then(|format| ImageTargetFormat::from_str(&format).ok()),
+            quality: val.quality.clamp(1, 100),
+            strip_metadata: val.strip_metadata,
+        }
+    }
+}
+
+impl From<&PropertySchema> for PropertySchemaDto {
+    fn from(val: &PropertySchema) -> Self {
+        PropertySchemaDto {
+            definitions: val
+                .definitions
+                .iter()
+                .map(|definition| PropertyDefinitionDto {
+                    key: definition.key.clone(),
+                    property_type: definition.property_type.to_string(),
+                    values: definition.property_type.allowed_values(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<PropertySchemaDto> for PropertySchema {
+    type Error = String;
+
+    fn try_from(val: PropertySchemaDto) -> Result<Self, Self::Error> {
+        let mut definitions = vec![];
+        for definition in val.definitions {
+            definitions.push(PropertyDefinition {
+                property_type: PropertyType::from_name(
+                    &definition.property_type,
+                    definition.values,
+                )?,
+                key: definition.key,
+            });
+        }
+        Ok(PropertySchema { definitions })
+    }
+}
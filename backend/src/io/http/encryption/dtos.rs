@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct UnlockRequestDto {
+    pub passphrase: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptionStatusDto {
+    pub locked: bool,
+    pub encrypted_namespaces: Vec<String>,
+}
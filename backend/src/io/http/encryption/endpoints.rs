@@ -0,0 +1,76 @@
+use crate::io::fs::config::save_config_to_file;
+use crate::io::fs::pages::encrypt_pending_pages;
+use crate::io::http::encryption::dtos::{EncryptionStatusDto, UnlockRequestDto};
+use crate::io::http::state::endpoints::refresh_internal_state;
+use crate::looksyk::encryption::{unlock, UnlockResult};
+use crate::looksyk::model::PageType;
+use crate::state::application_state::AppState;
+use crate::sync::io::sync_application_port::{document_change, GraphChange, GraphChangesState};
+use actix_web::error::{ErrorConflict, ErrorForbidden};
+use actix_web::web::Data;
+use actix_web::{get, post, web, Responder};
+
+#[get("/api/encryption")]
+async fn get_encryption_status(data: Data<AppState>) -> actix_web::Result<impl Responder> {
+    Ok(web::Json(status(&data)))
+}
+
+#[post("/api/encryption/unlock")]
+async fn unlock_graph(
+    body: web::Json<UnlockRequestDto>,
+    data: Data<AppState>,
+    graph_changes: Data<GraphChangesState>,
+) -> actix_web::Result<impl Responder> {
+    let encryption_configuration = data.g_config.lock().unwrap().encryption.clone();
+    let passphrase = body.into_inner().passphrase;
+    let unlock_result = web::block(move || unlock(&passphrase, &encryption_configuration)).await?;
+
+    let key = match unlock_result {
+        UnlockResult::Unlocked(key) => key,
+        UnlockResult::Initialized(key, encryption_configuration) => {
+            let mut config_guard = data.g_config.lock().unwrap();
+            if config_guard.encryption.salt.is_some() {
+                return Err(ErrorConflict("encryption was initialized in the meantime"));
+            }
+            config_guard.encryption = encryption_configuration;
+            save_config_to_file(&data.data_path, &config_guard);
+            drop(config_guard);
+            document_change(
+                graph_changes.clone(),
+                GraphChange::configuration_changed("encryption".to_string()),
+            );
+            key
+        }
+        UnlockResult::WrongPassphrase => return Err(ErrorForbidden("wrong passphrase")),
+    };
+
+    let mut encryption_guard = data.j_encryption.lock().unwrap();
+    encryption_guard.key = Some(key);
+    let encrypted_pages = encrypt_pending_pages(&data.data_path, &encryption_guard);
+    drop(encryption_guard);
+    for page_id in encrypted_pages {
+        let change = match page_id.page_type {
+            PageType::UserPage => GraphChange::user_page_changed(page_id.name.name),
+            PageType::JournalPage => GraphChange::journal_page_changed(page_id.name.name),
+        };
+        document_change(graph_changes.clone(), change);
+    }
+    refresh_internal_state(data.clone());
+
+    Ok(web::Json(status(&data)))
+}
+
+#[post("/api/encryption/lock")]
+async fn lock_graph(data: Data<AppState>) -> actix_web::Result<impl Responder> {
+    data.j_encryption.lock().unwrap().key = None;
+    refresh_internal_state(data.clone());
+    Ok(web::Json(status(&data)))
+}
+
+fn status(data: &AppState) -> EncryptionStatusDto {
+    let encryption_guard = data.j_encryption.lock().unwrap();
+    EncryptionStatusDto {
+        locked: encryption_guard.is_locked(),
+        encrypted_namespaces: encryption_guard.encrypted_namespaces.clone(),
+    }
+}
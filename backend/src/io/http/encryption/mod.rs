@@ -0,0 +1,12 @@
+mod dtos;
+pub mod endpoints;
+
+use crate::io::fs::pages::PageLockedError;
+use actix_web::http::StatusCode;
+use actix_web::ResponseError;
+
+impl ResponseError for PageLockedError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::LOCKED
+    }
+}
@@ -32,6 +32,7 @@ async fn get_kanban(
     let tag_guard = data.d_tag_index.lock().unwrap();
     let config_guard = data.g_config.lock().unwrap();
     let block_properties_guard = data.h_block_properties.lock().unwrap();
+    let page_properties_guard = data.i_page_properties.lock().unwrap();
     let kanban = get_kanban_from_tag(
         KanbanTitle {
             title: request_data.title,
@@ -64,6 +65,9 @@ async fn get_kanban(
             user_pages: &user_pages_guard,
             journal_pages: &journal_guard,
             todo_index: &todo_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+            property_schema: &config_guard.property_schema,
         },
         &mut data.e_asset_cache.lock().unwrap(),
         &data.data_path,
@@ -78,6 +82,7 @@ async fn get_kanban(
     drop(todo_guard);
     drop(tag_guard);
     drop(block_properties_guard);
+    drop(page_properties_guard);
 
     let dto: KanbanDataDto = rendered_kanban.into();
 
@@ -100,6 +105,7 @@ async fn move_card(
     let mut asset_cache = data.e_asset_cache.lock().unwrap();
     let config_guard = data.g_config.lock().unwrap();
     let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
 
     let resolved_page: &ParsedMarkdownFile = match page_id.page_type {
         PageType::UserPage => page_guard.entries.get(&page_id.name).unwrap(),
@@ -127,7 +133,8 @@ async fn move_card(
         },
         &data.data_path,
         &page_id.page_type,
-    );
+        &data.j_encryption.lock().unwrap(),
+    )?;
 
     let current_page_associated_state = CurrentPageAssociatedState {
         user_pages: &page_guard,
@@ -135,6 +142,7 @@ async fn move_card(
         todo_index: &todo_guard,
         tag_index: &tag_guard,
         block_properties_index: &block_properties_guard,
+        page_properties_index: &page_properties_guard,
     };
 
     let new_page_associated_state = update_index_for_file(
@@ -148,6 +156,7 @@ async fn move_card(
     *page_guard = new_page_associated_state.user_pages;
     *journal_guard = new_page_associated_state.journal_pages;
     *block_properties_guard = new_page_associated_state.block_properties_index;
+    *page_properties_guard = new_page_associated_state.page_properties_index;
 
     let parsed_block = updated_page.block(request.reference.block_number).unwrap();
 
@@ -158,6 +167,9 @@ async fn move_card(
             journal_pages: &journal_guard,
             todo_index: &todo_guard,
             tag_index: &tag_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+            property_schema: &config_guard.property_schema,
         },
         &mut asset_cache,
         &data.data_path,
@@ -173,6 +185,7 @@ async fn move_card(
     drop(journal_guard);
     drop(asset_cache);
     drop(block_properties_guard);
+    drop(page_properties_guard);
 
     document_change(
         graph_changes,
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkGraphQueryDto {
+    pub include_journals: Option<bool>,
+    pub min_degree: Option<usize>,
+    pub page: Option<String>,
+    pub is_journal: Option<bool>,
+    pub depth: Option<usize>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkGraphDto {
+    pub nodes: Vec<LinkGraphNodeDto>,
+    pub edges: Vec<LinkGraphEdgeDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkGraphNodeDto {
+    pub id: String,
+    pub title: String,
+    pub is_journal: bool,
+    pub exists: bool,
+    pub reference_count: usize,
+    pub link: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkGraphEdgeDto {
+    pub source: String,
+    pub target: String,
+    pub edge_type: String,
+}
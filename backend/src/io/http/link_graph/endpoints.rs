@@ -0,0 +1,34 @@
+use crate::io::http::link_graph::dtos::LinkGraphQueryDto;
+use crate::io::http::link_graph::mapper::{map_from_link_graph_query_dto, map_link_graph_to_dto};
+use crate::looksyk::link_graph::create_link_graph;
+use crate::state::application_state::AppState;
+use actix_web::web::{Data, Json, Query};
+use actix_web::{get, Responder};
+
+#[get("/api/graph")]
+async fn get_link_graph(
+    query: Query<LinkGraphQueryDto>,
+    data: Data<AppState>,
+) -> actix_web::Result<impl Responder> {
+    let filter = map_from_link_graph_query_dto(query.into_inner());
+
+    let page_guard = data.a_user_pages.lock().unwrap();
+    let journal_guard = data.b_journal_pages.lock().unwrap();
+    let tag_guard = data.d_tag_index.lock().unwrap();
+    let block_properties_guard = data.h_block_properties.lock().unwrap();
+
+    let link_graph = create_link_graph(
+        &tag_guard,
+        &page_guard,
+        &journal_guard,
+        &block_properties_guard,
+        &filter,
+    );
+
+    drop(page_guard);
+    drop(journal_guard);
+    drop(tag_guard);
+    drop(block_properties_guard);
+
+    Ok(Json(map_link_graph_to_dto(&link_graph)))
+}
@@ -0,0 +1,55 @@
+use crate::io::http::link_graph::dtos::{
+    LinkGraphDto, LinkGraphEdgeDto, LinkGraphNodeDto, LinkGraphQueryDto,
+};
+use crate::io::http::page::mapper::from_markdown_reference_to_link;
+use crate::io::http::page_type::page_id_to_external_string;
+use crate::looksyk::builder::page_name;
+use crate::looksyk::link_graph::{
+    LinkGraph, LinkGraphEdge, LinkGraphEdgeType, LinkGraphFilter, LinkGraphNode, Neighbourhood,
+};
+use crate::looksyk::model::PageType;
+
+const DEFAULT_NEIGHBOURHOOD_DEPTH: usize = 1;
+
+pub fn map_from_link_graph_query_dto(query: LinkGraphQueryDto) -> LinkGraphFilter {
+    LinkGraphFilter {
+        include_journals: query.include_journals.unwrap_or(true),
+        min_degree: query.min_degree.unwrap_or(0),
+        neighbourhood: query.page.map(|page| Neighbourhood {
+            center: match query.is_journal.unwrap_or(false) {
+                true => page_name(page).as_journal_page(),
+                false => page_name(page).as_user_page(),
+            },
+            depth: query.depth.unwrap_or(DEFAULT_NEIGHBOURHOOD_DEPTH),
+        }),
+    }
+}
+
+pub fn map_link_graph_to_dto(link_graph: &LinkGraph) -> LinkGraphDto {
+    LinkGraphDto {
+        nodes: link_graph.nodes.iter().map(map_node_to_dto).collect(),
+        edges: link_graph.edges.iter().map(map_edge_to_dto).collect(),
+    }
+}
+
+fn map_node_to_dto(node: &LinkGraphNode) -> LinkGraphNodeDto {
+    LinkGraphNodeDto {
+        id: page_id_to_external_string(&node.page_id),
+        title: node.page_id.name.name.clone(),
+        is_journal: node.page_id.page_type == PageType::JournalPage,
+        exists: node.exists,
+        reference_count: node.reference_count,
+        link: from_markdown_reference_to_link(&node.page_id.block_reference(0)),
+    }
+}
+
+fn map_edge_to_dto(edge: &LinkGraphEdge) -> LinkGraphEdgeDto {
+    LinkGraphEdgeDto {
+        source: page_id_to_external_string(&edge.source),
+        target: page_id_to_external_string(&edge.target),
+        edge_type: match edge.edge_type {
+            LinkGraphEdgeType::Link => "link".to_string(),
+            LinkGraphEdgeType::Hierarchy => "hierarchy".to_string(),
+        },
+    }
+}
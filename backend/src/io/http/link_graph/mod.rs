@@ -0,0 +1,3 @@
+mod dtos;
+pub mod endpoints;
+mod mapper;
@@ -27,6 +27,8 @@ async fn parse(
     let tag_guard = data.d_tag_index.lock().unwrap();
     let config_guard = data.g_config.lock().unwrap();
     let mut asset_guard = data.e_asset_cache.lock().unwrap();
+    let block_properties_guard = data.h_block_properties.lock().unwrap();
+    let page_properties_guard = data.i_page_properties.lock().unwrap();
 
     let serialized_block = render_block(
         &parsed_block,
@@ -35,6 +37,9 @@ async fn parse(
             user_pages: &user_page_guard,
             todo_index: &todo_index_guard,
             tag_index: &tag_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+            property_schema: &config_guard.property_schema,
         },
         &mut asset_guard,
         &data.data_path,
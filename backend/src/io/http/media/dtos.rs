@@ -27,10 +27,17 @@ pub struct UploadFormDto {
     pub json: MPJson<Metadata>,
 }
 
+#[derive(Deserialize)]
+pub struct ThumbnailQueryDto {
+    pub size: Option<u32>,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FileUploadResult {
     pub inline_markdown: String,
+    pub original_size: u64,
+    pub final_size: u64,
 }
 
 #[derive(Serialize)]
@@ -47,3 +54,30 @@ pub struct AssetPropertiesDto {
     pub size: String,
     pub full_qualified_path: String,
 }
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashMediaRequestDto {
+    pub file_names: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashMediaResultDto {
+    pub moved_files: Vec<String>,
+    pub failed_files: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameMediaRequestDto {
+    pub old_file_name: String,
+    pub new_file_name: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenameMediaResultDto {
+    pub new_file_name: String,
+    pub changed_pages: usize,
+}
@@ -4,32 +4,48 @@ use crate::io::fs::basic_file::{
     read_metadata,
 };
 use crate::io::fs::media::{
-    create_absolute_media_path, destination_path, read_file_sizes, read_media_file,
-    write_media_config, LoadedMedia, MediaOnDisk,
+    create_absolute_media_path, destination_path, move_media_to_trash, read_file_sizes,
+    read_media_file, rename_media, write_media_config, LoadedMedia, MediaOnDisk,
 };
+use crate::io::fs::pages::{write_page, PageLockedError, PageOnDisk};
+use crate::io::fs::thumbnail::load_or_create_thumbnail;
 use crate::io::hash::hash_file_content;
-use crate::io::http::media::dtos::{FileUploadResult, UploadFormDto};
+use crate::io::http::media::dtos::{
+    FileUploadResult, RenameMediaRequestDto, RenameMediaResultDto, ThumbnailQueryDto,
+    TrashMediaRequestDto, TrashMediaResultDto, UploadFormDto,
+};
 use crate::io::http::media::mapper::{map_to_asset_preview_dto, map_to_dto};
 use crate::io::http::page::mapper::map_markdown_file_to_dto;
+use crate::io::http::page::search::endpoints::cache_asset_texts;
+use crate::io::image_processing::process_image;
 use crate::looksyk::builtinpage::asset_metainfo_table::get_asset_meta_info_table;
 use crate::looksyk::builtinpage::assets_overview::generate_assets_overview_page;
 use crate::looksyk::datatypes::AssetDescriptor;
-use crate::looksyk::index::media::{find_file_by_hash, IndexedMedia};
+use crate::looksyk::index::index_operations::update_index_for_file;
+use crate::looksyk::index::media::{find_file, find_file_by_hash, IndexedMedia};
 use crate::looksyk::media::asset_preview::generate_asset_preview;
 use crate::looksyk::media::autodetect::inver_markdown_media_link;
+use crate::looksyk::media::rename::{rename_media_across_all_files, NewMediaName, OldMediaName};
 use crate::looksyk::media::suggestion::get_suggestion_for_file;
-use crate::looksyk::model::PageTitle;
+use crate::looksyk::media::thumbnail::{supports_thumbnail, thumbnail_size};
+use crate::looksyk::media::upload_policy::{processed_file_name, should_process_upload};
+use crate::looksyk::media::usage::{find_referenced_media, find_unused_media};
+use crate::looksyk::model::{PageId, PageTitle, PageType};
 use crate::looksyk::renderer::model::StaticRenderContext;
 use crate::looksyk::renderer::renderer_deep::render_file;
 use crate::looksyk::renderer::renderer_flat::render_file_flat;
 use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
-use crate::state::application_state::AppState;
+use crate::looksyk::serializer::serialize_page;
+use crate::state::application_state::{
+    AppState, CurrentPageAssociatedState, CurrentPageOnDiskState, GraphRootLocation,
+};
+use crate::state::encryption::EncryptionState;
 use crate::sync::io::sync_application_port::{document_change, GraphChange, GraphChangesState};
 use actix_files::NamedFile;
 use actix_multipart::form::MultipartForm;
 use actix_web::http::header::{ContentDisposition, DispositionType};
-use actix_web::web::{Data, Json};
-use actix_web::{error, get, post, Error, HttpRequest, Responder};
+use actix_web::web::{Data, Json, Query};
+use actix_web::{error, get, post, web, Error, HttpRequest, Responder};
 use mime::Mime;
 use std::fs;
 use std::os::unix::fs::MetadataExt;
@@ -49,36 +65,60 @@ pub async fn upload_file(
     );
     println!("path: {}", form.file.file.path().display());
     println!("original file-name: {json_filename}");
-    let filename = delete_all_forbidden_chars_in_filename(json_filename);
+    let mut filename = delete_all_forbidden_chars_in_filename(json_filename);
     println!("name after cleanup: {filename}");
-    let file = read_binary_file(form.file.file.path().to_path_buf());
+    let mut file = read_binary_file(form.file.file.path().to_path_buf());
+    let original_size = file.len() as u64;
+
+    let upload_policy = app_state.g_config.lock().unwrap().upload_policy.clone();
+    if should_process_upload(&upload_policy, &filename) {
+        match process_image(&file, &upload_policy) {
+            Some(processed_file) => {
+                file = processed_file;
+                filename = processed_file_name(&upload_policy, &filename);
+                println!(
+                    "processed upload {filename}: {original_size} -> {} bytes",
+                    file.len()
+                );
+            }
+            None if upload_policy.strip_metadata => {
+                return Err(error::ErrorUnprocessableEntity(
+                    "unable to process image, upload rejected to not store its metadata",
+                ));
+            }
+            None => println!("unable to process image, keeping original upload"),
+        }
+    }
+    let final_size = file.len() as u64;
 
     let hash = hash_file_content(LoadedMedia {
         content: file.clone(),
     });
 
-    let mut media_guard = app_state.f_media_index.lock().unwrap();
-
-    let index_element = find_file_by_hash(&hash, &media_guard).unwrap_or_else(|| {
-        let absolute_destination_path = destination_path(filename.as_str(), &app_state.data_path);
-        let name = absolute_destination_path
-            .file_name()
-            .unwrap()
-            .to_os_string()
-            .to_str()
-            .unwrap()
-            .to_string();
-        let new_entry = IndexedMedia {
-            file_name: name,
-            sha3: hash.clone(),
-        };
-        media_guard.media.push(new_entry.clone());
-        fs::write(Path::new(&absolute_destination_path), &file).unwrap();
-        write_media_config(&app_state.data_path, &media_guard);
-        new_entry
-    });
+    let index_element = {
+        let mut media_guard = app_state.f_media_index.lock().unwrap();
+        find_file_by_hash(&hash, &media_guard).unwrap_or_else(|| {
+            let absolute_destination_path =
+                destination_path(filename.as_str(), &app_state.data_path);
+            let name = absolute_destination_path
+                .file_name()
+                .unwrap()
+                .to_os_string()
+                .to_str()
+                .unwrap()
+                .to_string();
+            let new_entry = IndexedMedia {
+                file_name: name,
+                sha3: hash.clone(),
+            };
+            media_guard.media.push(new_entry.clone());
+            fs::write(Path::new(&absolute_destination_path), &file).unwrap();
+            write_media_config(&app_state.data_path, &media_guard);
+            new_entry
+        })
+    };
 
-    drop(media_guard);
+    cache_asset_texts(&app_state, vec![index_element.clone()]).await?;
 
     document_change(
         graph_changes,
@@ -87,6 +127,8 @@ pub async fn upload_file(
 
     Ok(Json(FileUploadResult {
         inline_markdown: inver_markdown_media_link(&index_element.file_name),
+        original_size,
+        final_size,
     }))
 }
 
@@ -148,14 +190,24 @@ pub async fn generate_assets_overview(data: Data<AppState>) -> error::Result<imp
     let mut asset_cache_guard = data.e_asset_cache.lock().unwrap();
     let media_index_guard = data.f_media_index.lock().unwrap();
     let config_guard = data.g_config.lock().unwrap();
+    let block_properties_guard = data.h_block_properties.lock().unwrap();
+    let page_properties_guard = data.i_page_properties.lock().unwrap();
 
-    let generate_assets_overview = generate_assets_overview_page(&media_index_guard, file_sizes);
+    let unused_media = find_unused_media(
+        &media_index_guard,
+        &find_referenced_media(&user_page_guard, &journal_page_guard),
+    );
+    let generate_assets_overview =
+        generate_assets_overview_page(&media_index_guard, file_sizes, unused_media);
 
     let render_context = StaticRenderContext {
         user_pages: &user_page_guard,
         journal_pages: &journal_page_guard,
         todo_index: &todo_guard,
         tag_index: &tag_index_guard,
+        block_properties_index: &block_properties_guard,
+        page_properties_index: &page_properties_guard,
+        property_schema: &config_guard.property_schema,
     };
 
     let rendered_file = render_file(
@@ -182,6 +234,286 @@ pub async fn generate_assets_overview(data: Data<AppState>) -> error::Result<imp
     )))
 }
 
+#[post("/api/assets/unused/trash")]
+pub async fn move_unused_media_to_trash(
+    body: Json<TrashMediaRequestDto>,
+    data: Data<AppState>,
+    graph_changes: Data<GraphChangesState>,
+) -> error::Result<impl Responder> {
+    ensure_all_pages_loaded(&data)?;
+
+    let user_page_guard = data.a_user_pages.lock().unwrap();
+    let journal_page_guard = data.b_journal_pages.lock().unwrap();
+    let mut media_index_guard = data.f_media_index.lock().unwrap();
+
+    let unused_media = find_unused_media(
+        &media_index_guard,
+        &find_referenced_media(&user_page_guard, &journal_page_guard),
+    );
+    drop(user_page_guard);
+    drop(journal_page_guard);
+
+    let mut moved_files = vec![];
+    let mut failed_files = vec![];
+    for media in unused_media {
+        if !body.file_names.contains(&media.file_name) {
+            continue;
+        }
+        let move_result = move_media_to_trash(
+            &MediaOnDisk {
+                name: media.file_name.clone(),
+            },
+            &data.data_path,
+        );
+        match move_result {
+            Ok(()) => moved_files.push(media.file_name),
+            Err(error) => {
+                println!("could not move {} to trash: {}", media.file_name, error);
+                failed_files.push(media.file_name);
+            }
+        }
+    }
+
+    media_index_guard
+        .media
+        .retain(|media| !moved_files.contains(&media.file_name));
+    write_media_config(&data.data_path, &media_index_guard);
+    drop(media_index_guard);
+
+    {
+        let mut asset_cache_guard = data.e_asset_cache.lock().unwrap();
+        for file_name in &moved_files {
+            asset_cache_guard.remove(&MediaOnDisk {
+                name: file_name.clone(),
+            });
+        }
+    }
+
+    for file_name in &moved_files {
+        document_change(
+            graph_changes.clone(),
+            GraphChange::media_moved_to_trash(file_name.clone()),
+        );
+    }
+
+    Ok(Json(TrashMediaResultDto {
+        moved_files,
+        failed_files,
+    }))
+}
+
+#[post("/api/assets/rename")]
+pub async fn rename_media_file(
+    body: Json<RenameMediaRequestDto>,
+    data: Data<AppState>,
+    graph_changes: Data<GraphChangesState>,
+) -> error::Result<impl Responder> {
+    ensure_all_pages_loaded(&data)?;
+
+    let body = body.into_inner();
+    let new_file_name = delete_all_forbidden_chars_in_filename(body.new_file_name);
+    if new_file_name.trim().is_empty() || new_file_name == body.old_file_name {
+        return Err(error::ErrorBadRequest("invalid new file name"));
+    }
+
+    let mut page_guard = data.a_user_pages.lock().unwrap();
+    let mut journal_guard = data.b_journal_pages.lock().unwrap();
+    let mut todo_guard = data.c_todo_index.lock().unwrap();
+    let mut tag_guard = data.d_tag_index.lock().unwrap();
+    let mut asset_cache_guard = data.e_asset_cache.lock().unwrap();
+    let mut media_index_guard = data.f_media_index.lock().unwrap();
+    let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
+
+    if find_file(&body.old_file_name, &media_index_guard).is_none() {
+        return Err(error::ErrorNotFound("media not found"));
+    }
+    let new_media_on_disk = MediaOnDisk {
+        name: new_file_name.clone(),
+    };
+    if find_file(&new_file_name, &media_index_guard).is_some()
+        || exists_file(create_absolute_media_path(
+            &new_media_on_disk,
+            &data.data_path,
+        ))
+    {
+        return Err(error::ErrorConflict("media with this name already exists"));
+    }
+
+    let rename_result = rename_media_across_all_files(
+        &OldMediaName {
+            file_name: body.old_file_name.clone(),
+        },
+        &NewMediaName {
+            file_name: new_file_name.clone(),
+        },
+        CurrentPageOnDiskState {
+            user_pages: &page_guard,
+            journal_pages: &journal_guard,
+        },
+    );
+    let new_state = rename_result.new_page_associated_state;
+
+    let mut page_rewrites = vec![];
+    for page_id in &rename_result.file_changes.changed_files {
+        let (old_page, new_page) = match page_id.page_type {
+            PageType::UserPage => (
+                page_guard.find(&page_id.name),
+                new_state.user_pages.find(&page_id.name),
+            ),
+            PageType::JournalPage => (
+                journal_guard.find(&page_id.name),
+                new_state.journal_pages.find(&page_id.name),
+            ),
+        };
+        page_rewrites.push(PageRewrite {
+            page_id: page_id.clone(),
+            old_content: old_page.map(serialize_page).unwrap_or_default().join("\n"),
+            new_content: serialize_page(new_page.unwrap()).join("\n"),
+        });
+    }
+
+    let encryption_guard = data.j_encryption.lock().unwrap();
+    write_page_rewrites(&page_rewrites, &data.data_path, &encryption_guard)?;
+    if let Err(error) = rename_media(
+        &MediaOnDisk {
+            name: body.old_file_name.clone(),
+        },
+        &new_media_on_disk,
+        &data.data_path,
+    ) {
+        roll_back_page_rewrites(&page_rewrites, &data.data_path, &encryption_guard);
+        return Err(error::ErrorInternalServerError(format!(
+            "could not rename media: {error}"
+        )));
+    }
+    drop(encryption_guard);
+
+    asset_cache_guard.remove(&MediaOnDisk {
+        name: body.old_file_name.clone(),
+    });
+    asset_cache_guard.remove(&new_media_on_disk);
+    drop(asset_cache_guard);
+
+    for media in media_index_guard.media.iter_mut() {
+        if media.file_name == body.old_file_name {
+            media.file_name = new_file_name.clone();
+        }
+    }
+    write_media_config(&data.data_path, &media_index_guard);
+    drop(media_index_guard);
+
+    *page_guard = new_state.user_pages;
+    *journal_guard = new_state.journal_pages;
+
+    let changed_pages = rename_result.file_changes.changed_files.len();
+    for file_to_save in rename_result.file_changes.changed_files {
+        let page = match file_to_save.page_type {
+            PageType::UserPage => page_guard.find(&file_to_save.name).unwrap(),
+            PageType::JournalPage => journal_guard.find(&file_to_save.name).unwrap(),
+        };
+
+        let current_page_associated_state = CurrentPageAssociatedState {
+            user_pages: &page_guard,
+            journal_pages: &journal_guard,
+            todo_index: &todo_guard,
+            tag_index: &tag_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+        };
+
+        let new_page_associated_state =
+            update_index_for_file(file_to_save, page, current_page_associated_state);
+
+        *todo_guard = new_page_associated_state.todo_index;
+        *tag_guard = new_page_associated_state.tag_index;
+        *page_guard = new_page_associated_state.user_pages;
+        *journal_guard = new_page_associated_state.journal_pages;
+        *block_properties_guard = new_page_associated_state.block_properties_index;
+        *page_properties_guard = new_page_associated_state.page_properties_index;
+    }
+
+    drop(page_guard);
+    drop(journal_guard);
+    drop(todo_guard);
+    drop(tag_guard);
+    drop(block_properties_guard);
+    drop(page_properties_guard);
+
+    document_change(
+        graph_changes,
+        GraphChange::media_renamed(body.old_file_name, new_file_name.clone()),
+    );
+
+    Ok(Json(RenameMediaResultDto {
+        new_file_name,
+        changed_pages,
+    }))
+}
+
+struct PageRewrite {
+    page_id: PageId,
+    old_content: String,
+    new_content: String,
+}
+
+fn write_page_rewrites(
+    page_rewrites: &[PageRewrite],
+    data_path: &GraphRootLocation,
+    encryption_state: &EncryptionState,
+) -> Result<(), PageLockedError> {
+    for (index, page_rewrite) in page_rewrites.iter().enumerate() {
+        let write_result = write_page(
+            PageOnDisk {
+                name: page_rewrite.page_id.name.name.clone(),
+                content: page_rewrite.new_content.clone(),
+            },
+            data_path,
+            &page_rewrite.page_id.page_type,
+            encryption_state,
+        );
+        if let Err(error) = write_result {
+            roll_back_page_rewrites(&page_rewrites[..index], data_path, encryption_state);
+            return Err(error);
+        }
+    }
+    Ok(())
+}
+
+fn roll_back_page_rewrites(
+    page_rewrites: &[PageRewrite],
+    data_path: &GraphRootLocation,
+    encryption_state: &EncryptionState,
+) {
+    for page_rewrite in page_rewrites {
+        let rollback_result = write_page(
+            PageOnDisk {
+                name: page_rewrite.page_id.name.name.clone(),
+                content: page_rewrite.old_content.clone(),
+            },
+            data_path,
+            &page_rewrite.page_id.page_type,
+            encryption_state,
+        );
+        if let Err(error) = rollback_result {
+            println!(
+                "could not roll back {}: {}",
+                page_rewrite.page_id.name.name, error
+            );
+        }
+    }
+}
+
+fn ensure_all_pages_loaded(data: &Data<AppState>) -> error::Result<()> {
+    let encryption_in_use = data.g_config.lock().unwrap().encryption.salt.is_some();
+    if encryption_in_use && data.j_encryption.lock().unwrap().is_locked() {
+        return Err(error::ErrorLocked(
+            "unlock the graph first, encrypted pages may reference media",
+        ));
+    }
+    Ok(())
+}
+
 #[get("/assets/{filename:.*}")]
 pub async fn assets(req: HttpRequest, data: Data<AppState>) -> Result<NamedFile, Error> {
     let path: String = req.match_info().query("filename").parse()?;
@@ -199,6 +531,34 @@ pub async fn assets(req: HttpRequest, data: Data<AppState>) -> Result<NamedFile,
     Ok(file.use_last_modified(true))
 }
 
+#[get("/assets/thumbnail/{filename:.*}")]
+pub async fn thumbnail(
+    req: HttpRequest,
+    query: Query<ThumbnailQueryDto>,
+    data: Data<AppState>,
+) -> Result<NamedFile, Error> {
+    let path: String = req.match_info().query("filename").parse()?;
+    let asset_descriptor = AssetDescriptor::new(path.clone());
+    let indexed_media = find_file(&path, &data.f_media_index.lock().unwrap());
+
+    if let Some(indexed_media) = indexed_media {
+        if supports_thumbnail(&asset_descriptor) {
+            let media = MediaOnDisk::new(&asset_descriptor);
+            let size = thumbnail_size(query.size);
+            let data_root_location = data.data_path.clone();
+            let thumbnail_path = web::block(move || {
+                load_or_create_thumbnail(&media, &indexed_media.sha3, size, &data_root_location)
+            })
+            .await?;
+            if let Some(thumbnail_path) = thumbnail_path {
+                return Ok(NamedFile::open(thumbnail_path)?.use_last_modified(true));
+            }
+        }
+    }
+
+    Ok(read_media_file(&path, &data.data_path)?.use_last_modified(true))
+}
+
 #[get("/api/asset-preview/info/{filename:.*}")]
 pub async fn get_asset_preview(
     req: HttpRequest,
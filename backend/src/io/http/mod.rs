@@ -1,10 +1,12 @@
 pub mod block_properties;
 pub mod config;
 pub mod design;
+pub mod encryption;
 pub mod favourites;
 pub(crate) mod help;
 pub mod kanban;
 mod link;
+pub mod link_graph;
 pub mod markdown;
 pub mod media;
 pub mod metainfo;
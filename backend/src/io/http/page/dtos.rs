@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 pub struct PreparedBlockContentDto {
     pub original_text: String,
     pub prepared_markdown: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prepared_html: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -37,6 +39,8 @@ pub struct PreparedMarkdownFileDto {
     pub is_favourite: bool,
     pub page_title: PageTitleDto,
     pub blocks: Vec<PreparedBlockDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -51,6 +55,18 @@ pub struct PageTitleDto {
     pub segments: Vec<PageTitleSegmentDto>,
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderFormatDto {
+    Markdown,
+    Html,
+}
+
+#[derive(Deserialize)]
+pub struct RenderFormatQueryDto {
+    pub format: Option<RenderFormatDto>,
+}
+
 #[derive(Deserialize)]
 pub struct ToValidateDto {
     pub block: String,
@@ -73,3 +89,10 @@ pub struct UpdateBlockContentDto {
 pub struct UpdateMarkdownFileDto {
     pub blocks: Vec<UpdateBlockDto>,
 }
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockIdDto {
+    pub id: String,
+    pub reference: MarkdownReferenceDto,
+}
@@ -1,22 +1,26 @@
 extern crate urlencoding;
 
+use crate::io::block_id::generate_unique_block_id;
 use crate::io::date::today;
 use crate::io::fs::pages::{write_page, PageOnDisk};
-use crate::io::http::page::dtos::UpdateBlockContentDto;
-use crate::io::http::page::mapper::{map_markdown_block_dto, map_to_block_dto};
+use crate::io::http::page::dtos::{BlockIdDto, UpdateBlockContentDto};
+use crate::io::http::page::mapper::{
+    map_markdown_block_dto, map_markdown_reference_to_dto, map_to_block_dto,
+};
 use crate::io::http::page_type::get_page_id_from_external_string;
+use crate::looksyk::index::block_id::{find_block_by_id, get_block_id, with_block_id};
 use crate::looksyk::index::index_operations::update_index_for_file;
-use crate::looksyk::model::{PageType, RawBlock, RawMarkdownFile};
+use crate::looksyk::model::{PageId, PageType, ParsedMarkdownFile, RawBlock, RawMarkdownFile};
 use crate::looksyk::parser::{parse_block, parse_markdown_file};
 use crate::looksyk::reader::parse_lines;
 use crate::looksyk::renderer::model::StaticRenderContext;
 use crate::looksyk::renderer::renderer_deep::render_block;
 use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
-use crate::looksyk::serializer::update_and_serialize_page;
+use crate::looksyk::serializer::{serialize_page, update_and_serialize_page};
 use crate::state::application_state::{AppState, CurrentPageAssociatedState};
 use crate::sync::io::sync_application_port::{document_change, GraphChange, GraphChangesState};
 use actix_web::web::{Data, Path};
-use actix_web::{post, web, Responder, Result};
+use actix_web::{error, get, post, web, Responder, Result};
 
 #[post("/api/pagesbyid/{page_id}/block/{block_number}")]
 async fn update_block(
@@ -37,6 +41,7 @@ async fn update_block(
     let mut asset_cache = data.e_asset_cache.lock().unwrap();
     let config_guard = data.g_config.lock().unwrap();
     let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
 
     let selected_page = match page_id.page_type {
         PageType::JournalPage => journal_guard.find(&page_id.name).unwrap().clone(),
@@ -59,7 +64,8 @@ async fn update_block(
         },
         &data.data_path,
         &page_id.page_type,
-    );
+        &data.j_encryption.lock().unwrap(),
+    )?;
 
     let current_page_associated_state = CurrentPageAssociatedState {
         user_pages: &page_guard,
@@ -67,6 +73,7 @@ async fn update_block(
         todo_index: &todo_guard,
         tag_index: &tag_guard,
         block_properties_index: &block_properties_guard,
+        page_properties_index: &page_properties_guard,
     };
 
     let new_page_associated_state = update_index_for_file(
@@ -80,6 +87,7 @@ async fn update_block(
     *page_guard = new_page_associated_state.user_pages;
     *journal_guard = new_page_associated_state.journal_pages;
     *block_properties_guard = new_page_associated_state.block_properties_index;
+    *page_properties_guard = new_page_associated_state.page_properties_index;
 
     let parsed_block = parse_block(&RawBlock {
         indentation: 0,
@@ -93,6 +101,9 @@ async fn update_block(
             journal_pages: &journal_guard,
             todo_index: &todo_guard,
             tag_index: &tag_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+            property_schema: &config_guard.property_schema,
         },
         &mut asset_cache,
         &data.data_path,
@@ -108,7 +119,118 @@ async fn update_block(
     drop(journal_guard);
     drop(asset_cache);
     drop(block_properties_guard);
+    drop(page_properties_guard);
+
+    document_page_change(&page_id, graph_changes);
+
+    Ok(web::Json(map_to_block_dto(&rendered_block)))
+}
+
+#[post("/api/pagesbyid/{page_id}/block/{block_number}/id")]
+async fn create_block_id(
+    path: Path<(String, usize)>,
+    data: Data<AppState>,
+    graph_changes: Data<GraphChangesState>,
+) -> Result<impl Responder> {
+    let (file_id, block_number) = path.into_inner();
+    let page_id = get_page_id_from_external_string(&file_id);
+    let block_reference = page_id.block_reference(block_number);
+
+    let mut page_guard = data.a_user_pages.lock().unwrap();
+    let mut journal_guard = data.b_journal_pages.lock().unwrap();
+    let mut todo_guard = data.c_todo_index.lock().unwrap();
+    let mut tag_guard = data.d_tag_index.lock().unwrap();
+    let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
+
+    let selected_page = match page_id.page_type {
+        PageType::JournalPage => journal_guard.find(&page_id.name),
+        PageType::UserPage => page_guard.find(&page_id.name),
+    }
+    .ok_or(error::ErrorNotFound("page not found"))?
+    .clone();
+    let selected_block = selected_page
+        .block(block_number)
+        .ok_or(error::ErrorNotFound("block not found"))?;
+
+    if let Some(block_id) = get_block_id(selected_block) {
+        return Ok(web::Json(BlockIdDto {
+            id: block_id,
+            reference: map_markdown_reference_to_dto(&block_reference),
+        }));
+    }
+
+    let block_id =
+        generate_unique_block_id(|id| find_block_by_id(id, &block_properties_guard).is_some())
+            .map_err(error::ErrorInternalServerError)?;
+
+    let mut blocks = selected_page.blocks.clone();
+    blocks[block_number] = with_block_id(selected_block, &block_id);
+    let serialized_page = serialize_page(&ParsedMarkdownFile::from_blocks(blocks));
+    let updated_page = parse_markdown_file(RawMarkdownFile {
+        blocks: parse_lines(serialized_page.join("\n").lines()),
+    });
+
+    write_page(
+        PageOnDisk {
+            name: page_id.name.name.clone(),
+            content: serialized_page.join("\n"),
+        },
+        &data.data_path,
+        &page_id.page_type,
+        &data.j_encryption.lock().unwrap(),
+    )?;
+
+    let new_page_associated_state = update_index_for_file(
+        page_id.clone(),
+        &updated_page,
+        CurrentPageAssociatedState {
+            user_pages: &page_guard,
+            journal_pages: &journal_guard,
+            todo_index: &todo_guard,
+            tag_index: &tag_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+        },
+    );
+
+    *todo_guard = new_page_associated_state.todo_index;
+    *tag_guard = new_page_associated_state.tag_index;
+    *page_guard = new_page_associated_state.user_pages;
+    *journal_guard = new_page_associated_state.journal_pages;
+    *block_properties_guard = new_page_associated_state.block_properties_index;
+    *page_properties_guard = new_page_associated_state.page_properties_index;
+
+    drop(todo_guard);
+    drop(tag_guard);
+    drop(page_guard);
+    drop(journal_guard);
+    drop(block_properties_guard);
+    drop(page_properties_guard);
+
+    document_page_change(&page_id, graph_changes);
+
+    Ok(web::Json(BlockIdDto {
+        id: block_id,
+        reference: map_markdown_reference_to_dto(&block_reference),
+    }))
+}
+
+#[get("/api/blocks/{block_id}")]
+async fn resolve_block_id(path: Path<String>, data: Data<AppState>) -> Result<impl Responder> {
+    let block_id = path.into_inner();
+    let block_properties_guard = data.h_block_properties.lock().unwrap();
+    let block_reference = find_block_by_id(&block_id, &block_properties_guard)
+        .ok_or(error::ErrorNotFound("block id not found"))?;
+    drop(block_properties_guard);
 
+    Ok(web::Json(BlockIdDto {
+        id: block_id,
+        reference: map_markdown_reference_to_dto(&block_reference),
+    }))
+}
+
+fn document_page_change(page_id: &PageId, graph_changes: Data<GraphChangesState>) {
     match page_id.page_type {
         PageType::JournalPage => document_change(
             graph_changes,
@@ -121,6 +243,4 @@ async fn update_block(
             );
         }
     }
-
-    Ok(web::Json(map_to_block_dto(&rendered_block)))
 }
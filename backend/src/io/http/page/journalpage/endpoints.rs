@@ -1,7 +1,10 @@
 use crate::io::date::today;
 use crate::io::fs::pages::{write_page, PageOnDisk};
-use crate::io::http::page::dtos::UpdateMarkdownFileDto;
-use crate::io::http::page::mapper::{map_from_update_markdown_dto, map_markdown_file_to_dto};
+use crate::io::http::page::dtos::{RenderFormatQueryDto, UpdateMarkdownFileDto};
+use crate::io::http::page::mapper::{
+    map_from_render_format_query_dto, map_from_update_markdown_dto, map_markdown_file_to_dto,
+    map_markdown_file_to_dto_in_format,
+};
 use crate::looksyk::builder::page_name;
 use crate::looksyk::builtinpage::journal_overview::generate_journal_overview;
 use crate::looksyk::builtinpage::page_not_found::generate_page_not_found;
@@ -19,7 +22,7 @@ use crate::looksyk::renderer::title::{
 use crate::looksyk::serializer::serialize_page;
 use crate::state::application_state::{AppState, CurrentPageAssociatedState};
 use crate::sync::io::sync_application_port::{document_change, GraphChange, GraphChangesState};
-use actix_web::web::{Data, Path};
+use actix_web::web::{Data, Path, Query};
 use actix_web::{get, post, web, Responder};
 
 #[get("/api/builtin-pages/journal-overview")]
@@ -43,73 +46,62 @@ async fn journal_overview(data: Data<AppState>) -> actix_web::Result<impl Respon
 #[get("/api/journal/{journal_name}")]
 async fn get_journal(
     path: Path<String>,
+    query: Query<RenderFormatQueryDto>,
     data: Data<AppState>,
 ) -> actix_web::Result<impl Responder> {
+    let render_format = map_from_render_format_query_dto(query.into_inner());
     let simple_page_name = page_name(path.into_inner());
 
-    let page_guard = data.a_user_pages.lock().unwrap();
-    let journal_guard = data.b_journal_pages.lock().unwrap();
-    let todo_index_guard = data.c_todo_index.lock().unwrap();
-    let mut asset_cache = data.e_asset_cache.lock().unwrap();
-    let config_guard = data.g_config.lock().unwrap();
-
-    let page = journal_guard.find(&simple_page_name);
-
-    let fav = is_favourite(&simple_page_name, &config_guard);
-    let journal_title_calculator_metadata = JournalTitleCalculatorMetadata {
-        journal_configurataion: &config_guard.journal_configuration,
-        today: today(),
+    let (rendered_file, fav, page_title) = {
+        let page_guard = data.a_user_pages.lock().unwrap();
+        let journal_guard = data.b_journal_pages.lock().unwrap();
+        let todo_index_guard = data.c_todo_index.lock().unwrap();
+        let tag_guard = data.d_tag_index.lock().unwrap();
+        let mut asset_cache = data.e_asset_cache.lock().unwrap();
+        let config_guard = data.g_config.lock().unwrap();
+        let block_properties_guard = data.h_block_properties.lock().unwrap();
+        let page_properties_guard = data.i_page_properties.lock().unwrap();
+
+        let fav = is_favourite(&simple_page_name, &config_guard);
+        let journal_title_calculator_metadata = JournalTitleCalculatorMetadata {
+            journal_configurataion: &config_guard.journal_configuration,
+            today: today(),
+        };
+
+        let page_title = calculate_journal_page_title(
+            &simple_page_name.as_journal_page(),
+            &journal_title_calculator_metadata,
+        );
+
+        let page_not_found = generate_page_not_found();
+        let page = journal_guard
+            .find(&simple_page_name)
+            .filter(|parsed_page| !parsed_page.blocks.is_empty())
+            .unwrap_or(&page_not_found);
+
+        let rendered_file = render_file(
+            page,
+            &StaticRenderContext {
+                user_pages: &page_guard,
+                journal_pages: &journal_guard,
+                todo_index: &todo_index_guard,
+                tag_index: &tag_guard,
+                block_properties_index: &block_properties_guard,
+                page_properties_index: &page_properties_guard,
+                property_schema: &config_guard.property_schema,
+            },
+            &mut asset_cache,
+            &data.data_path,
+            &journal_title_calculator_metadata,
+        );
+        (rendered_file, fav, page_title)
     };
 
-    let page_title = calculate_journal_page_title(
-        &simple_page_name.as_journal_page(),
-        &journal_title_calculator_metadata,
-    );
-
-    if let Some(parsed_page) = page {
-        if !parsed_page.blocks.is_empty() {
-            let prepared_page = render_file(
-                parsed_page,
-                &StaticRenderContext {
-                    user_pages: &page_guard,
-                    journal_pages: &journal_guard,
-                    todo_index: &todo_index_guard,
-                    tag_index: &data.d_tag_index.lock().unwrap(),
-                },
-                &mut asset_cache,
-                &data.data_path,
-                &journal_title_calculator_metadata,
-            );
-            return Ok(web::Json(map_markdown_file_to_dto(
-                prepared_page,
-                fav,
-                page_title,
-            )));
-        }
-    }
-
-    let rendered_file = render_file(
-        &generate_page_not_found(),
-        &StaticRenderContext {
-            user_pages: &page_guard,
-            journal_pages: &journal_guard,
-            todo_index: &todo_index_guard,
-            tag_index: &data.d_tag_index.lock().unwrap(),
-        },
-        &mut asset_cache,
-        &data.data_path,
-        &journal_title_calculator_metadata,
-    );
-
-    drop(page_guard);
-    drop(journal_guard);
-    drop(todo_index_guard);
-    drop(asset_cache);
-
-    Ok(web::Json(map_markdown_file_to_dto(
+    Ok(web::Json(map_markdown_file_to_dto_in_format(
         rendered_file,
         fav,
         page_title,
+        &render_format,
     )))
 }
 
@@ -137,7 +129,8 @@ async fn update_journal(
         },
         &data.data_path,
         &PageType::JournalPage,
-    );
+        &data.j_encryption.lock().unwrap(),
+    )?;
 
     let mut page_guard = data.a_user_pages.lock().unwrap();
     let mut journal_guard = data.b_journal_pages.lock().unwrap();
@@ -146,6 +139,7 @@ async fn update_journal(
     let mut asset_cache = data.e_asset_cache.lock().unwrap();
     let config_guard = data.g_config.lock().unwrap();
     let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
 
     let current_page_associated_state = CurrentPageAssociatedState {
         user_pages: &page_guard,
@@ -153,6 +147,7 @@ async fn update_journal(
         todo_index: &todo_guard,
         tag_index: &tag_guard,
         block_properties_index: &block_properties_guard,
+        page_properties_index: &page_properties_guard,
     };
 
     let page_id = simple_page_name.as_journal_page();
@@ -167,6 +162,7 @@ async fn update_journal(
     *page_guard = new_page_associated_state.user_pages;
     *journal_guard = new_page_associated_state.journal_pages;
     *block_properties_guard = new_page_associated_state.block_properties_index;
+    *page_properties_guard = new_page_associated_state.page_properties_index;
 
     let is_fav = is_favourite(&simple_page_name, &config_guard);
     let journal_title_calculator_metadata = JournalTitleCalculatorMetadata {
@@ -181,6 +177,9 @@ async fn update_journal(
             journal_pages: &journal_guard,
             todo_index: &todo_guard,
             tag_index: &tag_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+            property_schema: &config_guard.property_schema,
         },
         &mut asset_cache,
         &data.data_path,
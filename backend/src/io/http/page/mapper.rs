@@ -1,32 +1,54 @@
 use crate::io::http::page::dtos::{
     MarkdownReferenceDto, PageTitleDto, PageTitleSegmentDto, PreparedBlockContentDto,
-    PreparedBlockDto, PreparedMarkdownFileDto, PreparedReferencedMarkdownDto,
-    UpdateBlockContentDto, UpdateMarkdownFileDto,
+    PreparedBlockDto, PreparedMarkdownFileDto, PreparedReferencedMarkdownDto, RenderFormatDto,
+    RenderFormatQueryDto, UpdateBlockContentDto, UpdateMarkdownFileDto,
 };
 use crate::io::http::page_type::page_id_to_external_string;
 use crate::io::http::routes::{to_journal_page_url, to_wiki_page_url};
 use crate::looksyk::model::{
-    PageTitle, PageType, PreparedBlock, PreparedMarkdownFile, PreparedReferencedMarkdown, RawBlock,
-    UpdateBlock, UpdateMarkdownFile,
+    PageTitle, PageType, PreparedBlock, PreparedBlockContent, PreparedMarkdownFile,
+    PreparedReferencedMarkdown, RawBlock, UpdateBlock, UpdateMarkdownFile,
 };
+use crate::looksyk::renderer::model::RenderFormat;
+use crate::looksyk::renderer::renderer_html::render_prepared_markdown_as_html;
 use crate::state::block::BlockReference;
 
 pub fn map_to_block_dto(prepared_block: &PreparedBlock) -> PreparedBlockDto {
+    map_to_block_dto_in_format(prepared_block, &RenderFormat::Markdown)
+}
+
+fn map_to_block_dto_in_format(
+    prepared_block: &PreparedBlock,
+    render_format: &RenderFormat,
+) -> PreparedBlockDto {
     PreparedBlockDto {
         indentation: prepared_block.indentation,
-        content: PreparedBlockContentDto {
-            original_text: prepared_block.content.original_text.clone(),
-            prepared_markdown: prepared_block.content.prepared_markdown.clone(),
-        },
+        content: map_to_block_content_dto(&prepared_block.content, render_format),
         referenced_content: prepared_block
             .referenced_markdown
             .iter()
-            .map(map_to_prepared_reference_to)
+            .map(|reference| map_to_prepared_reference_in_format(reference, render_format))
             .collect(),
         has_dynamic_content: prepared_block.has_dynamic_content,
     }
 }
 
+fn map_to_block_content_dto(
+    content: &PreparedBlockContent,
+    render_format: &RenderFormat,
+) -> PreparedBlockContentDto {
+    PreparedBlockContentDto {
+        original_text: content.original_text.clone(),
+        prepared_markdown: content.prepared_markdown.clone(),
+        prepared_html: match render_format {
+            RenderFormat::Markdown => None,
+            RenderFormat::Html => {
+                Some(render_prepared_markdown_as_html(&content.prepared_markdown))
+            }
+        },
+    }
+}
+
 impl From<&PreparedBlock> for PreparedBlockDto {
     fn from(prepared_block: &PreparedBlock) -> Self {
         map_to_block_dto(prepared_block)
@@ -41,15 +63,16 @@ impl From<&PreparedReferencedMarkdown> for PreparedReferencedMarkdownDto {
 
 pub fn map_to_prepared_reference_to(
     prepared_referenced_markdown: &PreparedReferencedMarkdown,
+) -> PreparedReferencedMarkdownDto {
+    map_to_prepared_reference_in_format(prepared_referenced_markdown, &RenderFormat::Markdown)
+}
+
+fn map_to_prepared_reference_in_format(
+    prepared_referenced_markdown: &PreparedReferencedMarkdown,
+    render_format: &RenderFormat,
 ) -> PreparedReferencedMarkdownDto {
     PreparedReferencedMarkdownDto {
-        content: PreparedBlockContentDto {
-            original_text: prepared_referenced_markdown.content.original_text.clone(),
-            prepared_markdown: prepared_referenced_markdown
-                .content
-                .prepared_markdown
-                .clone(),
-        },
+        content: map_to_block_content_dto(&prepared_referenced_markdown.content, render_format),
         reference: map_markdown_reference_to_dto(&prepared_referenced_markdown.reference),
     }
 }
@@ -74,6 +97,20 @@ pub fn map_markdown_file_to_dto(
     prepared_markdown_file: PreparedMarkdownFile,
     is_fav: bool,
     page_title: PageTitle,
+) -> PreparedMarkdownFileDto {
+    map_markdown_file_to_dto_in_format(
+        prepared_markdown_file,
+        is_fav,
+        page_title,
+        &RenderFormat::Markdown,
+    )
+}
+
+pub fn map_markdown_file_to_dto_in_format(
+    prepared_markdown_file: PreparedMarkdownFile,
+    is_fav: bool,
+    page_title: PageTitle,
+    render_format: &RenderFormat,
 ) -> PreparedMarkdownFileDto {
     PreparedMarkdownFileDto {
         is_favourite: is_fav,
@@ -91,8 +128,16 @@ pub fn map_markdown_file_to_dto(
         blocks: prepared_markdown_file
             .blocks
             .iter()
-            .map(map_to_block_dto)
+            .map(|block| map_to_block_dto_in_format(block, render_format))
             .collect(),
+        warning: None,
+    }
+}
+
+pub fn map_from_render_format_query_dto(query: RenderFormatQueryDto) -> RenderFormat {
+    match query.format {
+        Some(RenderFormatDto::Html) => RenderFormat::Html,
+        Some(RenderFormatDto::Markdown) | None => RenderFormat::Markdown,
     }
 }
 
@@ -123,8 +168,12 @@ pub fn map_markdown_block_dto(
 
 #[cfg(test)]
 mod tests {
-    use crate::io::http::page::mapper::from_markdown_reference_to_link;
+    use crate::io::http::page::mapper::{
+        from_markdown_reference_to_link, map_to_block_dto_in_format,
+    };
     use crate::looksyk::builder::test_builder::{journal_page_id, user_page_id};
+    use crate::looksyk::model::{PreparedBlock, PreparedBlockContent};
+    use crate::looksyk::renderer::model::RenderFormat;
     use crate::state::block::BlockReference;
 
     #[test]
@@ -149,4 +198,26 @@ mod tests {
 
         assert_eq!(link, "/page/my-page");
     }
+
+    #[test]
+    fn test_map_markdown_file_to_dto_in_format_html_should_add_html() {
+        let prepared_block = PreparedBlock {
+            indentation: 0,
+            content: PreparedBlockContent {
+                original_text: "**bold**".to_string(),
+                prepared_markdown: "**bold**".to_string(),
+            },
+            referenced_markdown: vec![],
+            has_dynamic_content: false,
+        };
+
+        let markdown_dto = map_to_block_dto_in_format(&prepared_block, &RenderFormat::Markdown);
+        let html_dto = map_to_block_dto_in_format(&prepared_block, &RenderFormat::Html);
+
+        assert_eq!(markdown_dto.content.prepared_html, None);
+        assert_eq!(
+            html_dto.content.prepared_html,
+            Some("<p><strong>bold</strong></p>\n".to_string())
+        );
+    }
 }
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 pub struct SearchResultDto {
     pub journal: Vec<SearchFindingDto>,
     pub page: Vec<SearchFindingDto>,
+    pub asset: Vec<AssetSearchFindingDto>,
 }
 
 #[derive(Serialize)]
@@ -25,3 +26,20 @@ pub struct SearchReferenceDto {
     pub file_name: String,
     pub block_number: usize,
 }
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetSearchFindingDto {
+    pub file_name: String,
+    pub line: Option<usize>,
+    pub page: Option<usize>,
+    pub text_line: String,
+    pub referenced_by: Vec<AssetReferenceDto>,
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetReferenceDto {
+    pub page_name: String,
+    pub link: String,
+}
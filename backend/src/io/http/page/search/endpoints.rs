@@ -1,6 +1,11 @@
+use crate::io::fs::asset_cache_loader::load_asset_text;
+use crate::io::fs::media::MediaOnDisk;
 use crate::io::http::page::search::dtos::SearchTermDto;
 use crate::io::http::page::search::mapper::{search_result_to_dto, to_search_term};
+use crate::looksyk::index::media::IndexedMedia;
+use crate::looksyk::media::usage::find_pages_referencing_media;
 use crate::looksyk::search;
+use crate::looksyk::search::SearchableAsset;
 use crate::state::application_state::AppState;
 use actix_web::web::Data;
 use actix_web::{post, web, Responder, Result};
@@ -12,13 +17,71 @@ async fn search_in_files(
 ) -> Result<impl Responder> {
     let search_term = to_search_term(body.into_inner());
 
+    let media_without_text: Vec<IndexedMedia> = {
+        let asset_cache_guard = data.e_asset_cache.lock().unwrap();
+        let media_index_guard = data.f_media_index.lock().unwrap();
+        media_index_guard
+            .media
+            .iter()
+            .filter(|media| !asset_cache_guard.has_text(&media.sha3))
+            .cloned()
+            .collect()
+    };
+    cache_asset_texts(&data, media_without_text).await?;
+
     let page_guard = data.a_user_pages.lock().unwrap();
     let journal_guard = data.b_journal_pages.lock().unwrap();
+    let asset_cache_guard = data.e_asset_cache.lock().unwrap();
+    let media_index_guard = data.f_media_index.lock().unwrap();
+
+    let mut pages_referencing_media = find_pages_referencing_media(&page_guard, &journal_guard);
+    let mut assets = vec![];
+    for media in &media_index_guard.media {
+        if let Some(text) = asset_cache_guard.find_extracted_text(&media.sha3) {
+            assets.push(SearchableAsset {
+                file_name: &media.file_name,
+                text,
+                referenced_by: pages_referencing_media
+                    .remove(&media.file_name)
+                    .unwrap_or_default(),
+            });
+        }
+    }
 
-    let result = search::search(search_term, &journal_guard, &page_guard);
+    let result = search::search(search_term, &journal_guard, &page_guard, &assets);
 
+    drop(assets);
     drop(page_guard);
     drop(journal_guard);
+    drop(asset_cache_guard);
+    drop(media_index_guard);
 
     Ok(web::Json(search_result_to_dto(result)))
 }
+
+/// Extracts the searchable text of the given media outside of any lock, parsing large pdfs
+/// would otherwise block all other requests.
+pub async fn cache_asset_texts(data: &Data<AppState>, media: Vec<IndexedMedia>) -> Result<()> {
+    if media.is_empty() {
+        return Ok(());
+    }
+    let data_path = data.data_path.clone();
+    let text_states = web::block(move || {
+        media
+            .into_iter()
+            .map(|media| {
+                let media_on_disk = MediaOnDisk {
+                    name: media.file_name,
+                };
+                (media.sha3, load_asset_text(&media_on_disk, &data_path))
+            })
+            .collect::<Vec<_>>()
+    })
+    .await?;
+
+    let mut asset_cache_guard = data.e_asset_cache.lock().unwrap();
+    for (sha3, text_state) in text_states {
+        asset_cache_guard.insert_text(&sha3, text_state);
+    }
+    Ok(())
+}
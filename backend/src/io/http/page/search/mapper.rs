@@ -1,7 +1,12 @@
 use crate::io::http::page::search::dtos::{
-    SearchFindingDto, SearchReferenceDto, SearchResultDto, SearchTermDto,
+    AssetReferenceDto, AssetSearchFindingDto, SearchFindingDto, SearchReferenceDto,
+    SearchResultDto, SearchTermDto,
+};
+use crate::looksyk::model::{PageId, PageType};
+use crate::looksyk::renderer::atomics::{journal_path, user_page_path};
+use crate::looksyk::search::{
+    AssetSearchFinding, AssetTextLocation, SearchFinding, SearchResult, SearchTerm,
 };
-use crate::looksyk::search::{SearchFinding, SearchResult, SearchTerm};
 use crate::state::block::BlockReference;
 
 pub fn search_result_to_dto(search_result: SearchResult) -> SearchResultDto {
@@ -16,6 +21,11 @@ pub fn search_result_to_dto(search_result: SearchResult) -> SearchResultDto {
             .iter()
             .map(search_finding_to_dto)
             .collect(),
+        asset: search_result
+            .asset
+            .iter()
+            .map(asset_search_finding_to_dto)
+            .collect(),
     }
 }
 
@@ -26,6 +36,34 @@ fn search_finding_to_dto(search_finding: &SearchFinding) -> SearchFindingDto {
     }
 }
 
+fn asset_search_finding_to_dto(asset_search_finding: &AssetSearchFinding) -> AssetSearchFindingDto {
+    let (line, page) = match asset_search_finding.location {
+        AssetTextLocation::Line(line) => (Some(line), None),
+        AssetTextLocation::Page(page) => (None, Some(page)),
+    };
+    AssetSearchFindingDto {
+        file_name: asset_search_finding.file_name.clone(),
+        line,
+        page,
+        text_line: asset_search_finding.text_line.clone(),
+        referenced_by: asset_search_finding
+            .referenced_by
+            .iter()
+            .map(to_asset_reference)
+            .collect(),
+    }
+}
+
+fn to_asset_reference(page_id: &PageId) -> AssetReferenceDto {
+    AssetReferenceDto {
+        page_name: page_id.name.name.clone(),
+        link: match page_id.page_type {
+            PageType::UserPage => user_page_path(&page_id.name),
+            PageType::JournalPage => journal_path(&page_id.name),
+        },
+    }
+}
+
 fn to_search_reference(markdown_reference: &BlockReference) -> SearchReferenceDto {
     SearchReferenceDto {
         file_name: markdown_reference.page_id.name.name.clone(),
@@ -41,9 +79,14 @@ pub fn to_search_term(search_term_dto: SearchTermDto) -> SearchTerm {
 
 #[cfg(test)]
 mod tests {
-    use crate::io::http::page::search::mapper::{search_finding_to_dto, search_result_to_dto};
+    use crate::io::http::page::search::dtos::AssetReferenceDto;
+    use crate::io::http::page::search::mapper::{
+        asset_search_finding_to_dto, search_finding_to_dto, search_result_to_dto,
+    };
     use crate::looksyk::builder::test_builder::{journal_page_id, user_page_id};
-    use crate::looksyk::search::{SearchFinding, SearchResult};
+    use crate::looksyk::search::{
+        AssetSearchFinding, AssetTextLocation, SearchFinding, SearchResult,
+    };
     use crate::state::block::BlockReference;
 
     #[test]
@@ -79,6 +122,7 @@ mod tests {
                 },
                 text_line: "text_line".to_string(),
             }],
+            asset: vec![],
         };
 
         let dto = search_result_to_dto(search_result);
@@ -86,4 +130,27 @@ mod tests {
         assert_eq!(dto.journal.len(), 1);
         assert_eq!(dto.page.len(), 1);
     }
+
+    #[test]
+    fn test_asset_search_finding_to_dto() {
+        let asset_search_finding = AssetSearchFinding {
+            file_name: "doc.pdf".to_string(),
+            location: AssetTextLocation::Page(3),
+            text_line: "text_line".to_string(),
+            referenced_by: vec![journal_page_id("2024_01_01")],
+        };
+
+        let dto = asset_search_finding_to_dto(&asset_search_finding);
+
+        assert_eq!(dto.file_name, "doc.pdf");
+        assert_eq!(dto.line, None);
+        assert_eq!(dto.page, Some(3));
+        assert_eq!(
+            dto.referenced_by,
+            vec![AssetReferenceDto {
+                page_name: "2024_01_01".to_string(),
+                link: "journal/2024_01_01".to_string(),
+            }]
+        );
+    }
 }
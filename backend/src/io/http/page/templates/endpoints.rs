@@ -63,13 +63,15 @@ async fn insert_template_into_page(
         },
         &data.data_path,
         &page_id.page_type,
-    );
+        &data.j_encryption.lock().unwrap(),
+    )?;
 
     let mut todo_guard = data.c_todo_index.lock().unwrap();
     let mut tag_guard = data.d_tag_index.lock().unwrap();
     let mut asset_guard = data.e_asset_cache.lock().unwrap();
     let config_guard = data.g_config.lock().unwrap();
     let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
 
     let current_page_associated_state = CurrentPageAssociatedState {
         user_pages: &page_guard,
@@ -77,6 +79,7 @@ async fn insert_template_into_page(
         todo_index: &todo_guard,
         tag_index: &tag_guard,
         block_properties_index: &block_properties_guard,
+        page_properties_index: &page_properties_guard,
     };
 
     let new_page_associated_state = update_index_for_file(
@@ -90,6 +93,7 @@ async fn insert_template_into_page(
     *page_guard = new_page_associated_state.user_pages;
     *journal_guard = new_page_associated_state.journal_pages;
     *block_properties_guard = new_page_associated_state.block_properties_index;
+    *page_properties_guard = new_page_associated_state.page_properties_index;
 
     let journal_title_calculator_metadata = JournalTitleCalculatorMetadata {
         journal_configurataion: &config_guard.journal_configuration,
@@ -103,6 +107,9 @@ async fn insert_template_into_page(
             journal_pages: &journal_guard,
             todo_index: &todo_guard,
             tag_index: &tag_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+            property_schema: &config_guard.property_schema,
         },
         &mut asset_guard,
         &data.data_path,
@@ -114,6 +121,7 @@ async fn insert_template_into_page(
     drop(page_guard);
     drop(journal_guard);
     drop(block_properties_guard);
+    drop(page_properties_guard);
 
     let is_fav = match page_id.page_type {
         PageType::UserPage => is_favourite(&page_id.name, &data.g_config.lock().unwrap()),
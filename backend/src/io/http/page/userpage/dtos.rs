@@ -1,3 +1,4 @@
+use crate::io::http::page::dtos::MarkdownReferenceDto;
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize)]
@@ -5,6 +6,7 @@ use serde::{Deserialize, Serialize};
 pub struct RenamePageDto {
     pub old_page_name: String,
     pub new_page_name: String,
+    pub rename_hierarchy: Option<bool>,
 }
 
 #[derive(Serialize)]
@@ -15,3 +17,17 @@ pub struct RenamePageResultDto {
 
 #[derive(Serialize)]
 pub struct PageDeletedDto {}
+
+#[derive(Deserialize)]
+pub struct OverviewPageQueryDto {
+    pub columns: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlinkedReferenceDto {
+    pub reference: MarkdownReferenceDto,
+    pub text_line: String,
+    pub matched_text: String,
+    pub linked_markdown: String,
+}
@@ -1,29 +1,49 @@
 use crate::io::date::today;
-use crate::io::fs::pages::{delete_user_file, write_page, PageOnDisk};
-use crate::io::http::page::dtos::UpdateMarkdownFileDto;
-use crate::io::http::page::mapper::{map_from_update_markdown_dto, map_markdown_file_to_dto};
-use crate::io::http::page::userpage::dtos::{PageDeletedDto, RenamePageDto, RenamePageResultDto};
+use crate::io::fs::pages::{
+    check_page_writable, delete_user_file, read_all_page_file_names, write_page, PageOnDisk,
+};
+use crate::io::http::page::dtos::{RenderFormatQueryDto, UpdateMarkdownFileDto};
+use crate::io::http::page::mapper::{
+    map_from_render_format_query_dto, map_from_update_markdown_dto, map_markdown_file_to_dto,
+    map_markdown_file_to_dto_in_format,
+};
+use crate::io::http::page::userpage::dtos::{
+    OverviewPageQueryDto, PageDeletedDto, RenamePageDto, RenamePageResultDto, UnlinkedReferenceDto,
+};
+use crate::io::http::page::userpage::mapper::{
+    map_to_page_property_columns, map_unlinked_reference_to_dto,
+};
 use crate::looksyk::builder::page_name;
+use crate::looksyk::builtinpage::graph_health::generate_graph_health_page;
 use crate::looksyk::builtinpage::page_not_found::generate_page_not_found;
+use crate::looksyk::builtinpage::property_validation::generate_property_validation_page;
 use crate::looksyk::builtinpage::user_page_overview::generate_overview_page;
 use crate::looksyk::favourite::is_favourite;
+use crate::looksyk::health::create_health_report;
+use crate::looksyk::index::alias::{all_names_of, find_alias_owner, resolve_alias};
 use crate::looksyk::index::index_operations::{
     remove_page_from_internal_state, update_index_for_file,
 };
-use crate::looksyk::index::rename::{rename_page_across_all_files, NewPageName, OldPageName};
+use crate::looksyk::index::rename::{
+    rename_page_across_all_files, rename_page_hierarchy_across_all_files, NewPageName, OldPageName,
+};
 use crate::looksyk::index::tag::render_tag_index_for_page;
-use crate::looksyk::model::{PageTitle, PageType, ParsedMarkdownFile, RawMarkdownFile};
+use crate::looksyk::model::{
+    PageTitle, PageType, ParsedMarkdownFile, RawMarkdownFile, SimplePageName,
+};
 use crate::looksyk::parser::{parse_markdown_file, parse_markdown_update_file};
+use crate::looksyk::property_schema::validate_property_values;
 use crate::looksyk::reader::parse_lines;
 use crate::looksyk::renderer::model::StaticRenderContext;
 use crate::looksyk::renderer::renderer_deep::render_file;
 use crate::looksyk::renderer::title::{calculate_user_page_title, JournalTitleCalculatorMetadata};
 use crate::looksyk::serializer::serialize_page;
+use crate::looksyk::unlinked_references::find_unlinked_references;
 use crate::state::application_state::{
     AppState, CurrentPageAssociatedState, CurrentPageOnDiskState,
 };
 use crate::sync::io::sync_application_port::{document_change, GraphChange, GraphChangesState};
-use actix_web::web::{Data, Json, Path};
+use actix_web::web::{Data, Json, Path, Query};
 use actix_web::{delete, get, post, Responder};
 
 #[post("/api/pages/{page_name}")]
@@ -36,6 +56,7 @@ async fn update_page(
     let request_body = body.into_inner();
     let page_name_from_input = path.into_inner();
     let page_name = page_name(page_name_from_input);
+    let alias_warning = alias_collision_warning(&page_name, &data);
 
     let parsed_page = parse_markdown_update_file(map_from_update_markdown_dto(request_body));
     let serialized_page = serialize_page(&parsed_page);
@@ -51,7 +72,8 @@ async fn update_page(
         },
         &data.data_path,
         &PageType::UserPage,
-    );
+        &data.j_encryption.lock().unwrap(),
+    )?;
 
     let mut page_guard = data.a_user_pages.lock().unwrap();
     let mut journal_guard = data.b_journal_pages.lock().unwrap();
@@ -60,6 +82,7 @@ async fn update_page(
     let mut asset_cache = data.e_asset_cache.lock().unwrap();
     let config_guard = data.g_config.lock().unwrap();
     let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
 
     let page_id = page_name.as_user_page();
     let current_page_associated_state = CurrentPageAssociatedState {
@@ -68,6 +91,7 @@ async fn update_page(
         todo_index: &todo_guard,
         tag_index: &tag_guard,
         block_properties_index: &block_properties_guard,
+        page_properties_index: &page_properties_guard,
     };
 
     let new_page_associated_state = update_index_for_file(
@@ -81,6 +105,7 @@ async fn update_page(
     *page_guard = new_page_associated_state.user_pages;
     *journal_guard = new_page_associated_state.journal_pages;
     *block_properties_guard = new_page_associated_state.block_properties_index;
+    *page_properties_guard = new_page_associated_state.page_properties_index;
 
     let is_fav = is_favourite(&page_name, &config_guard);
     let rendered_file = render_file(
@@ -90,6 +115,9 @@ async fn update_page(
             journal_pages: &journal_guard,
             todo_index: &todo_guard,
             tag_index: &tag_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+            property_schema: &config_guard.property_schema,
         },
         &mut asset_cache,
         &data.data_path,
@@ -105,86 +133,97 @@ async fn update_page(
     drop(page_guard);
     drop(journal_guard);
     drop(block_properties_guard);
+    drop(page_properties_guard);
 
     document_change(
         graph_changes,
         GraphChange::user_page_changed(page_id.name.name),
     );
 
-    Ok(Json(map_markdown_file_to_dto(
-        rendered_file,
-        is_fav,
-        page_title,
-    )))
+    let mut result = map_markdown_file_to_dto(rendered_file, is_fav, page_title);
+    result.warning = alias_warning;
+    Ok(Json(result))
+}
+
+fn alias_collision_warning(page_name: &SimplePageName, data: &Data<AppState>) -> Option<String> {
+    if data
+        .a_user_pages
+        .lock()
+        .unwrap()
+        .entries
+        .contains_key(page_name)
+    {
+        return None;
+    }
+    let alias_owner = find_alias_owner(page_name, &data.h_block_properties.lock().unwrap())?;
+    Some(format!(
+        "The new page '{}' collides with an alias of the page '{}'",
+        page_name.name, alias_owner.name
+    ))
 }
 
 #[get("/api/pages/{page_name}")]
 async fn get_page(
     input_page_name: Path<String>,
+    query: Query<RenderFormatQueryDto>,
     data: Data<AppState>,
 ) -> actix_web::Result<impl Responder> {
+    let render_format = map_from_render_format_query_dto(query.into_inner());
     let page_name_from_input = input_page_name.into_inner();
-    let simple_page_name = page_name(page_name_from_input);
+    let requested_page_name = page_name(page_name_from_input);
+
+    let (rendered_file, is_fav, page_title) = {
+        let page_guard = data.a_user_pages.lock().unwrap();
+        let journal_guard = data.b_journal_pages.lock().unwrap();
+        let todo_index_guard = data.c_todo_index.lock().unwrap();
+        let tag_guard = data.d_tag_index.lock().unwrap();
+        let mut asset_cache = data.e_asset_cache.lock().unwrap();
+        let config_guard = data.g_config.lock().unwrap();
+        let block_properties_guard = data.h_block_properties.lock().unwrap();
+        let page_properties_guard = data.i_page_properties.lock().unwrap();
+        let simple_page_name = if page_guard.entries.contains_key(&requested_page_name) {
+            requested_page_name
+        } else {
+            resolve_alias(&requested_page_name, &block_properties_guard)
+        };
+        let is_fav = is_favourite(&simple_page_name, &config_guard);
+        let page_title = calculate_user_page_title(&simple_page_name.as_user_page());
 
-    let page_guard = data.a_user_pages.lock().unwrap();
-    let journal_guard = data.b_journal_pages.lock().unwrap();
-    let todo_index_guard = data.c_todo_index.lock().unwrap();
-    let tag_guard = data.d_tag_index.lock().unwrap();
-    let mut asset_cache = data.e_asset_cache.lock().unwrap();
-    let config_guard = data.g_config.lock().unwrap();
-    let is_fav = is_favourite(&simple_page_name, &config_guard);
-    let page_title = calculate_user_page_title(&simple_page_name.as_user_page());
+        let journal_title_calculator_metadata = JournalTitleCalculatorMetadata {
+            journal_configurataion: &config_guard.journal_configuration,
+            today: today(),
+        };
 
-    let page = page_guard.entries.get(&simple_page_name);
-    let journal_title_calculator_metadata = JournalTitleCalculatorMetadata {
-        journal_configurataion: &config_guard.journal_configuration,
-        today: today(),
+        let page_not_found = generate_page_not_found();
+        let page = page_guard
+            .entries
+            .get(&simple_page_name)
+            .filter(|parsed_page| !parsed_page.blocks.is_empty())
+            .unwrap_or(&page_not_found);
+
+        let rendered_file = render_file(
+            page,
+            &StaticRenderContext {
+                user_pages: &page_guard,
+                journal_pages: &journal_guard,
+                todo_index: &todo_index_guard,
+                tag_index: &tag_guard,
+                block_properties_index: &block_properties_guard,
+                page_properties_index: &page_properties_guard,
+                property_schema: &config_guard.property_schema,
+            },
+            &mut asset_cache,
+            &data.data_path,
+            &journal_title_calculator_metadata,
+        );
+        (rendered_file, is_fav, page_title)
     };
 
-    let data_root_location = &data.data_path;
-    if let Some(parsed_page) = page {
-        if !parsed_page.blocks.is_empty() {
-            let prepared_page = render_file(
-                parsed_page,
-                &StaticRenderContext {
-                    user_pages: &page_guard,
-                    journal_pages: &journal_guard,
-                    todo_index: &todo_index_guard,
-                    tag_index: &tag_guard,
-                },
-                &mut asset_cache,
-                data_root_location,
-                &journal_title_calculator_metadata,
-            );
-            return Ok(Json(map_markdown_file_to_dto(
-                prepared_page,
-                is_fav,
-                page_title,
-            )));
-        }
-    }
-    let rendered_file = render_file(
-        &generate_page_not_found(),
-        &StaticRenderContext {
-            user_pages: &page_guard,
-            journal_pages: &journal_guard,
-            todo_index: &todo_index_guard,
-            tag_index: &tag_guard,
-        },
-        &mut asset_cache,
-        data_root_location,
-        &journal_title_calculator_metadata,
-    );
-
-    drop(page_guard);
-    drop(todo_index_guard);
-    drop(tag_guard);
-    drop(asset_cache);
-
-    Ok(Json(map_markdown_file_to_dto(
+    Ok(Json(map_markdown_file_to_dto_in_format(
         rendered_file,
         is_fav,
         page_title,
+        &render_format,
     )))
 }
 
@@ -201,10 +240,16 @@ async fn get_backlinks(
     let tag_guard = data.d_tag_index.lock().unwrap();
     let mut asset_cache_guard = data.e_asset_cache.lock().unwrap();
     let config_guard = data.g_config.lock().unwrap();
+    let block_properties_guard = data.h_block_properties.lock().unwrap();
+    let page_properties_guard = data.i_page_properties.lock().unwrap();
 
     let data_root_location = &data.data_path;
 
-    let result = render_tag_index_for_page(simple_page_name.as_user_page(), &tag_guard);
+    let result = render_tag_index_for_page(
+        simple_page_name.as_user_page(),
+        &tag_guard,
+        &block_properties_guard,
+    );
 
     let rendered_file = render_file(
         &result,
@@ -213,6 +258,9 @@ async fn get_backlinks(
             journal_pages: &journal_guard,
             todo_index: &todo_index_guard,
             tag_index: &tag_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+            property_schema: &config_guard.property_schema,
         },
         &mut asset_cache_guard,
         data_root_location,
@@ -234,16 +282,49 @@ async fn get_backlinks(
     )))
 }
 
+#[get("/api/unlinked-references/{page_name}")]
+async fn get_unlinked_references(
+    input_page_name: Path<String>,
+    data: Data<AppState>,
+) -> actix_web::Result<impl Responder> {
+    let simple_page_name = page_name(input_page_name.into_inner());
+
+    let page_guard = data.a_user_pages.lock().unwrap();
+    let journal_guard = data.b_journal_pages.lock().unwrap();
+    let block_properties_guard = data.h_block_properties.lock().unwrap();
+
+    let page_names = all_names_of(&simple_page_name, &block_properties_guard);
+    let unlinked_references = find_unlinked_references(&page_names, &page_guard, &journal_guard);
+
+    drop(page_guard);
+    drop(journal_guard);
+    drop(block_properties_guard);
+
+    Ok(Json(
+        unlinked_references
+            .iter()
+            .map(map_unlinked_reference_to_dto)
+            .collect::<Vec<UnlinkedReferenceDto>>(),
+    ))
+}
+
 #[get("/api/builtin-pages/user-page-overview")]
-async fn get_overview_page(data: Data<AppState>) -> actix_web::Result<impl Responder> {
+async fn get_overview_page(
+    query: Query<OverviewPageQueryDto>,
+    data: Data<AppState>,
+) -> actix_web::Result<impl Responder> {
     let user_page_guard = data.a_user_pages.lock().unwrap();
     let journal_page_guard = data.b_journal_pages.lock().unwrap();
     let todo_guard = data.c_todo_index.lock().unwrap();
     let tag_index_guard = data.d_tag_index.lock().unwrap();
     let mut asset_cache = data.e_asset_cache.lock().unwrap();
     let config_guard = data.g_config.lock().unwrap();
+    let block_properties_guard = data.h_block_properties.lock().unwrap();
+    let page_properties_guard = data.i_page_properties.lock().unwrap();
 
-    let overview_page = generate_overview_page(&tag_index_guard, &user_page_guard);
+    let page_property_columns = map_to_page_property_columns(&query);
+    let overview_page =
+        generate_overview_page(&tag_index_guard, &user_page_guard, &page_property_columns);
 
     let rendered_file = render_file(
         &overview_page,
@@ -252,6 +333,9 @@ async fn get_overview_page(data: Data<AppState>) -> actix_web::Result<impl Respo
             journal_pages: &journal_page_guard,
             todo_index: &todo_guard,
             tag_index: &tag_index_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+            property_schema: &config_guard.property_schema,
         },
         &mut asset_cache,
         &data.data_path,
@@ -273,6 +357,113 @@ async fn get_overview_page(data: Data<AppState>) -> actix_web::Result<impl Respo
     )))
 }
 
+#[get("/api/builtin-pages/graph-health")]
+async fn get_graph_health_page(data: Data<AppState>) -> actix_web::Result<impl Responder> {
+    let page_files = read_all_page_file_names(&data.data_path);
+
+    let user_page_guard = data.a_user_pages.lock().unwrap();
+    let journal_page_guard = data.b_journal_pages.lock().unwrap();
+    let todo_guard = data.c_todo_index.lock().unwrap();
+    let tag_index_guard = data.d_tag_index.lock().unwrap();
+    let mut asset_cache = data.e_asset_cache.lock().unwrap();
+    let config_guard = data.g_config.lock().unwrap();
+    let block_properties_guard = data.h_block_properties.lock().unwrap();
+    let page_properties_guard = data.i_page_properties.lock().unwrap();
+
+    let health_report = create_health_report(
+        &user_page_guard,
+        &journal_page_guard,
+        &tag_index_guard,
+        &block_properties_guard,
+        &page_files,
+    );
+    let health_page = generate_graph_health_page(&health_report);
+
+    let rendered_file = render_file(
+        &health_page,
+        &StaticRenderContext {
+            user_pages: &user_page_guard,
+            journal_pages: &journal_page_guard,
+            todo_index: &todo_guard,
+            tag_index: &tag_index_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+            property_schema: &config_guard.property_schema,
+        },
+        &mut asset_cache,
+        &data.data_path,
+        &JournalTitleCalculatorMetadata {
+            journal_configurataion: &config_guard.journal_configuration,
+            today: today(),
+        },
+    );
+
+    drop(user_page_guard);
+    drop(journal_page_guard);
+    drop(todo_guard);
+    drop(tag_index_guard);
+    drop(asset_cache);
+    drop(config_guard);
+    drop(block_properties_guard);
+    drop(page_properties_guard);
+
+    Ok(Json(map_markdown_file_to_dto(
+        rendered_file,
+        false,
+        PageTitle::internal_page_title("Graph Health".to_string()),
+    )))
+}
+
+#[get("/api/builtin-pages/property-validation")]
+async fn get_property_validation_page(data: Data<AppState>) -> actix_web::Result<impl Responder> {
+    let user_page_guard = data.a_user_pages.lock().unwrap();
+    let journal_page_guard = data.b_journal_pages.lock().unwrap();
+    let todo_guard = data.c_todo_index.lock().unwrap();
+    let tag_index_guard = data.d_tag_index.lock().unwrap();
+    let mut asset_cache = data.e_asset_cache.lock().unwrap();
+    let config_guard = data.g_config.lock().unwrap();
+    let block_properties_guard = data.h_block_properties.lock().unwrap();
+    let page_properties_guard = data.i_page_properties.lock().unwrap();
+
+    let findings = validate_property_values(&block_properties_guard);
+    let validation_page =
+        generate_property_validation_page(&config_guard.property_schema, &findings);
+
+    let rendered_file = render_file(
+        &validation_page,
+        &StaticRenderContext {
+            user_pages: &user_page_guard,
+            journal_pages: &journal_page_guard,
+            todo_index: &todo_guard,
+            tag_index: &tag_index_guard,
+            block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
+            property_schema: &config_guard.property_schema,
+        },
+        &mut asset_cache,
+        &data.data_path,
+        &JournalTitleCalculatorMetadata {
+            journal_configurataion: &config_guard.journal_configuration,
+            today: today(),
+        },
+    );
+
+    drop(user_page_guard);
+    drop(journal_page_guard);
+    drop(todo_guard);
+    drop(tag_index_guard);
+    drop(asset_cache);
+    drop(config_guard);
+    drop(block_properties_guard);
+    drop(page_properties_guard);
+
+    Ok(Json(map_markdown_file_to_dto(
+        rendered_file,
+        false,
+        PageTitle::internal_page_title("Property Validation".to_string()),
+    )))
+}
+
 #[post("/api/append-page/{page_name}")]
 async fn append_page(
     body: Json<UpdateMarkdownFileDto>,
@@ -295,7 +486,7 @@ async fn append_page(
     let merged_page = if let Some(current_page) = &current_page {
         let mut new_blocks = current_page.blocks.clone();
         new_blocks.extend(page_appendix.blocks);
-        ParsedMarkdownFile { blocks: new_blocks }
+        ParsedMarkdownFile::from_blocks(new_blocks)
     } else {
         page_appendix
     };
@@ -307,13 +498,15 @@ async fn append_page(
         },
         &data.data_path,
         &PageType::UserPage,
-    );
+        &data.j_encryption.lock().unwrap(),
+    )?;
 
     let mut page_guard = data.a_user_pages.lock().unwrap();
     let mut journal_guard = data.b_journal_pages.lock().unwrap();
     let mut todo_guard = data.c_todo_index.lock().unwrap();
     let mut tag_guard = data.d_tag_index.lock().unwrap();
     let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
 
     let page_id = page_name.as_user_page();
     let current_page_associated_state = CurrentPageAssociatedState {
@@ -322,6 +515,7 @@ async fn append_page(
         todo_index: &todo_guard,
         tag_index: &tag_guard,
         block_properties_index: &block_properties_guard,
+        page_properties_index: &page_properties_guard,
     };
     let new_page_associated_state =
         update_index_for_file(page_id.clone(), &merged_page, current_page_associated_state);
@@ -330,6 +524,7 @@ async fn append_page(
     *page_guard = new_page_associated_state.user_pages;
     *journal_guard = new_page_associated_state.journal_pages;
     *block_properties_guard = new_page_associated_state.block_properties_index;
+    *page_properties_guard = new_page_associated_state.page_properties_index;
 
     document_change(
         graph_change,
@@ -354,22 +549,46 @@ async fn rename_page(
     let mut todo_guard = data.c_todo_index.lock().unwrap();
     let mut tag_guard = data.d_tag_index.lock().unwrap();
     let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
+    let encryption_guard = data.j_encryption.lock().unwrap();
 
     let current_page_associated_state = CurrentPageOnDiskState {
         user_pages: &page_guard,
         journal_pages: &journal_guard,
     };
 
-    let rename_tag_result = rename_page_across_all_files(
-        OldPageName {
-            page_name: old_page_name.clone(),
-        },
-        NewPageName {
-            page_name: new_page_name.clone(),
-        },
-        current_page_associated_state,
-        &tag_guard,
-    );
+    let old = OldPageName {
+        page_name: old_page_name.clone(),
+    };
+    let new = NewPageName {
+        page_name: new_page_name.clone(),
+    };
+    let rename_tag_result = if body.rename_hierarchy.unwrap_or(false) {
+        rename_page_hierarchy_across_all_files(old, new, current_page_associated_state, &tag_guard)?
+    } else {
+        rename_page_across_all_files(old, new, current_page_associated_state, &tag_guard)
+    };
+
+    // a locked page must fail the rename before any file is touched
+    let renamed_state = &rename_tag_result.new_page_associated_state;
+    for file_to_save in &rename_tag_result.file_changes.changed_files {
+        let page = match file_to_save.page_type {
+            PageType::UserPage => renamed_state.user_pages.find(&file_to_save.name).unwrap(),
+            PageType::JournalPage => renamed_state
+                .journal_pages
+                .find(&file_to_save.name)
+                .unwrap(),
+        };
+        check_page_writable(
+            &PageOnDisk {
+                name: file_to_save.name.name.clone(),
+                content: serialize_page(page).join("\n"),
+            },
+            &data.data_path,
+            &file_to_save.page_type,
+            &encryption_guard,
+        )?;
+    }
 
     *page_guard = rename_tag_result.new_page_associated_state.user_pages;
     *journal_guard = rename_tag_result.new_page_associated_state.journal_pages;
@@ -386,6 +605,7 @@ async fn rename_page(
             todo_index: &todo_guard,
             tag_index: &tag_guard,
             block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
         };
 
         let serialized_page = serialize_page(page);
@@ -396,7 +616,8 @@ async fn rename_page(
             },
             &data.data_path,
             &file_to_save.page_type,
-        );
+            &encryption_guard,
+        )?;
 
         let new_page_associated_state =
             update_index_for_file(file_to_save, page, current_page_associated_state);
@@ -406,6 +627,7 @@ async fn rename_page(
         *page_guard = new_page_associated_state.user_pages;
         *journal_guard = new_page_associated_state.journal_pages;
         *block_properties_guard = new_page_associated_state.block_properties_index;
+        *page_properties_guard = new_page_associated_state.page_properties_index;
     }
 
     for file_to_delete in rename_tag_result.file_changes.file_to_delete {
@@ -415,6 +637,7 @@ async fn rename_page(
             todo_index: &todo_guard,
             tag_index: &tag_guard,
             block_properties_index: &block_properties_guard,
+            page_properties_index: &page_properties_guard,
         };
 
         let new_page_associated_state =
@@ -426,6 +649,7 @@ async fn rename_page(
         *page_guard = new_page_associated_state.user_pages;
         *journal_guard = new_page_associated_state.journal_pages;
         *block_properties_guard = new_page_associated_state.block_properties_index;
+        *page_properties_guard = new_page_associated_state.page_properties_index;
     }
 
     drop(page_guard);
@@ -433,6 +657,8 @@ async fn rename_page(
     drop(todo_guard);
     drop(tag_guard);
     drop(block_properties_guard);
+    drop(page_properties_guard);
+    drop(encryption_guard);
 
     document_change(
         graph_changes,
@@ -458,6 +684,7 @@ async fn delete_page(
     let mut todo_guard = data.c_todo_index.lock().unwrap();
     let mut tag_guard = data.d_tag_index.lock().unwrap();
     let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
 
     let current_page_associated_state = CurrentPageAssociatedState {
         user_pages: &page_guard,
@@ -465,6 +692,7 @@ async fn delete_page(
         todo_index: &todo_guard,
         tag_index: &tag_guard,
         block_properties_index: &block_properties_guard,
+        page_properties_index: &page_properties_guard,
     };
 
     let page_id = simple_page_name.as_user_page();
@@ -477,12 +705,14 @@ async fn delete_page(
     *page_guard = new_page_associated_state.user_pages;
     *journal_guard = new_page_associated_state.journal_pages;
     *block_properties_guard = new_page_associated_state.block_properties_index;
+    *page_properties_guard = new_page_associated_state.page_properties_index;
 
     drop(todo_guard);
     drop(tag_guard);
     drop(page_guard);
     drop(journal_guard);
     drop(block_properties_guard);
+    drop(page_properties_guard);
 
     document_change(
         graph_changes,
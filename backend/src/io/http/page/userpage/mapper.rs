@@ -0,0 +1,25 @@
+use crate::io::http::page::mapper::map_markdown_reference_to_dto;
+use crate::io::http::page::userpage::dtos::{OverviewPageQueryDto, UnlinkedReferenceDto};
+use crate::looksyk::unlinked_references::UnlinkedReference;
+
+pub fn map_unlinked_reference_to_dto(
+    unlinked_reference: &UnlinkedReference,
+) -> UnlinkedReferenceDto {
+    UnlinkedReferenceDto {
+        reference: map_markdown_reference_to_dto(&unlinked_reference.reference),
+        text_line: unlinked_reference.text_line.clone(),
+        matched_text: unlinked_reference.matched_text.clone(),
+        linked_markdown: unlinked_reference.linked_markdown.clone(),
+    }
+}
+
+pub fn map_to_page_property_columns(query: &OverviewPageQueryDto) -> Vec<String> {
+    query
+        .columns
+        .iter()
+        .flat_map(|columns| columns.split(','))
+        .map(str::trim)
+        .filter(|column| !column.is_empty())
+        .map(str::to_string)
+        .collect()
+}
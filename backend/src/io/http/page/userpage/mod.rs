@@ -1,2 +1,13 @@
 mod dtos;
 pub mod endpoints;
+mod mapper;
+
+use crate::looksyk::index::rename::PageRenameCollisionError;
+use actix_web::http::StatusCode;
+use actix_web::ResponseError;
+
+impl ResponseError for PageRenameCollisionError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::CONFLICT
+    }
+}
@@ -7,4 +7,7 @@ pub struct PlotDataDto {
     pub property_key: String,
     pub starting_at: String,
     pub ending_at: String,
+    pub chart_type: Option<String>,
+    pub aggregation: Option<String>,
+    pub cumulative: Option<bool>,
 }
@@ -1,8 +1,11 @@
+use crate::io::date::today;
 use crate::io::http::plot::dtos::PlotDataDto;
 use crate::io::plot::render_as_svg;
-use crate::looksyk::plot::{calculate_plot_data, PlotDataQuery, PlotMetadata};
+use crate::looksyk::plot::{
+    calculate_plot_data, parse_property_keys, ChartType, PlotAggregation, PlotDataQuery,
+    PlotMetadata,
+};
 use crate::state::application_state::AppState;
-use crate::state::block_properties::BlockPropertyKey;
 use actix_web::http::header;
 use actix_web::web::{Data, Query};
 use actix_web::{get, Error, HttpResponse};
@@ -13,6 +16,17 @@ pub async fn example_plot_svg(
     dto: Query<PlotDataDto>,
 ) -> Result<HttpResponse, Error> {
     let plot = dto.into_inner();
+    let chart_type = match plot.chart_type.as_deref() {
+        Some(chart_type) => ChartType::from_name(chart_type),
+        None => Some(ChartType::Line),
+    };
+    let aggregation = match plot.aggregation.as_deref() {
+        Some(aggregation) => PlotAggregation::from_name(aggregation),
+        None => Some(PlotAggregation::Day),
+    };
+    let (Some(chart_type), Some(aggregation)) = (chart_type, aggregation) else {
+        return Ok(HttpResponse::BadRequest().body("invalid chart type or aggregation"));
+    };
 
     let config_guard = data.g_config.lock().unwrap();
     let property_guard = data.h_block_properties.lock().unwrap();
@@ -22,21 +36,22 @@ pub async fn example_plot_svg(
         PlotDataQuery {
             ending_at: plot.ending_at.clone(),
             starting_at: plot.starting_at.clone(),
-            property_key: BlockPropertyKey {
-                value: plot.property_key.clone(),
-            },
+            property_keys: parse_property_keys(&plot.property_key),
+            aggregation,
+            cumulative: plot.cumulative.unwrap_or(false),
         },
         PlotMetadata {
-            label: plot.property_key.clone(),
             title: plot.title.clone(),
             width: plot.width,
             height: plot.height,
+            chart_type,
         },
+        &today(),
     );
 
     drop(property_guard);
 
-    if plot_data.data.points.is_empty() {
+    if !plot_data.has_data_points() {
         return Ok(HttpResponse::BadRequest().body("no data points"));
     }
     let svg_buf = render_as_svg(&plot_data, &config_guard.design)?;
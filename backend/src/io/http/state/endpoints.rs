@@ -13,7 +13,8 @@ pub async fn post_refresh_internal_state(
 }
 
 pub fn refresh_internal_state(data: Data<AppState>) {
-    let new_state = load_graph_data(&data.data_path);
+    let encryption_key = data.j_encryption.lock().unwrap().key.clone();
+    let new_state = load_graph_data(&data.data_path, encryption_key);
 
     let mut page_guard = data.a_user_pages.lock().unwrap();
     let mut journal_guard = data.b_journal_pages.lock().unwrap();
@@ -22,6 +23,9 @@ pub fn refresh_internal_state(data: Data<AppState>) {
     let mut asset_cache = data.e_asset_cache.lock().unwrap();
     let mut media_index = data.f_media_index.lock().unwrap();
     let mut config = data.g_config.lock().unwrap();
+    let mut block_properties_guard = data.h_block_properties.lock().unwrap();
+    let mut page_properties_guard = data.i_page_properties.lock().unwrap();
+    let mut encryption_guard = data.j_encryption.lock().unwrap();
 
     *page_guard = new_state.a_user_pages;
     *journal_guard = new_state.b_journal_pages;
@@ -30,6 +34,9 @@ pub fn refresh_internal_state(data: Data<AppState>) {
     *asset_cache = new_state.e_asset_cache;
     *media_index = new_state.f_media_index;
     *config = new_state.g_config;
+    *block_properties_guard = new_state.h_block_properties;
+    *page_properties_guard = new_state.i_page_properties;
+    *encryption_guard = new_state.j_encryption;
 
     drop(todo_guard);
     drop(tag_guard);
@@ -38,4 +45,7 @@ pub fn refresh_internal_state(data: Data<AppState>) {
     drop(asset_cache);
     drop(media_index);
     drop(config);
+    drop(block_properties_guard);
+    drop(page_properties_guard);
+    drop(encryption_guard);
 }
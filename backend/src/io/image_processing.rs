@@ -0,0 +1,84 @@
+use crate::looksyk::media::upload_policy::{ImageTargetFormat, UploadPolicy};
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader};
+use std::io::Cursor;
+
+pub fn process_image(content: &[u8], upload_policy: &UploadPolicy) -> Option<Vec<u8>> {
+    let reader = ImageReader::new(Cursor::new(content))
+        .with_guessed_format()
+        .ok()?;
+    let source_format = reader.format()?;
+    let mut decoder = reader.into_decoder().ok()?;
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let mut image = DynamicImage::from_decoder(decoder).ok()?;
+    image.apply_orientation(orientation);
+
+    if let Some(max_dimension) = upload_policy.max_dimension {
+        if image.width() > max_dimension || image.height() > max_dimension {
+            image = image.resize(max_dimension, max_dimension, FilterType::Lanczos3);
+        }
+    }
+
+    let target_format = match &upload_policy.target_format {
+        Some(ImageTargetFormat::Jpeg) => ImageFormat::Jpeg,
+        Some(ImageTargetFormat::Png) => ImageFormat::Png,
+        None => source_format,
+    };
+
+    let mut result = Cursor::new(vec![]);
+    if target_format == ImageFormat::Jpeg {
+        let encoder = JpegEncoder::new_with_quality(&mut result, upload_policy.quality);
+        image.to_rgb8().write_with_encoder(encoder).ok()?;
+    } else {
+        image.write_to(&mut result, target_format).ok()?;
+    }
+    Some(result.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, RgbImage};
+
+    fn png_image(width: u32, height: u32) -> Vec<u8> {
+        let mut result = Cursor::new(vec![]);
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .write_to(&mut result, ImageFormat::Png)
+            .unwrap();
+        result.into_inner()
+    }
+
+    #[test]
+    fn test_process_image_should_resize_to_max_dimension() {
+        let policy = UploadPolicy {
+            max_dimension: Some(50),
+            ..UploadPolicy::default()
+        };
+
+        let result = process_image(&png_image(200, 100), &policy).unwrap();
+
+        let decoded = image::load_from_memory(&result).unwrap();
+        assert_eq!(decoded.dimensions(), (50, 25));
+    }
+
+    #[test]
+    fn test_process_image_should_convert_format() {
+        let policy = UploadPolicy {
+            target_format: Some(ImageTargetFormat::Jpeg),
+            ..UploadPolicy::default()
+        };
+
+        let result = process_image(&png_image(10, 10), &policy).unwrap();
+
+        assert_eq!(image::guess_format(&result).unwrap(), ImageFormat::Jpeg);
+    }
+
+    #[test]
+    fn test_process_image_should_reject_unreadable_content() {
+        let result = process_image(&[1, 2, 3], &UploadPolicy::default());
+
+        assert!(result.is_none());
+    }
+}
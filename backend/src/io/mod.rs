@@ -1,11 +1,14 @@
 pub mod actix;
+pub mod block_id;
 pub mod cargo;
 pub mod cli;
 pub mod date;
+pub mod encryption;
 pub mod fs;
 mod hash;
 pub mod http;
 pub mod human;
+pub mod image_processing;
 pub mod markdown;
 pub mod plot;
 pub mod state;
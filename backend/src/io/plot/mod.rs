@@ -1,15 +1,22 @@
 use crate::looksyk::data::config::runtime_graph_configuration::Design;
-use crate::looksyk::plot::PlotData;
+use crate::looksyk::plot::{ChartType, DataPoint, PlotData};
 use actix_web::Error;
-use chrono::NaiveDate;
+use chrono::{Days, NaiveDate};
 use csscolorparser::Color as CssColor;
 use plotters::backend::SVGBackend;
 use plotters::chart::ChartBuilder;
 use plotters::element::PathElement;
-use plotters::prelude::{Color, IntoDrawingArea, IntoTextStyle, LineSeries, BLACK, RED, WHITE};
-use plotters::style::{RGBAColor, TextStyle};
+use plotters::element::Rectangle;
+use plotters::prelude::{
+    AreaSeries, Color, IntoDrawingArea, IntoTextStyle, LineSeries, BLACK, RED, WHITE,
+};
+use plotters::style::{Palette, Palette99, RGBAColor, TextStyle};
 use std::str::FromStr;
 
+fn days_between(from: NaiveDate, to: NaiveDate) -> f64 {
+    (to - from).num_days() as f64
+}
+
 fn css_to_plotters_rgba(s: &str, default: RGBAColor) -> RGBAColor {
     match CssColor::from_str(s) {
         Ok(c) => RGBAColor(
@@ -23,15 +30,28 @@ fn css_to_plotters_rgba(s: &str, default: RGBAColor) -> RGBAColor {
 }
 
 pub fn render_as_svg(plot: &PlotData, design: &Design) -> Result<String, Error> {
-    let (min_y, max_y) = plot
-        .data
-        .points
+    let points: Vec<&DataPoint> = plot
+        .series
         .iter()
-        .fold((i32::MAX, i32::MIN), |(min, max), p| {
-            (min.min(p.value), max.max(p.value))
-        });
-    let y_range_min = min_y - 1; // kleiner Puffer
-    let y_range_max = max_y + 1;
+        .flat_map(|series| series.points.iter())
+        .collect();
+    let first_date = points.iter().map(|p| p.date).min().unwrap();
+    let last_date = points.iter().map(|p| p.date).max().unwrap();
+    let x_range_max = match plot.chart_type {
+        ChartType::Bar => days_between(first_date, plot.aggregation.bucket_end(last_date)),
+        ChartType::Line | ChartType::Area => days_between(first_date, last_date).max(1.0),
+    };
+
+    let (min_y, max_y) = points.iter().fold((f64::MAX, f64::MIN), |(min, max), p| {
+        (min.min(p.value), max.max(p.value))
+    });
+    let (min_y, max_y) = match plot.chart_type {
+        ChartType::Line => (min_y, max_y),
+        ChartType::Bar | ChartType::Area => (min_y.min(0.0), max_y.max(0.0)),
+    };
+    let padding = ((max_y - min_y) * 0.1).max(1.0); // kleiner Puffer
+    let y_range_min = min_y - padding;
+    let y_range_max = max_y + padding;
 
     // Farben abhängig vom Design-Appearance ODER explizit aus Design, falls gesetzt
     let axis_color = css_to_plotters_rgba(
@@ -87,33 +107,56 @@ pub fn render_as_svg(plot: &PlotData, design: &Design) -> Result<String, Error>
             .caption(&plot.caption, caption_style)
             .x_label_area_size(40)
             .y_label_area_size(40)
-            .build_cartesian_2d(
-                plot.data.points[0].date..plot.data.points.last().unwrap().date,
-                y_range_min..y_range_max,
-            )
+            .build_cartesian_2d(0.0..x_range_max, y_range_min..y_range_max)
             .map_err(actix_web::error::ErrorInternalServerError)?;
 
         chart
             .configure_mesh()
-            .x_label_formatter(&|d: &NaiveDate| d.format("%Y-%m-%d").to_string())
+            .x_label_formatter(&|x: &f64| {
+                (first_date + Days::new(x.max(0.0).round() as u64))
+                    .format("%Y-%m-%d")
+                    .to_string()
+            })
             .label_style(TextStyle::from(("sans-serif", 12)).with_color(label_color))
             .axis_style(axis_color)
             .light_line_style(grid_color)
             .draw()
             .map_err(actix_web::error::ErrorInternalServerError)?;
 
-        chart
-            .draw_series(LineSeries::new(
-                plot.data
-                    .points
-                    .clone()
-                    .into_iter()
-                    .map(|p| (p.date, p.value)),
-                &graph_color,
-            ))
-            .map_err(actix_web::error::ErrorInternalServerError)?
-            .label(&plot.label)
-            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], graph_color));
+        let bar_slot = 0.8 / plot.series.len() as f64;
+        for (index, series) in plot.series.iter().enumerate() {
+            let series_color = if index == 0 {
+                graph_color
+            } else {
+                Palette99::pick(index).to_rgba()
+            };
+            let coordinates = series
+                .points
+                .iter()
+                .map(|p| (days_between(first_date, p.date), p.value));
+
+            let annotation = match plot.chart_type {
+                ChartType::Line => chart.draw_series(LineSeries::new(coordinates, series_color)),
+                ChartType::Area => chart.draw_series(
+                    AreaSeries::new(coordinates, 0.0, series_color.mix(0.3))
+                        .border_style(series_color),
+                ),
+                ChartType::Bar => chart.draw_series(series.points.iter().map(|p| {
+                    let bucket_width = days_between(p.date, plot.aggregation.bucket_end(p.date));
+                    let x = days_between(first_date, p.date)
+                        + bucket_width * (0.1 + bar_slot * index as f64);
+                    Rectangle::new(
+                        [(x, 0.0), (x + bucket_width * bar_slot, p.value)],
+                        series_color.filled(),
+                    )
+                })),
+            }
+            .map_err(actix_web::error::ErrorInternalServerError)?;
+
+            annotation
+                .label(&series.label)
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], series_color));
+        }
 
         chart
             .configure_series_labels()
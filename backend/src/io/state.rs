@@ -14,5 +14,7 @@ pub fn convert_to_app_state(state: PureAppState, static_path: &str) -> Data<AppS
         f_media_index: Mutex::new(state.f_media_index),
         g_config: Mutex::new(state.g_config),
         h_block_properties: Mutex::new(state.h_block_properties),
+        i_page_properties: Mutex::new(state.i_page_properties),
+        j_encryption: Mutex::new(state.j_encryption),
     })
 }
@@ -0,0 +1,397 @@
+use crate::looksyk::index::alias::all_names_of;
+use crate::looksyk::model::{PageId, PageType, ParsedBlock, SimplePageName};
+use crate::looksyk::plot::{is_within, journal_date_of};
+use crate::looksyk::renderer::atomics::render_link;
+use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
+use crate::state::block::BlockReference;
+use crate::state::block_properties::{BlockPropertiesIndex, BlockPropertyKey};
+use crate::state::journal::JournalPageIndex;
+use crate::state::userpage::UserPageIndex;
+use chrono::{Datelike, NaiveDate};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+const NO_GROUP_LABEL: &str = "total";
+const MISSING_PROPERTY_LABEL: &str = "none";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AggregationFunction {
+    Sum,
+    Average,
+    Min,
+    Max,
+    Count,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AggregationGrouping {
+    None,
+    Day,
+    Week,
+    Month,
+    Page,
+    Property(BlockPropertyKey),
+}
+
+pub struct AggregationQuery {
+    pub property_key: BlockPropertyKey,
+    pub function: AggregationFunction,
+    pub grouping: AggregationGrouping,
+    pub tag: Option<SimplePageName>,
+    pub starting_at: Option<NaiveDate>,
+    pub ending_at: Option<NaiveDate>,
+}
+
+pub struct AggregationResult {
+    pub total: Option<f64>,
+    pub groups: Vec<AggregatedGroup>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AggregatedGroup {
+    pub label: String,
+    pub value: Option<f64>,
+}
+
+pub struct AggregationSources<'a> {
+    pub block_properties_index: &'a BlockPropertiesIndex,
+    pub user_pages: &'a UserPageIndex,
+    pub journal_pages: &'a JournalPageIndex,
+    pub journal_title_calculator_metadata: &'a JournalTitleCalculatorMetadata<'a>,
+}
+
+impl AggregationFunction {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "sum" => Some(AggregationFunction::Sum),
+            "average" => Some(AggregationFunction::Average),
+            "min" => Some(AggregationFunction::Min),
+            "max" => Some(AggregationFunction::Max),
+            "count" => Some(AggregationFunction::Count),
+            _ => None,
+        }
+    }
+
+    pub fn apply(&self, values: &[f64]) -> Option<f64> {
+        match self {
+            AggregationFunction::Sum => Some(values.iter().sum()),
+            AggregationFunction::Count => Some(values.len() as f64),
+            AggregationFunction::Average if values.is_empty() => None,
+            AggregationFunction::Average => Some(values.iter().sum::<f64>() / values.len() as f64),
+            AggregationFunction::Min => values.iter().copied().reduce(f64::min),
+            AggregationFunction::Max => values.iter().copied().reduce(f64::max),
+        }
+    }
+}
+
+impl Display for AggregationFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AggregationFunction::Sum => write!(f, "sum"),
+            AggregationFunction::Average => write!(f, "average"),
+            AggregationFunction::Min => write!(f, "min"),
+            AggregationFunction::Max => write!(f, "max"),
+            AggregationFunction::Count => write!(f, "count"),
+        }
+    }
+}
+
+impl AggregationGrouping {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "day" => AggregationGrouping::Day,
+            "week" => AggregationGrouping::Week,
+            "month" => AggregationGrouping::Month,
+            "page" => AggregationGrouping::Page,
+            _ => AggregationGrouping::Property(BlockPropertyKey {
+                value: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn requires_date(&self) -> bool {
+        matches!(
+            self,
+            AggregationGrouping::Day | AggregationGrouping::Week | AggregationGrouping::Month
+        )
+    }
+}
+
+impl Display for AggregationGrouping {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AggregationGrouping::None => write!(f, "group"),
+            AggregationGrouping::Day => write!(f, "day"),
+            AggregationGrouping::Week => write!(f, "week"),
+            AggregationGrouping::Month => write!(f, "month"),
+            AggregationGrouping::Page => write!(f, "page"),
+            AggregationGrouping::Property(key) => write!(f, "{}", key.value),
+        }
+    }
+}
+
+pub fn aggregate_property(
+    query: &AggregationQuery,
+    sources: &AggregationSources,
+) -> AggregationResult {
+    let tag_names = query
+        .tag
+        .as_ref()
+        .map(|tag| all_names_of(tag, sources.block_properties_index));
+    let filter_by_date =
+        query.starting_at.is_some() || query.ending_at.is_some() || query.grouping.requires_date();
+
+    let mut values = vec![];
+    let mut groups: BTreeMap<String, (String, Vec<f64>)> = BTreeMap::new();
+
+    for occurence in sources
+        .block_properties_index
+        .find_block_properties(&query.property_key)
+    {
+        let Some(value) = occurence.numeric_value() else {
+            continue;
+        };
+        let date = journal_date_of(&occurence.block);
+        if filter_by_date
+            && !date.is_some_and(|date| is_within(date, query.starting_at, query.ending_at))
+        {
+            continue;
+        }
+        let block = find_block(&occurence.block, sources);
+        if let Some(tag_names) = &tag_names {
+            let references_tag = block.is_some_and(|block| {
+                tag_names
+                    .iter()
+                    .any(|tag_name| block.contains_reference(tag_name))
+            });
+            if !references_tag {
+                continue;
+            }
+        }
+
+        let (sort_key, label) = group_of(&query.grouping, &occurence.block, date, block, sources);
+        values.push(value);
+        groups
+            .entry(sort_key)
+            .or_insert_with(|| (label, vec![]))
+            .1
+            .push(value);
+    }
+
+    AggregationResult {
+        total: query.function.apply(&values),
+        groups: groups
+            .into_values()
+            .map(|(label, group_values)| AggregatedGroup {
+                label,
+                value: query.function.apply(&group_values),
+            })
+            .collect(),
+    }
+}
+
+pub fn format_aggregated_value(value: Option<f64>) -> String {
+    match value {
+        Some(value) => format!("{}", (value * 100.0).round() / 100.0),
+        None => "-".to_string(),
+    }
+}
+
+fn find_block<'a>(
+    block_reference: &BlockReference,
+    sources: &AggregationSources<'a>,
+) -> Option<&'a ParsedBlock> {
+    let page = match block_reference.page_id.page_type {
+        PageType::UserPage => sources.user_pages.find(&block_reference.page_id.name),
+        PageType::JournalPage => sources.journal_pages.find(&block_reference.page_id.name),
+    };
+    page.and_then(|page| page.blocks.get(block_reference.block_number))
+}
+
+fn group_of(
+    grouping: &AggregationGrouping,
+    block_reference: &BlockReference,
+    date: Option<NaiveDate>,
+    block: Option<&ParsedBlock>,
+    sources: &AggregationSources,
+) -> (String, String) {
+    let label = match (grouping, date) {
+        (AggregationGrouping::None, _) => NO_GROUP_LABEL.to_string(),
+        (AggregationGrouping::Day, Some(date)) => date.format("%Y-%m-%d").to_string(),
+        (AggregationGrouping::Week, Some(date)) => {
+            let week = date.iso_week();
+            format!("{}-W{:02}", week.year(), week.week())
+        }
+        (AggregationGrouping::Month, Some(date)) => date.format("%Y-%m").to_string(),
+        (AggregationGrouping::Page, _) => {
+            return page_group(&block_reference.page_id, sources);
+        }
+        (AggregationGrouping::Property(key), _) => block
+            .and_then(|block| {
+                block
+                    .properties
+                    .properties
+                    .iter()
+                    .find(|property| property.key == key.value)
+            })
+            .map(|property| property.value.clone())
+            .unwrap_or(MISSING_PROPERTY_LABEL.to_string()),
+        (_, None) => NO_GROUP_LABEL.to_string(),
+    };
+    (label.clone(), label)
+}
+
+fn page_group(page_id: &PageId, sources: &AggregationSources) -> (String, String) {
+    let sort_key = match page_id.page_type {
+        PageType::JournalPage => format!("journal/{}", page_id.name.name),
+        PageType::UserPage => format!("page/{}", page_id.name.name),
+    };
+    (
+        sort_key,
+        render_link(page_id, sources.journal_title_calculator_metadata),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::page_name_str;
+    use crate::looksyk::index::block_properties::create_block_properties_index;
+    use crate::looksyk::model::ParsedMarkdownFile;
+    use crate::looksyk::parser::parse_markdown_file;
+    use crate::looksyk::property_schema::PropertySchema;
+    use crate::looksyk::reader::read_file_contents;
+    use crate::looksyk::renderer::title::builder::world_journal_title_calculator_metadata;
+    use crate::state::block_properties::builder::block_property_key;
+    use crate::state::journal::JournalPageIndex;
+    use crate::state::userpage::builder::user_page_index;
+
+    fn page(content: &str) -> ParsedMarkdownFile {
+        parse_markdown_file(read_file_contents(content))
+    }
+
+    fn journal_pages() -> JournalPageIndex {
+        let mut journal_pages = JournalPageIndex::default();
+        journal_pages.insert(
+            page_name_str("2024_01_01"),
+            page("- [[work]] hours:: 2 client:: a\n- private hours:: 5"),
+        );
+        journal_pages.insert(
+            page_name_str("2024_01_15"),
+            page("- [[work]] hours:: 1.5 client:: b"),
+        );
+        journal_pages.insert(
+            page_name_str("2024_02_03"),
+            page("- [[work]] hours:: 4 client:: a\n- [[work]] hours:: n/a"),
+        );
+        journal_pages
+    }
+
+    fn aggregate(query: AggregationQuery) -> AggregationResult {
+        let journal_pages = journal_pages();
+        let user_pages = user_page_index("notes", page("- meeting hours:: 10"));
+        let block_properties_index =
+            create_block_properties_index(&journal_pages, &user_pages, &PropertySchema::default());
+        aggregate_property(
+            &query,
+            &AggregationSources {
+                block_properties_index: &block_properties_index,
+                user_pages: &user_pages,
+                journal_pages: &journal_pages,
+                journal_title_calculator_metadata: &world_journal_title_calculator_metadata(),
+            },
+        )
+    }
+
+    fn query(function: AggregationFunction, grouping: AggregationGrouping) -> AggregationQuery {
+        AggregationQuery {
+            property_key: block_property_key("hours"),
+            function,
+            grouping,
+            tag: None,
+            starting_at: None,
+            ending_at: None,
+        }
+    }
+
+    #[test]
+    fn test_apply_should_calculate_functions() {
+        let values = [2.0, 1.5, 4.0];
+
+        assert_eq!(AggregationFunction::Sum.apply(&values), Some(7.5));
+        assert_eq!(AggregationFunction::Average.apply(&values), Some(2.5));
+        assert_eq!(AggregationFunction::Min.apply(&values), Some(1.5));
+        assert_eq!(AggregationFunction::Max.apply(&values), Some(4.0));
+        assert_eq!(AggregationFunction::Count.apply(&values), Some(3.0));
+        assert_eq!(AggregationFunction::Average.apply(&[]), None);
+        assert_eq!(AggregationFunction::Sum.apply(&[]), Some(0.0));
+    }
+
+    #[test]
+    fn test_aggregate_property_should_include_all_numeric_values_without_filter() {
+        let result = aggregate(query(AggregationFunction::Sum, AggregationGrouping::None));
+
+        assert_eq!(result.total, Some(22.5));
+        assert_eq!(
+            result.groups,
+            vec![AggregatedGroup {
+                label: "total".to_string(),
+                value: Some(22.5)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_property_should_filter_by_tag_and_group_by_month() {
+        let result = aggregate(AggregationQuery {
+            tag: Some(page_name_str("work")),
+            ..query(AggregationFunction::Sum, AggregationGrouping::Month)
+        });
+
+        assert_eq!(result.total, Some(7.5));
+        assert_eq!(
+            result.groups,
+            vec![
+                AggregatedGroup {
+                    label: "2024-01".to_string(),
+                    value: Some(3.5)
+                },
+                AggregatedGroup {
+                    label: "2024-02".to_string(),
+                    value: Some(4.0)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_property_should_filter_by_date_range_and_group_by_property() {
+        let result = aggregate(AggregationQuery {
+            starting_at: NaiveDate::from_ymd_opt(2024, 1, 2),
+            ..query(
+                AggregationFunction::Max,
+                AggregationGrouping::from_name("client"),
+            )
+        });
+
+        assert_eq!(result.total, Some(4.0));
+        let labels: Vec<&str> = result.groups.iter().map(|g| g.label.as_str()).collect();
+        assert_eq!(labels, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_aggregate_property_should_group_by_iso_week() {
+        let result = aggregate(query(AggregationFunction::Count, AggregationGrouping::Week));
+
+        let labels: Vec<&str> = result.groups.iter().map(|g| g.label.as_str()).collect();
+        assert_eq!(labels, vec!["2024-W01", "2024-W03", "2024-W05"]);
+        assert_eq!(result.groups[0].value, Some(2.0));
+    }
+
+    #[test]
+    fn test_format_aggregated_value_should_round_to_two_decimals() {
+        assert_eq!(format_aggregated_value(Some(3.0)), "3");
+        assert_eq!(format_aggregated_value(Some(2.0 / 3.0)), "0.67");
+        assert_eq!(format_aggregated_value(None), "-");
+    }
+}
@@ -1,6 +1,7 @@
 use crate::looksyk::model::{BlockToken, BlockTokenType, SimplePageName};
 
 #[cfg(test)]
+#[allow(clippy::items_after_test_module, clippy::get_first)]
 pub mod test_builder {
     use crate::looksyk::builder::{page_name_str, text_token_str};
     use crate::looksyk::datatypes::AssetDescriptor;
@@ -72,13 +73,11 @@ pub mod test_builder {
     }
 
     pub fn empty_block_properties_index() -> BlockPropertiesIndex {
-        BlockPropertiesIndex {
-            entries: HashMap::new(),
-        }
+        BlockPropertiesIndex::default()
     }
 
     pub fn any_parsed_markdown_file() -> ParsedMarkdownFile {
-        ParsedMarkdownFile { blocks: vec![] }
+        ParsedMarkdownFile::empty()
     }
 
     pub fn block_content(text: &str) -> BlockContent {
@@ -4,23 +4,21 @@ use crate::looksyk::model::{no_text_content, BlockContent, ParsedBlock, ParsedMa
 use crate::looksyk::parser::BlockProperties;
 
 pub fn get_asset_meta_info_table(size: u64, last_changed: i64) -> ParsedMarkdownFile {
-    ParsedMarkdownFile {
-        blocks: vec![ParsedBlock {
-            indentation: 0,
-            content: vec![BlockContent {
-                as_text: no_text_content(),
-                as_tokens: vec![text_token(format!(
-                    "### Properties \n\n\
+    ParsedMarkdownFile::from_blocks(vec![ParsedBlock {
+        indentation: 0,
+        content: vec![BlockContent {
+            as_text: no_text_content(),
+            as_tokens: vec![text_token(format!(
+                "### Properties \n\n\
                              | Property | Value |\n | :-- | :-- |\n\
                              | Size | {} |\n\
                              | Last Changed | {} |",
-                    filesize_as_human_string(size),
-                    timestamp_as_human_string(last_changed),
-                ))],
-            }],
-            properties: BlockProperties::empty(),
+                filesize_as_human_string(size),
+                timestamp_as_human_string(last_changed),
+            ))],
         }],
-    }
+        properties: BlockProperties::empty(),
+    }])
 }
 
 #[cfg(test)]
@@ -1,9 +1,10 @@
 use crate::looksyk::builtinpage::generating_page_util::create_textblock;
 use crate::looksyk::datatypes::AssetDescriptor;
-use crate::looksyk::index::media::MediaIndex;
+use crate::looksyk::index::media::{IndexedMedia, MediaIndex};
 use crate::looksyk::media::media_type::{get_media_type_from_extension, MediaType};
+use crate::looksyk::media::thumbnail::{get_thumbnail_path, OVERVIEW_THUMBNAIL_SIZE};
 use crate::looksyk::model::{ParsedBlock, ParsedMarkdownFile};
-use crate::looksyk::syntax::markdown::render_as_link;
+use crate::looksyk::syntax::markdown::{render_as_image, render_as_link};
 use humansize::{format_size, DECIMAL};
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -11,21 +12,25 @@ use std::collections::HashMap;
 pub fn generate_assets_overview_page(
     media_index: &MediaIndex,
     sizes: HashMap<String, u64>,
+    unused_media: Vec<IndexedMedia>,
 ) -> ParsedMarkdownFile {
+    let unused_assets = describe_unused_assets(unused_media, &sizes);
     let sorted_assets = sort_assets(&media_index, sizes);
 
     let result = vec![
-        render_table("Images", sorted_assets.images),
+        render_image_table("Images", sorted_assets.images),
         render_table("PDFs", sorted_assets.pdfs),
         render_table("Htmls", sorted_assets.htmls),
         render_table("Videos", sorted_assets.videos),
         render_table("Audios", sorted_assets.audios),
         render_table("Code", sorted_assets.codes),
         render_table("Texts", sorted_assets.texts),
+        render_table("Tables", sorted_assets.tables),
         render_table("Other", sorted_assets.others),
+        render_table("Unused", unused_assets),
     ];
 
-    ParsedMarkdownFile { blocks: result }
+    ParsedMarkdownFile::from_blocks(result)
 }
 
 fn render_table(asset_type_name: &str, assets: Vec<AssetDescription>) -> ParsedBlock {
@@ -43,6 +48,41 @@ fn render_table(asset_type_name: &str, assets: Vec<AssetDescription>) -> ParsedB
     create_textblock(result.as_str(), 0)
 }
 
+fn render_image_table(asset_type_name: &str, assets: Vec<AssetDescription>) -> ParsedBlock {
+    let mut result =
+        format!("### {asset_type_name} \n \n | Preview | Name | Size |\n| :-- | :-- | :-- |\n");
+    for asset in assets {
+        result.push_str(&format!(
+            "| {} | {} | {} |\n",
+            render_as_image(
+                &asset.indexed_media.get_display_name(),
+                &get_thumbnail_path(&asset.indexed_media, OVERVIEW_THUMBNAIL_SIZE)
+            ),
+            render_as_link(
+                &asset.indexed_media.get_display_name(),
+                &asset.indexed_media.get_qualified_path()
+            ),
+            format_size(asset.file_size, DECIMAL)
+        ));
+    }
+    create_textblock(result.as_str(), 0)
+}
+
+fn describe_unused_assets(
+    unused_media: Vec<IndexedMedia>,
+    sizes: &HashMap<String, u64>,
+) -> Vec<AssetDescription> {
+    let mut result: Vec<AssetDescription> = unused_media
+        .into_iter()
+        .map(|media| AssetDescription {
+            file_size: *sizes.get(&media.file_name).unwrap_or(&0),
+            indexed_media: AssetDescriptor::new(media.file_name),
+        })
+        .collect();
+    result.sort_by(sort_by_filesize);
+    result
+}
+
 fn sort_assets(media_index: &&MediaIndex, sizes: HashMap<String, u64>) -> SortedAssets {
     let mut images: Vec<AssetDescription> = vec![];
     let mut videos: Vec<AssetDescription> = vec![];
@@ -52,6 +92,7 @@ fn sort_assets(media_index: &&MediaIndex, sizes: HashMap<String, u64>) -> Sorted
     let mut texts: Vec<AssetDescription> = vec![];
     let mut htmls: Vec<AssetDescription> = vec![];
     let mut pdfs: Vec<AssetDescription> = vec![];
+    let mut tables: Vec<AssetDescription> = vec![];
 
     for media in &media_index.media {
         let indexed_media = AssetDescriptor::new(media.file_name.clone());
@@ -86,6 +127,9 @@ fn sort_assets(media_index: &&MediaIndex, sizes: HashMap<String, u64>) -> Sorted
             MediaType::Pdf => {
                 pdfs.push(asset_description);
             }
+            MediaType::Table => {
+                tables.push(asset_description);
+            }
         }
     }
     images.sort_by(sort_by_filesize);
@@ -96,6 +140,7 @@ fn sort_assets(media_index: &&MediaIndex, sizes: HashMap<String, u64>) -> Sorted
     texts.sort_by(sort_by_filesize);
     htmls.sort_by(sort_by_filesize);
     pdfs.sort_by(sort_by_filesize);
+    tables.sort_by(sort_by_filesize);
 
     SortedAssets {
         images,
@@ -106,6 +151,7 @@ fn sort_assets(media_index: &&MediaIndex, sizes: HashMap<String, u64>) -> Sorted
         texts,
         htmls,
         pdfs,
+        tables,
     }
 }
 
@@ -122,6 +168,7 @@ struct SortedAssets {
     texts: Vec<AssetDescription>,
     htmls: Vec<AssetDescription>,
     pdfs: Vec<AssetDescription>,
+    tables: Vec<AssetDescription>,
 }
 
 struct AssetDescription {
@@ -182,6 +229,22 @@ mod tests {
         assert_eq!(result.audios[0].indexed_media.get_display_name(), "c.mp3");
     }
 
+    #[test]
+    fn test_describe_unused_assets_should_sort_by_filesize() {
+        let sizes = vec![("a.jpg".to_string(), 1), ("b.pdf".to_string(), 3)]
+            .into_iter()
+            .collect();
+
+        let result = describe_unused_assets(
+            vec![indexed_media("a.jpg", ""), indexed_media("b.pdf", "")],
+            &sizes,
+        );
+
+        assert_eq!(result[0].indexed_media.get_display_name(), "b.pdf");
+        assert_eq!(result[0].file_size, 3);
+        assert_eq!(result[1].indexed_media.get_display_name(), "a.jpg");
+    }
+
     #[test]
     fn test_render_table_should_render_table() {
         let asset_description = AssetDescription {
@@ -196,6 +259,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_render_image_table_should_render_thumbnails() {
+        let asset_description = AssetDescription {
+            indexed_media: AssetDescriptor::new("a.jpg".to_string()),
+            file_size: 1,
+        };
+        let result = render_image_table("Images", vec![asset_description]);
+
+        assert_eq!(
+            get_first_text_payload(&result),
+            "### Images \n \n | Preview | Name | Size |\n| :-- | :-- | :-- |\n| ![a.jpg](/assets/thumbnail/a.jpg?size=64) | [a.jpg](/assets/a.jpg) | 1 B |\n"
+        );
+    }
+
     fn get_first_text_payload(result: &ParsedBlock) -> String {
         result
             .content
@@ -0,0 +1,93 @@
+use crate::looksyk::builder::text_token;
+use crate::looksyk::builtinpage::generating_page_util::create_textblock;
+use crate::looksyk::health::{HealthFinding, HealthReport, ALL_HEALTH_FINDING_TYPES};
+use crate::looksyk::model::{
+    BlockToken, BlockTokenType, PageType, ParsedBlock, ParsedMarkdownFile,
+};
+
+pub fn generate_graph_health_page(health_report: &HealthReport) -> ParsedMarkdownFile {
+    let mut result = vec![];
+    if health_report.is_healthy() {
+        result.push(create_textblock("No problems found in the graph.", 0));
+        return ParsedMarkdownFile::from_blocks(result);
+    }
+
+    for finding_type in ALL_HEALTH_FINDING_TYPES {
+        let findings = health_report.findings_of_type(finding_type);
+        result.push(create_textblock(
+            &format!("### {} ({})", finding_type.title(), findings.len()),
+            0,
+        ));
+        if findings.is_empty() {
+            result.push(create_textblock("Nothing found.", 1));
+        }
+        for finding in findings {
+            result.push(render_finding(finding));
+        }
+    }
+
+    ParsedMarkdownFile::from_blocks(result)
+}
+
+fn render_finding(finding: &HealthFinding) -> ParsedBlock {
+    let page_id = &finding.reference.page_id;
+    let mut block = ParsedBlock::from_tokens(vec![
+        BlockToken {
+            block_token_type: match page_id.page_type {
+                PageType::UserPage => BlockTokenType::Link,
+                PageType::JournalPage => BlockTokenType::JournalLink,
+            },
+            payload: page_id.name.name.clone(),
+        },
+        text_token(format!(
+            " (block {}): {}",
+            finding.reference.block_number + 1,
+            finding.message
+        )),
+    ]);
+    block.indentation = 1;
+    block
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::test_builder::{
+        extract_textblock_line_at, extract_very_first_textblock_line, user_page_id,
+    };
+    use crate::looksyk::health::HealthFindingType;
+
+    #[test]
+    fn test_generate_graph_health_page_should_report_healthy_graph() {
+        let result = generate_graph_health_page(&HealthReport { findings: vec![] });
+
+        assert_eq!(
+            extract_very_first_textblock_line(&result),
+            "No problems found in the graph."
+        );
+    }
+
+    #[test]
+    fn test_generate_graph_health_page_should_link_to_offending_block() {
+        let result = generate_graph_health_page(&HealthReport {
+            findings: vec![HealthFinding {
+                finding_type: HealthFindingType::BrokenLink,
+                reference: user_page_id("a").block_reference(2),
+                message: "Link to missing page \"b\"".to_string(),
+            }],
+        });
+
+        assert_eq!(
+            extract_very_first_textblock_line(&result),
+            "### Links to missing pages (1)"
+        );
+        let finding = &result.blocks[1].content[0].as_tokens;
+        assert_eq!(finding[0].block_token_type, BlockTokenType::Link);
+        assert_eq!(finding[0].payload, "a");
+        assert_eq!(finding[1].payload, " (block 3): Link to missing page \"b\"");
+        assert_eq!(
+            extract_textblock_line_at(&result, 2),
+            "### Orphan pages (0)"
+        );
+    }
+}
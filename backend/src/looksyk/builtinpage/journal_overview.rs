@@ -1,3 +1,6 @@
+use crate::looksyk::calendar::{
+    days_between, is_week_start, leading_empty_days, trailing_empty_days,
+};
 use crate::looksyk::model::{ParsedBlock, ParsedMarkdownFile, SimplePageName};
 use chrono::{Datelike, NaiveDate};
 
@@ -5,32 +8,26 @@ pub fn generate_journal_overview(journal_entries: Vec<SimplePageName>) -> Parsed
     let mut result = vec![];
     let mut sorted_journals = journal_entries
         .iter()
-        .filter(|j| j.name.len() == 10)
-        .map(|j| NaiveDate::parse_from_str(&j.name, "%Y_%m_%d").unwrap())
+        .filter_map(parse_journal_date)
         .collect::<Vec<NaiveDate>>();
     sorted_journals.sort();
     if sorted_journals.is_empty() {
-        return ParsedMarkdownFile {
-            blocks: vec![ParsedBlock::artificial_text_block(
-                "No journal entries found.",
-            )],
-        };
+        return ParsedMarkdownFile::from_blocks(vec![ParsedBlock::artificial_text_block(
+            "No journal entries found.",
+        )]);
     }
 
     let min_date = sorted_journals[0];
     let max_date = sorted_journals[sorted_journals.len() - 1];
-    let mut first = true;
+    let mut previous_date: Option<NaiveDate> = None;
 
     let mut as_text = "".to_string();
-    let mut weekday = min_date.weekday().num_days_from_monday();
-    for date in min_date.iter_days() {
+    for date in days_between(min_date, max_date) {
         let first_of_month = date.day() == 1;
 
-        if first_of_month || first {
-            if !first {
-                for _ in weekday..7 {
-                    as_text.push_str(" | ");
-                }
+        if first_of_month || previous_date.is_none() {
+            if let Some(previous_date) = previous_date {
+                push_empty_cells(&mut as_text, trailing_empty_days(previous_date));
                 result.push(ParsedBlock::artificial_text_block(&as_text));
                 as_text = "".to_string();
             }
@@ -41,11 +38,9 @@ pub fn generate_journal_overview(journal_entries: Vec<SimplePageName>) -> Parsed
 
             as_text.push_str(format!("### {}\n\n", date.format("%B %Y")).as_str());
             as_text.push_str("| Monday | Tuesday | Wednesday | Thursday | Friday | Saturday | Sunday |\n| --- | --- | --- | --- | --- | --- | --- |\n|");
-            for _ in 0..weekday {
-                as_text.push_str(" | ");
-            }
+            push_empty_cells(&mut as_text, leading_empty_days(date));
         }
-        if weekday == 0 {
+        if is_week_start(date) {
             as_text.push_str("\n|");
         }
         let date_url_str = date.format("%Y_%m_%d").to_string();
@@ -57,17 +52,10 @@ pub fn generate_journal_overview(journal_entries: Vec<SimplePageName>) -> Parsed
         } else {
             as_text.push_str(&format!("<div class=\"cal-item filled-cal-item\">[{date_str}](journal/{date_url_str})</div>|"));
         }
-        weekday = (weekday + 1) % 7;
-
-        if date >= max_date {
-            break;
-        }
-        first = false;
+        previous_date = Some(date);
     }
 
-    for _ in weekday..7 {
-        as_text.push_str(" | ");
-    }
+    push_empty_cells(&mut as_text, trailing_empty_days(max_date));
 
     result.push(ParsedBlock::artificial_text_block(&as_text));
     if max_date.month() != 1 {
@@ -79,7 +67,20 @@ pub fn generate_journal_overview(journal_entries: Vec<SimplePageName>) -> Parsed
 
     result.reverse();
 
-    ParsedMarkdownFile { blocks: result }
+    ParsedMarkdownFile::from_blocks(result)
+}
+
+fn push_empty_cells(as_text: &mut String, count: u32) {
+    for _ in 0..count {
+        as_text.push_str(" | ");
+    }
+}
+
+pub fn parse_journal_date(journal_name: &SimplePageName) -> Option<NaiveDate> {
+    if journal_name.name.len() != 10 {
+        return None;
+    }
+    NaiveDate::parse_from_str(&journal_name.name, "%Y_%m_%d").ok()
 }
 
 #[cfg(test)]
@@ -173,6 +174,29 @@ mod tests {
 |<div class=\"cal-item\">[30.](journal/2024_12_30)</div>|<div class=\"cal-item\">[31.](journal/2024_12_31)</div>| |  |  |  |  | ");
     }
 
+    #[test]
+    fn test_generate_journal_overview_should_not_pad_full_weeks() {
+        let journal_entries = vec![page_name_str("2025_01_13"), page_name_str("2025_01_19")];
+
+        let result = super::generate_journal_overview(journal_entries);
+
+        assert!(extract_very_first_textblock_line(&result)
+            .ends_with("<div class=\"cal-item filled-cal-item\">[19.](journal/2025_01_19)</div>|"));
+    }
+
+    #[test]
+    fn test_generate_journal_overview_should_skip_malformed_journal_names() {
+        let journal_entries = vec![
+            page_name_str("2025_01_15"),
+            page_name_str("2025_13_01"),
+            page_name_str("notes"),
+        ];
+
+        let result = super::generate_journal_overview(journal_entries);
+
+        assert_eq!(result.blocks.len(), 1);
+    }
+
     #[test]
     fn test_generate_journal_overview_empty() {
         let journal_entries = vec![];
@@ -183,7 +207,7 @@ mod tests {
         assert_eq!(
             result.blocks[0].content[0]
                 .as_tokens
-                .get(0)
+                .first()
                 .unwrap()
                 .payload,
             "No journal entries found."
@@ -1,7 +1,9 @@
 pub mod asset_metainfo_table;
 pub mod assets_overview;
 mod generating_page_util;
+pub mod graph_health;
 pub mod help_page;
 pub mod journal_overview;
 pub mod page_not_found;
+pub mod property_validation;
 pub mod user_page_overview;
@@ -0,0 +1,100 @@
+use crate::looksyk::builder::text_token;
+use crate::looksyk::builtinpage::generating_page_util::create_textblock;
+use crate::looksyk::model::{
+    BlockToken, BlockTokenType, PageType, ParsedBlock, ParsedMarkdownFile,
+};
+use crate::looksyk::property_schema::{PropertySchema, PropertyValidationFinding};
+
+pub fn generate_property_validation_page(
+    schema: &PropertySchema,
+    findings: &[PropertyValidationFinding],
+) -> ParsedMarkdownFile {
+    let mut result = vec![];
+    if schema.definitions.is_empty() {
+        result.push(create_textblock(
+            "No property schema configured. Declare property types in the section property_schema of the graph configuration.",
+            0,
+        ));
+        return ParsedMarkdownFile::from_blocks(result);
+    }
+    if findings.is_empty() {
+        result.push(create_textblock(
+            "All property values match the property schema.",
+            0,
+        ));
+        return ParsedMarkdownFile::from_blocks(result);
+    }
+
+    result.push(create_textblock(
+        &format!("### Invalid property values ({})", findings.len()),
+        0,
+    ));
+    for finding in findings {
+        result.push(render_finding(finding));
+    }
+    ParsedMarkdownFile::from_blocks(result)
+}
+
+fn render_finding(finding: &PropertyValidationFinding) -> ParsedBlock {
+    let page_id = &finding.block.page_id;
+    let mut block = ParsedBlock::from_tokens(vec![
+        BlockToken {
+            block_token_type: match page_id.page_type {
+                PageType::UserPage => BlockTokenType::Link,
+                PageType::JournalPage => BlockTokenType::JournalLink,
+            },
+            payload: page_id.name.name.clone(),
+        },
+        text_token(format!(
+            " (block {}): {}",
+            finding.block.block_number + 1,
+            finding.error.message
+        )),
+    ]);
+    block.indentation = 1;
+    block
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::test_builder::{
+        extract_very_first_textblock_line, journal_page_id,
+    };
+    use crate::looksyk::property_schema::builder::property_schema_with;
+    use crate::looksyk::property_schema::{PropertyType, PropertyValueError};
+
+    #[test]
+    fn test_generate_property_validation_page_should_report_missing_schema() {
+        let result = generate_property_validation_page(&PropertySchema::default(), &[]);
+
+        assert!(extract_very_first_textblock_line(&result).starts_with("No property schema"));
+    }
+
+    #[test]
+    fn test_generate_property_validation_page_should_link_to_offending_block() {
+        let result = generate_property_validation_page(
+            &property_schema_with("hours", PropertyType::Number),
+            &[PropertyValidationFinding {
+                block: journal_page_id("2024_01_01").block_reference(0),
+                error: PropertyValueError {
+                    key: "hours".to_string(),
+                    value: "3h".to_string(),
+                    message: "Value '3h' of property 'hours' is not a valid number".to_string(),
+                },
+            }],
+        );
+
+        assert_eq!(
+            extract_very_first_textblock_line(&result),
+            "### Invalid property values (1)"
+        );
+        let finding = &result.blocks[1].content[0].as_tokens;
+        assert_eq!(finding[0].block_token_type, BlockTokenType::JournalLink);
+        assert_eq!(finding[0].payload, "2024_01_01");
+        assert_eq!(
+            finding[1].payload,
+            " (block 1): Value '3h' of property 'hours' is not a valid number"
+        );
+    }
+}
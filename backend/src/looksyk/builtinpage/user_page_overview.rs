@@ -1,7 +1,9 @@
 use crate::looksyk::builtinpage::generating_page_util::create_textblock;
+use crate::looksyk::media::table::escape_cell;
 use crate::looksyk::model::{
     BlockToken, BlockTokenType, PageId, PageType, ParsedBlock, ParsedMarkdownFile, SimplePageName,
 };
+use crate::looksyk::queries::pages::page_property_cell;
 use crate::state::tag::TagIndex;
 use crate::state::userpage::UserPageIndex;
 use std::collections::HashSet;
@@ -9,13 +11,14 @@ use std::collections::HashSet;
 pub fn generate_overview_page(
     all_tags: &TagIndex,
     all_pages: &UserPageIndex,
+    page_property_columns: &[String],
 ) -> ParsedMarkdownFile {
     let mut result = vec![];
 
     if all_tags.entries.is_empty() && all_pages.entries.is_empty() {
         result.push(create_textblock("No tags or pages found!", 0));
     } else {
-        let mut result_table = vec![table_headline()];
+        let mut result_table = vec![table_headline(page_property_columns)];
 
         let mut visited_pages = HashSet::new();
 
@@ -29,6 +32,7 @@ pub fn generate_overview_page(
                 &mut result_table,
                 &tag.name,
                 all_tags.entries.get(tag).unwrap(),
+                page_property_columns,
             );
             visited_pages.insert(tag);
         }
@@ -43,6 +47,7 @@ pub fn generate_overview_page(
                     &mut result_table,
                     simple_page_name,
                     &HashSet::new(),
+                    page_property_columns,
                 );
             }
         }
@@ -50,7 +55,7 @@ pub fn generate_overview_page(
         result.push(ParsedBlock::from_tokens(result_table))
     }
 
-    ParsedMarkdownFile { blocks: result }
+    ParsedMarkdownFile::from_blocks(result)
 }
 
 fn get_sorted(all_tags: &TagIndex) -> Vec<&PageId> {
@@ -59,10 +64,16 @@ fn get_sorted(all_tags: &TagIndex) -> Vec<&PageId> {
     entries
 }
 
-fn table_headline() -> BlockToken {
+fn table_headline(page_property_columns: &[String]) -> BlockToken {
+    let mut payload = "| pagename | number of backlinks | page has content |".to_string();
+    for column in page_property_columns {
+        payload.push_str(&format!(" {} |", escape_cell(column)));
+    }
+    payload.push_str("\n| :-- | :-- | :-- |");
+    payload.push_str(&" :-- |".repeat(page_property_columns.len()));
+    payload.push('\n');
     BlockToken {
-        payload: "| pagename | number of backlinks | page has content |\n| :-- | :-- | :-- |\n"
-            .to_string(),
+        payload,
         block_token_type: BlockTokenType::Text,
     }
 }
@@ -72,6 +83,7 @@ fn render_table_line(
     result_table: &mut Vec<BlockToken>,
     tag: &SimplePageName,
     references: &HashSet<PageId>,
+    page_property_columns: &[String],
 ) {
     result_table.push(BlockToken {
         block_token_type: BlockTokenType::Text,
@@ -81,14 +93,21 @@ fn render_table_line(
         block_token_type: BlockTokenType::Link,
         payload: tag.name.clone(),
     });
+    let mut payload = format!(
+        " | {} | {} |",
+        references.len(),
+        get_display_text_page_created(tag, all_pages)
+    );
+    for column in page_property_columns {
+        payload.push_str(&format!(
+            " {} |",
+            page_property_cell(all_pages.find(tag), column)
+        ));
+    }
+    payload.push('\n');
     result_table.push(BlockToken {
         block_token_type: BlockTokenType::Text,
-        payload: format!(
-            " | {} | {} |\n",
-            references.len(),
-            get_display_text_page_created(tag, all_pages)
-        )
-        .to_string(),
+        payload,
     });
 }
 
@@ -103,12 +122,14 @@ fn get_display_text_page_created(
 }
 
 #[cfg(test)]
+#[allow(clippy::get_first)]
 mod tests {
     use std::collections::HashMap;
 
     use crate::looksyk::builder::page_name_str;
     use crate::looksyk::builder::test_builder::user_page_id;
     use crate::looksyk::builtinpage::user_page_overview::generate_overview_page;
+    use crate::looksyk::model::builder::block_with_property;
     use crate::looksyk::model::{BlockToken, BlockTokenType, ParsedBlock, ParsedMarkdownFile};
     use crate::state::tag::TagIndex;
     use crate::state::userpage::UserPageIndex;
@@ -122,6 +143,7 @@ mod tests {
             &UserPageIndex {
                 entries: HashMap::new(),
             },
+            &[],
         );
         assert_eq!(result.blocks.len(), 1);
 
@@ -140,6 +162,7 @@ mod tests {
             &UserPageIndex {
                 entries: HashMap::new(),
             },
+            &[],
         );
         assert_eq!(result.blocks.len(), 1);
 
@@ -176,12 +199,9 @@ mod tests {
             vec![user_page_id("source")].into_iter().collect(),
         );
         let mut data = HashMap::new();
-        data.insert(
-            page_name_str("target"),
-            ParsedMarkdownFile { blocks: vec![] },
-        );
+        data.insert(page_name_str("target"), ParsedMarkdownFile::empty());
         let result =
-            generate_overview_page(&TagIndex { entries }, &UserPageIndex { entries: data });
+            generate_overview_page(&TagIndex { entries }, &UserPageIndex { entries: data }, &[]);
         assert_eq!(result.blocks.len(), 1);
 
         let first_block = result.blocks.get(0).unwrap();
@@ -211,15 +231,13 @@ mod tests {
     #[test]
     fn should_append_non_referenced_page() {
         let mut all_pages = HashMap::new();
-        all_pages.insert(
-            page_name_str("MyPage"),
-            ParsedMarkdownFile { blocks: vec![] },
-        );
+        all_pages.insert(page_name_str("MyPage"), ParsedMarkdownFile::empty());
         let result = generate_overview_page(
             &TagIndex {
                 entries: HashMap::new(),
             },
             &UserPageIndex { entries: all_pages },
+            &[],
         );
         assert_eq!(result.blocks.len(), 1);
 
@@ -248,6 +266,29 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn should_render_selected_page_properties_as_columns() {
+        let mut all_pages = HashMap::new();
+        all_pages.insert(
+            page_name_str("MyPage"),
+            ParsedMarkdownFile::from_blocks(vec![block_with_property("status", "done")]),
+        );
+        let result = generate_overview_page(
+            &TagIndex {
+                entries: HashMap::new(),
+            },
+            &UserPageIndex { entries: all_pages },
+            &["status".to_string(), "owner".to_string()],
+        );
+
+        let tokens = &result.blocks[0].content[0].as_tokens;
+        assert_eq!(
+            tokens[0].payload,
+            "| pagename | number of backlinks | page has content | status | owner |\n| :-- | :-- | :-- | :-- | :-- |\n"
+        );
+        assert_eq!(tokens[3].payload, " | 0 | yes | done |  |\n");
+    }
+
     fn block_contains_markdown_text(block: &ParsedBlock, markdown_text: &str, indentation: usize) {
         assert_eq!(block.indentation, indentation);
         assert_eq!(block.content.len(), 1);
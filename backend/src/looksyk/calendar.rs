@@ -0,0 +1,63 @@
+use chrono::{Datelike, NaiveDate};
+
+pub const DAYS_PER_WEEK: u32 = 7;
+
+/// Empty cells in front of the date when weeks start on monday.
+pub fn leading_empty_days(date: NaiveDate) -> u32 {
+    date.weekday().num_days_from_monday()
+}
+
+/// Empty cells after the date until the end of its week.
+pub fn trailing_empty_days(date: NaiveDate) -> u32 {
+    DAYS_PER_WEEK - 1 - leading_empty_days(date)
+}
+
+pub fn is_week_start(date: NaiveDate) -> bool {
+    leading_empty_days(date) == 0
+}
+
+/// All days from first to last, both included.
+pub fn days_between(first: NaiveDate, last: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+    first.iter_days().take_while(move |date| *date <= last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_empty_days_of_week() {
+        assert_eq!(leading_empty_days(date(2025, 1, 13)), 0);
+        assert_eq!(trailing_empty_days(date(2025, 1, 13)), 6);
+        assert_eq!(leading_empty_days(date(2025, 1, 15)), 2);
+        assert_eq!(trailing_empty_days(date(2025, 1, 15)), 4);
+        assert_eq!(leading_empty_days(date(2025, 1, 19)), 6);
+        assert_eq!(trailing_empty_days(date(2025, 1, 19)), 0);
+    }
+
+    #[test]
+    fn test_is_week_start() {
+        assert!(is_week_start(date(2025, 1, 13)));
+        assert!(!is_week_start(date(2025, 1, 19)));
+    }
+
+    #[test]
+    fn test_days_between() {
+        let days: Vec<NaiveDate> = days_between(date(2024, 12, 30), date(2025, 1, 2)).collect();
+
+        assert_eq!(
+            days,
+            vec![
+                date(2024, 12, 30),
+                date(2024, 12, 31),
+                date(2025, 1, 1),
+                date(2025, 1, 2)
+            ]
+        );
+        assert_eq!(days_between(date(2025, 1, 2), date(2025, 1, 1)).count(), 0);
+    }
+}
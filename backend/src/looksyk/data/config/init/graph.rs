@@ -4,11 +4,15 @@ use crate::io::fs::config::save_config_to_file;
 use crate::io::fs::media::write_media_config;
 use crate::io::fs::version::save_graph_version;
 use crate::looksyk::data::config::init::theme::init_empty_user_theme_if_non_existent;
-use crate::looksyk::data::config::runtime_graph_configuration::{Appearance, Config, Design};
+use crate::looksyk::data::config::runtime_graph_configuration::{
+    Appearance, Config, Design, EncryptionConfiguration,
+};
 use crate::looksyk::data::config::runtime_graph_configuration::{
     JournalConfigration, JournalTitleFormat, ShowWeekdayInTitle,
 };
 use crate::looksyk::index::media::MediaIndex;
+use crate::looksyk::media::upload_policy::UploadPolicy;
+use crate::looksyk::property_schema::PropertySchema;
 use crate::state::application_state::GraphRootLocation;
 
 pub fn init_graph_if_needed(data_root_location: &GraphRootLocation) {
@@ -43,6 +47,9 @@ fn default_configuration() -> Config {
         },
         title: Some("No Graph Title".to_string()),
         journal_configuration: default_journal_configuration(),
+        encryption: EncryptionConfiguration::default(),
+        upload_policy: UploadPolicy::default(),
+        property_schema: PropertySchema::default(),
     }
 }
 
@@ -1,3 +1,5 @@
+use crate::looksyk::media::upload_policy::UploadPolicy;
+use crate::looksyk::property_schema::PropertySchema;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::str::FromStr;
@@ -8,6 +10,16 @@ pub struct Config {
     pub design: Design,
     pub journal_configuration: JournalConfigration,
     pub title: Option<String>,
+    pub encryption: EncryptionConfiguration,
+    pub upload_policy: UploadPolicy,
+    pub property_schema: PropertySchema,
+}
+
+#[derive(Clone, Default)]
+pub struct EncryptionConfiguration {
+    pub namespaces: Vec<String>,
+    pub salt: Option<String>,
+    pub verification: Option<String>,
 }
 
 #[derive(Clone)]
@@ -130,9 +142,11 @@ pub mod builder {
     use crate::io::http::routes::to_wiki_page_url;
     use crate::looksyk::data::config::init::graph::default_journal_configuration;
     use crate::looksyk::data::config::runtime_graph_configuration::{
-        Appearance, Config, Design, Favourite,
+        Appearance, Config, Design, EncryptionConfiguration, Favourite,
     };
+    use crate::looksyk::media::upload_policy::UploadPolicy;
     use crate::looksyk::model::SimplePageName;
+    use crate::looksyk::property_schema::PropertySchema;
 
     pub fn page_favourite_str(name: &str) -> Favourite {
         Favourite {
@@ -149,6 +163,9 @@ pub mod builder {
             favourites: vec![page_favourite_str(fav)],
             title: None,
             journal_configuration: default_journal_configuration(),
+            encryption: EncryptionConfiguration::default(),
+            upload_policy: UploadPolicy::default(),
+            property_schema: PropertySchema::default(),
         }
     }
 
@@ -158,6 +175,9 @@ pub mod builder {
             design: empty_design(),
             title: None,
             journal_configuration: default_journal_configuration(),
+            encryption: EncryptionConfiguration::default(),
+            upload_policy: UploadPolicy::default(),
+            property_schema: PropertySchema::default(),
         }
     }
 
@@ -13,6 +13,7 @@ pub struct Configuration {
     pub max_inline_filesize: u64,
     pub static_path: String,
     pub overwrite_graph_location: Option<GraphRootLocation>,
+    pub check_graph: bool,
 }
 
 pub fn get_default_configuration() -> Configuration {
@@ -22,6 +23,7 @@ pub fn get_default_configuration() -> Configuration {
         max_inline_filesize: MAX_INLINE_FILESIZE,
         static_path: DEFAULT_STATIC_PATH.to_string(),
         overwrite_graph_location: None,
+        check_graph: false,
     }
 }
 
@@ -37,6 +39,7 @@ impl Configuration {
                 .static_path
                 .unwrap_or_else(|| self.static_path.clone()),
             max_inline_filesize: self.max_inline_filesize,
+            check_graph: cli_args.check.unwrap_or(self.check_graph),
         }
     }
 }
@@ -47,6 +50,7 @@ pub struct CliArgs {
     pub port: Option<u16>,
     pub static_path: Option<String>,
     pub external_app: Option<bool>,
+    pub check: Option<bool>,
 }
 
 fn application_host_from_cli_args(cli_args: &CliArgs) -> &'static str {
@@ -68,6 +72,7 @@ mod tests {
             port: None,
             static_path: None,
             external_app: None,
+            check: None,
         };
 
         let result = default_config.overwrite(cli_args);
@@ -83,6 +88,7 @@ mod tests {
             port: None,
             static_path: None,
             external_app: Some(true),
+            check: None,
         };
 
         let result = default_config.overwrite(cli_args);
@@ -99,10 +105,27 @@ mod tests {
             port: None,
             static_path: None,
             external_app: Some(false),
+            check: None,
         };
 
         let result = default_config.overwrite(cli_args);
 
         assert_eq!(result.application_host, super::APPLICATION_INTERNAL_HOST);
     }
+
+    #[test]
+    fn test_overwrite_defaults_should_enable_graph_check_when_check_set() {
+        let default_config = super::get_default_configuration();
+        let cli_args = super::CliArgs {
+            graph_location: None,
+            port: None,
+            static_path: None,
+            external_app: None,
+            check: Some(true),
+        };
+
+        let result = default_config.overwrite(cli_args);
+
+        assert!(result.check_graph);
+    }
 }
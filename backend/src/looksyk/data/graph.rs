@@ -3,25 +3,36 @@ use crate::io::fs::media::{init_media, read_media_config, write_media_config};
 use crate::io::fs::pages::{read_all_journal_files, read_all_user_files};
 use crate::looksyk::index::asset::create_empty_asset_cache;
 use crate::looksyk::index::block_properties::create_block_properties_index;
+use crate::looksyk::index::page_properties::create_page_properties_index;
 use crate::looksyk::index::tag::create_tag_index;
 use crate::looksyk::index::todo::create_todo_index;
 use crate::looksyk::index::userpage::{create_journal_page_index, create_user_page_index};
 use crate::state::application_state::{GraphRootLocation, PureAppState};
+use crate::state::encryption::{EncryptionKey, EncryptionState};
 
-pub fn load_graph_data(data_root_location: &GraphRootLocation) -> PureAppState {
+pub fn load_graph_data(
+    data_root_location: &GraphRootLocation,
+    encryption_key: Option<EncryptionKey>,
+) -> PureAppState {
     let mut media_index = read_media_config(data_root_location);
     media_index = init_media(data_root_location, &media_index);
     write_media_config(data_root_location, &media_index);
 
     let config = read_config_from_file(data_root_location);
-    let all_pages = read_all_user_files(data_root_location);
-    let all_journals = read_all_journal_files(data_root_location);
+    let encryption_state = EncryptionState {
+        key: encryption_key,
+        encrypted_namespaces: config.encryption.namespaces.clone(),
+    };
+    let all_pages = read_all_user_files(data_root_location, &encryption_state);
+    let all_journals = read_all_journal_files(data_root_location, &encryption_state);
     let user_page_index = create_user_page_index(&all_pages);
     let journal_index = create_journal_page_index(&all_journals);
     let todo_index = create_todo_index(&user_page_index, &journal_index);
     let tag_index = create_tag_index(&user_page_index, &journal_index);
     let asset_cache = create_empty_asset_cache();
-    let block_properties_index = create_block_properties_index(&journal_index, &user_page_index);
+    let block_properties_index =
+        create_block_properties_index(&journal_index, &user_page_index, &config.property_schema);
+    let page_properties_index = create_page_properties_index(&journal_index, &user_page_index);
 
     println!("all data refreshed");
 
@@ -35,5 +46,7 @@ pub fn load_graph_data(data_root_location: &GraphRootLocation) -> PureAppState {
         f_media_index: media_index,
         g_config: config,
         h_block_properties: block_properties_index,
+        i_page_properties: page_properties_index,
+        j_encryption: encryption_state,
     }
 }
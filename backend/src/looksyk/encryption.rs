@@ -0,0 +1,155 @@
+use crate::io::encryption::{decrypt, derive_key, encrypt, generate_salt};
+use crate::looksyk::data::config::runtime_graph_configuration::EncryptionConfiguration;
+use crate::looksyk::model::PageType;
+use crate::state::encryption::{EncryptionKey, EncryptionState};
+
+pub const ENCRYPTED_PROPERTY: &str = "encrypted:: true";
+pub const VERIFICATION_TEXT: &str = "looksyk";
+
+pub fn should_encrypt_page(
+    page_name: &str,
+    page_type: &PageType,
+    content: &str,
+    encryption_state: &EncryptionState,
+) -> bool {
+    if *page_type == PageType::UserPage
+        && is_in_encrypted_namespace(page_name, &encryption_state.encrypted_namespaces)
+    {
+        return true;
+    }
+    has_encrypted_property(content)
+}
+
+pub enum UnlockResult {
+    Unlocked(EncryptionKey),
+    Initialized(EncryptionKey, EncryptionConfiguration),
+    WrongPassphrase,
+}
+
+pub fn unlock(passphrase: &str, configuration: &EncryptionConfiguration) -> UnlockResult {
+    match (&configuration.salt, &configuration.verification) {
+        (Some(salt), Some(verification)) => {
+            let key = derive_key(passphrase, salt);
+            match decrypt(verification, &key) {
+                Some(text) if text == VERIFICATION_TEXT => UnlockResult::Unlocked(key),
+                _ => UnlockResult::WrongPassphrase,
+            }
+        }
+        _ => {
+            let salt = generate_salt();
+            let key = derive_key(passphrase, &salt);
+            let new_configuration = EncryptionConfiguration {
+                namespaces: configuration.namespaces.clone(),
+                verification: Some(encrypt(VERIFICATION_TEXT, &key)),
+                salt: Some(salt),
+            };
+            UnlockResult::Initialized(key, new_configuration)
+        }
+    }
+}
+
+fn is_in_encrypted_namespace(page_name: &str, namespaces: &[String]) -> bool {
+    namespaces.iter().any(|namespace| {
+        page_name == namespace || page_name.starts_with(format!("{namespace}/").as_str())
+    })
+}
+
+fn has_encrypted_property(content: &str) -> bool {
+    content.lines().any(|line| {
+        line.trim_start()
+            .trim_start_matches('-')
+            .trim()
+            .eq(ENCRYPTED_PROPERTY)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with_namespace(namespace: &str) -> EncryptionState {
+        EncryptionState {
+            key: None,
+            encrypted_namespaces: vec![namespace.to_string()],
+        }
+    }
+
+    #[test]
+    fn test_should_encrypt_page_with_property() {
+        let result = should_encrypt_page(
+            "page",
+            &PageType::UserPage,
+            "- my credentials\n\tencrypted:: true",
+            &state_with_namespace("hr"),
+        );
+
+        assert!(result);
+    }
+
+    #[test]
+    fn test_should_encrypt_journal_with_property() {
+        let result = should_encrypt_page(
+            "2024_01_01",
+            &PageType::JournalPage,
+            "- encrypted:: true",
+            &state_with_namespace("hr"),
+        );
+
+        assert!(result);
+    }
+
+    #[test]
+    fn test_should_encrypt_page_in_namespace() {
+        let state = state_with_namespace("hr");
+
+        assert!(should_encrypt_page("hr", &PageType::UserPage, "", &state));
+        assert!(should_encrypt_page(
+            "hr/reviews",
+            &PageType::UserPage,
+            "",
+            &state
+        ));
+    }
+
+    #[test]
+    fn test_unlock_should_initialize_and_verify_passphrase() {
+        let configuration = EncryptionConfiguration {
+            namespaces: vec!["hr".to_string()],
+            salt: None,
+            verification: None,
+        };
+
+        let new_configuration = match unlock("secret", &configuration) {
+            UnlockResult::Initialized(_, new_configuration) => new_configuration,
+            _ => panic!("expected initialization"),
+        };
+
+        assert_eq!(new_configuration.namespaces, vec!["hr".to_string()]);
+        assert!(matches!(
+            unlock("secret", &new_configuration),
+            UnlockResult::Unlocked(_)
+        ));
+        assert!(matches!(
+            unlock("wrong", &new_configuration),
+            UnlockResult::WrongPassphrase
+        ));
+    }
+
+    #[test]
+    fn test_should_not_encrypt_other_pages() {
+        let state = state_with_namespace("hr");
+
+        assert!(!should_encrypt_page(
+            "hrm",
+            &PageType::UserPage,
+            "- encrypted:: false",
+            &state
+        ));
+        assert!(!should_encrypt_page(
+            "hr",
+            &PageType::JournalPage,
+            "- text",
+            &state
+        ));
+    }
+}
@@ -23,6 +23,9 @@ pub fn add_favourite(new_fav: Favourite, config: &Config) -> Config {
         design: config.design.clone(),
         title: config.title.clone(),
         journal_configuration: config.journal_configuration.clone(),
+        encryption: config.encryption.clone(),
+        upload_policy: config.upload_policy.clone(),
+        property_schema: config.property_schema.clone(),
     }
 }
 
@@ -32,6 +35,9 @@ pub fn set_favourites(new_favourites: Vec<Favourite>, config: &Config) -> Config
         design: config.design.clone(),
         title: config.title.clone(),
         journal_configuration: config.journal_configuration.clone(),
+        encryption: config.encryption.clone(),
+        upload_policy: config.upload_policy.clone(),
+        property_schema: config.property_schema.clone(),
     }
 }
 
@@ -48,6 +54,9 @@ pub fn remove_favourite(fav_to_remove: Favourite, config: &Config) -> Config {
         design: config.design.clone(),
         title: config.title.clone(),
         journal_configuration: config.journal_configuration.clone(),
+        encryption: config.encryption.clone(),
+        upload_policy: config.upload_policy.clone(),
+        property_schema: config.property_schema.clone(),
     }
 }
 
@@ -58,11 +67,15 @@ mod tests {
     use crate::looksyk::data::config::runtime_graph_configuration::builder::{
         config_with_fav, empty_config, empty_design, page_favourite_str,
     };
-    use crate::looksyk::data::config::runtime_graph_configuration::Config;
+    use crate::looksyk::data::config::runtime_graph_configuration::{
+        Config, EncryptionConfiguration,
+    };
     use crate::looksyk::favourite::{
         add_favourite, is_favourite, remove_favourite, set_favourites,
     };
+    use crate::looksyk::media::upload_policy::UploadPolicy;
     use crate::looksyk::model::SimplePageName;
+    use crate::looksyk::property_schema::PropertySchema;
 
     #[test]
     fn when_fav_is_set_in_config_should_return_fav() {
@@ -113,6 +126,9 @@ mod tests {
             design: empty_design(),
             title: None,
             journal_configuration: default_journal_configuration(),
+            encryption: EncryptionConfiguration::default(),
+            upload_policy: UploadPolicy::default(),
+            property_schema: PropertySchema::default(),
         };
 
         let result = remove_favourite(page_favourite_str("MySite"), &config);
@@ -0,0 +1,317 @@
+use crate::looksyk::builtinpage::journal_overview::parse_journal_date;
+use crate::looksyk::calendar::{days_between, leading_empty_days};
+use crate::looksyk::index::alias::{all_names_of, find_references_including_aliases};
+use crate::looksyk::model::{PageType, SimplePageName};
+use crate::looksyk::plot::journal_date_of;
+use crate::looksyk::property_schema::TypedPropertyValue;
+use crate::state::block_properties::{BlockPropertiesIndex, BlockPropertyKey};
+use crate::state::journal::JournalPageIndex;
+use crate::state::tag::TagIndex;
+use chrono::{Days, NaiveDate};
+use std::collections::BTreeMap;
+
+pub const HEATMAP_DAYS: u64 = 365;
+
+pub const MAX_HEATMAP_LEVEL: u8 = 4;
+
+pub enum HabitSource {
+    Tag(SimplePageName),
+    Property(BlockPropertyKey),
+}
+
+pub struct HabitSources<'a> {
+    pub tag_index: &'a TagIndex,
+    pub block_properties_index: &'a BlockPropertiesIndex,
+    pub journal_pages: &'a JournalPageIndex,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Streaks {
+    pub current: usize,
+    pub longest: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct HeatmapDay {
+    pub date: NaiveDate,
+    pub value: f64,
+    pub level: u8,
+}
+
+pub struct Heatmap {
+    pub leading_empty_days: u32,
+    pub days: Vec<HeatmapDay>,
+    pub streaks: Streaks,
+}
+
+/// Collects the value of a habit per journal day. Tags count the blocks referencing them,
+/// numeric properties are summed up, booleans count as one or zero and any other property
+/// value counts as one. Values violating the property schema are skipped.
+pub fn collect_habit_days(
+    source: &HabitSource,
+    sources: &HabitSources,
+) -> BTreeMap<NaiveDate, f64> {
+    match source {
+        HabitSource::Tag(tag) => count_tag_per_day(tag, sources),
+        HabitSource::Property(key) => sum_property_per_day(key, sources.block_properties_index),
+    }
+}
+
+fn count_tag_per_day(tag: &SimplePageName, sources: &HabitSources) -> BTreeMap<NaiveDate, f64> {
+    let target_names = all_names_of(tag, sources.block_properties_index);
+    let mut result = BTreeMap::new();
+    for page_id in
+        find_references_including_aliases(tag, sources.tag_index, sources.block_properties_index)
+    {
+        if page_id.page_type != PageType::JournalPage {
+            continue;
+        }
+        let (Some(date), Some(page)) = (
+            parse_journal_date(&page_id.name),
+            sources.journal_pages.find(&page_id.name),
+        ) else {
+            continue;
+        };
+        let count = page
+            .blocks
+            .iter()
+            .filter(|block| {
+                target_names
+                    .iter()
+                    .any(|target| block.contains_reference(target))
+            })
+            .count();
+        if count > 0 {
+            result.insert(date, count as f64);
+        }
+    }
+    result
+}
+
+fn sum_property_per_day(
+    key: &BlockPropertyKey,
+    block_properties_index: &BlockPropertiesIndex,
+) -> BTreeMap<NaiveDate, f64> {
+    let mut result = BTreeMap::new();
+    for occurence in block_properties_index.find_block_properties(key) {
+        let Some(date) = journal_date_of(&occurence.block) else {
+            continue;
+        };
+        let value = match &occurence.typed_value {
+            Ok(TypedPropertyValue::Boolean(true)) => 1.0,
+            Ok(TypedPropertyValue::Boolean(false)) => 0.0,
+            Ok(typed_value) => match typed_value.as_number() {
+                Some(number) => number,
+                None if occurence.value.value.trim().is_empty() => 0.0,
+                None => 1.0,
+            },
+            Err(_) => continue,
+        };
+        *result.entry(date).or_insert(0.0) += value;
+    }
+    result
+}
+
+/// A day counts towards a streak if its value is positive. The current streak is still
+/// running if the habit was done on the last day or the day before.
+pub fn calculate_streaks(days: &BTreeMap<NaiveDate, f64>, ending_at: NaiveDate) -> Streaks {
+    let mut longest = 0;
+    let mut running = 0;
+    let mut previous: Option<NaiveDate> = None;
+    for date in days
+        .iter()
+        .filter(|(date, value)| **date <= ending_at && **value > 0.0)
+        .map(|(date, _)| *date)
+    {
+        running = match previous {
+            Some(previous) if previous.succ_opt() == Some(date) => running + 1,
+            _ => 1,
+        };
+        longest = longest.max(running);
+        previous = Some(date);
+    }
+
+    let current = match previous {
+        Some(last) if last == ending_at || last.succ_opt() == Some(ending_at) => running,
+        _ => 0,
+    };
+    Streaks { current, longest }
+}
+
+pub fn calculate_heatmap(days: &BTreeMap<NaiveDate, f64>, ending_at: NaiveDate) -> Heatmap {
+    let first_day = ending_at
+        .checked_sub_days(Days::new(HEATMAP_DAYS - 1))
+        .unwrap_or(ending_at);
+    let max_value = days
+        .range(first_day..=ending_at)
+        .map(|(_, value)| *value)
+        .fold(0.0, f64::max);
+
+    let heatmap_days = days_between(first_day, ending_at)
+        .map(|date| {
+            let value = days.get(&date).copied().unwrap_or(0.0);
+            HeatmapDay {
+                date,
+                value,
+                level: level_of(value, max_value),
+            }
+        })
+        .collect();
+
+    Heatmap {
+        leading_empty_days: leading_empty_days(first_day),
+        days: heatmap_days,
+        streaks: calculate_streaks(days, ending_at),
+    }
+}
+
+fn level_of(value: f64, max_value: f64) -> u8 {
+    if value <= 0.0 || max_value <= 0.0 {
+        return 0;
+    }
+    ((value / max_value * MAX_HEATMAP_LEVEL as f64).ceil() as u8).clamp(1, MAX_HEATMAP_LEVEL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::{page_name_str, test_builder::journal_page_id};
+    use crate::looksyk::index::block_properties::create_block_properties_index;
+    use crate::looksyk::index::tag::create_tag_index;
+    use crate::looksyk::parser::parse_markdown_file;
+    use crate::looksyk::property_schema::PropertySchema;
+    use crate::looksyk::reader::read_file_contents;
+    use crate::state::block_properties::builder::{
+        block_properties_index_with, block_property_key, block_property_occurance,
+    };
+    use crate::state::userpage::builder::empty_user_page_index;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn days(entries: &[(NaiveDate, f64)]) -> BTreeMap<NaiveDate, f64> {
+        entries.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_collect_habit_days_should_count_tagged_blocks_per_journal_day() {
+        let mut journal_pages = JournalPageIndex::default();
+        journal_pages.insert(
+            page_name_str("2024_06_14"),
+            parse_markdown_file(read_file_contents("- [[Workout]] run\n- [[Workout]] gym")),
+        );
+        journal_pages.insert(
+            page_name_str("2024_06_15"),
+            parse_markdown_file(read_file_contents("- [[Workout]]\n- [[Reading]]")),
+        );
+        let user_pages = empty_user_page_index();
+
+        let result = collect_habit_days(
+            &HabitSource::Tag(page_name_str("Workout")),
+            &HabitSources {
+                tag_index: &create_tag_index(&user_pages, &journal_pages),
+                block_properties_index: &create_block_properties_index(
+                    &journal_pages,
+                    &user_pages,
+                    &PropertySchema::default(),
+                ),
+                journal_pages: &journal_pages,
+            },
+        );
+
+        assert_eq!(
+            result,
+            days(&[(date(2024, 6, 14), 2.0), (date(2024, 6, 15), 1.0)])
+        );
+    }
+
+    #[test]
+    fn test_collect_habit_days_should_sum_property_values_per_journal_day() {
+        let key = block_property_key("pushups");
+        let block_properties_index = block_properties_index_with(
+            key.clone(),
+            vec![
+                block_property_occurance("20", journal_page_id("2024_06_14").block_reference(0)),
+                block_property_occurance("15", journal_page_id("2024_06_14").block_reference(1)),
+                block_property_occurance("yes", journal_page_id("2024_06_15").block_reference(0)),
+            ],
+        );
+
+        let result = collect_habit_days(
+            &HabitSource::Property(key),
+            &HabitSources {
+                tag_index: &TagIndex {
+                    entries: Default::default(),
+                },
+                block_properties_index: &block_properties_index,
+                journal_pages: &JournalPageIndex::default(),
+            },
+        );
+
+        assert_eq!(
+            result,
+            days(&[(date(2024, 6, 14), 35.0), (date(2024, 6, 15), 1.0)])
+        );
+    }
+
+    #[test]
+    fn test_calculate_streaks() {
+        let habit_days = days(&[
+            (date(2024, 6, 1), 1.0),
+            (date(2024, 6, 2), 1.0),
+            (date(2024, 6, 3), 1.0),
+            (date(2024, 6, 5), 0.0),
+            (date(2024, 6, 13), 1.0),
+            (date(2024, 6, 14), 2.0),
+        ]);
+
+        assert_eq!(
+            calculate_streaks(&habit_days, date(2024, 6, 15)),
+            Streaks {
+                current: 2,
+                longest: 3
+            }
+        );
+        assert_eq!(
+            calculate_streaks(&habit_days, date(2024, 6, 16)),
+            Streaks {
+                current: 0,
+                longest: 3
+            }
+        );
+        assert_eq!(
+            calculate_streaks(&habit_days, date(2024, 6, 2)),
+            Streaks {
+                current: 2,
+                longest: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_calculate_heatmap_should_cover_one_year_aligned_to_weekdays() {
+        let habit_days = days(&[
+            (date(2024, 6, 14), 4.0),
+            (date(2024, 6, 15), 1.0),
+            (date(2023, 6, 15), 8.0),
+        ]);
+
+        let result = calculate_heatmap(&habit_days, date(2024, 6, 15));
+
+        assert_eq!(result.days.len(), 365);
+        assert_eq!(result.days[0].date, date(2023, 6, 17));
+        assert_eq!(result.leading_empty_days, 5);
+        assert_eq!(
+            result.days[364],
+            HeatmapDay {
+                date: date(2024, 6, 15),
+                value: 1.0,
+                level: 1
+            }
+        );
+        assert_eq!(result.days[363].level, MAX_HEATMAP_LEVEL);
+        assert_eq!(result.days[0].level, 0);
+        assert_eq!(result.streaks.current, 2);
+    }
+}
@@ -0,0 +1,493 @@
+use crate::io::fs::pages::PageFileName;
+use crate::looksyk::builder::page_name;
+use crate::looksyk::builtinpage::journal_overview::parse_journal_date;
+use crate::looksyk::index::alias::{find_alias_owner, find_references_including_aliases};
+use crate::looksyk::model::{BlockTokenType, PageId, PageType, ParsedMarkdownFile, SimplePageName};
+use crate::looksyk::query::{parse_query, QueryType};
+use crate::state::block::BlockReference;
+use crate::state::block_properties::BlockPropertiesIndex;
+use crate::state::journal::JournalPageIndex;
+use crate::state::tag::TagIndex;
+use crate::state::userpage::UserPageIndex;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum HealthFindingType {
+    BrokenLink,
+    OrphanPage,
+    MalformedJournalName,
+    PageFileCollision,
+    InvalidQuery,
+    LockedPage,
+}
+
+impl HealthFindingType {
+    pub fn title(&self) -> &'static str {
+        match self {
+            HealthFindingType::BrokenLink => "Links to missing pages",
+            HealthFindingType::OrphanPage => "Orphan pages",
+            HealthFindingType::MalformedJournalName => "Malformed journal names",
+            HealthFindingType::PageFileCollision => "Colliding page files",
+            HealthFindingType::InvalidQuery => "Invalid queries",
+            HealthFindingType::LockedPage => "Locked pages",
+        }
+    }
+}
+
+pub const ALL_HEALTH_FINDING_TYPES: [HealthFindingType; 6] = [
+    HealthFindingType::BrokenLink,
+    HealthFindingType::OrphanPage,
+    HealthFindingType::MalformedJournalName,
+    HealthFindingType::PageFileCollision,
+    HealthFindingType::InvalidQuery,
+    HealthFindingType::LockedPage,
+];
+
+#[derive(Debug, PartialEq)]
+pub struct HealthFinding {
+    pub finding_type: HealthFindingType,
+    pub reference: BlockReference,
+    pub message: String,
+}
+
+pub struct HealthReport {
+    pub findings: Vec<HealthFinding>,
+}
+
+impl HealthReport {
+    pub fn is_healthy(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    pub fn findings_of_type(&self, finding_type: HealthFindingType) -> Vec<&HealthFinding> {
+        self.findings
+            .iter()
+            .filter(|finding| finding.finding_type == finding_type)
+            .collect()
+    }
+}
+
+pub fn create_health_report(
+    user_page_index: &UserPageIndex,
+    journal_page_index: &JournalPageIndex,
+    tag_index: &TagIndex,
+    block_properties_index: &BlockPropertiesIndex,
+    page_files: &[PageFileName],
+) -> HealthReport {
+    let pages = sorted_pages(user_page_index, journal_page_index);
+    let locked_pages = find_locked_pages(user_page_index, journal_page_index, page_files);
+
+    let mut findings = vec![];
+    findings.extend(find_broken_links(
+        &pages,
+        user_page_index,
+        block_properties_index,
+        &locked_pages,
+    ));
+    // Links inside locked pages are unknown, so every page could be linked from them
+    if locked_pages.is_empty() {
+        findings.extend(find_orphan_pages(
+            user_page_index,
+            tag_index,
+            block_properties_index,
+        ));
+    }
+    findings.extend(find_malformed_journal_names(journal_page_index));
+    findings.extend(find_page_file_collisions(page_files));
+    findings.extend(find_invalid_queries(&pages));
+    findings.extend(locked_pages.iter().map(|page_id| HealthFinding {
+        finding_type: HealthFindingType::LockedPage,
+        reference: page_id.block_reference(0),
+        message: "Encrypted page was not checked and orphan pages are not reported, unlock the graph to check it".to_string(),
+    }));
+    HealthReport { findings }
+}
+
+/// Page files on disk that could not be loaded because they are encrypted.
+fn find_locked_pages(
+    user_page_index: &UserPageIndex,
+    journal_page_index: &JournalPageIndex,
+    page_files: &[PageFileName],
+) -> Vec<PageId> {
+    let mut result: Vec<PageId> = page_files
+        .iter()
+        .filter(|page_file| match page_file.page_id.page_type {
+            PageType::UserPage => !user_page_index
+                .entries
+                .contains_key(&page_file.page_id.name),
+            PageType::JournalPage => journal_page_index.find(&page_file.page_id.name).is_none(),
+        })
+        .map(|page_file| page_file.page_id.clone())
+        .collect();
+    result.sort_by(|a, b| a.name.name.cmp(&b.name.name));
+    result
+}
+
+fn sorted_pages<'a>(
+    user_page_index: &'a UserPageIndex,
+    journal_page_index: &'a JournalPageIndex,
+) -> Vec<(PageId, &'a ParsedMarkdownFile)> {
+    let mut user_pages: Vec<(PageId, &ParsedMarkdownFile)> = user_page_index
+        .iter_entries()
+        .map(|(name, file)| (name.as_user_page(), file))
+        .collect();
+    user_pages.sort_by(|a, b| a.0.name.name.cmp(&b.0.name.name));
+    let mut journal_pages: Vec<(PageId, &ParsedMarkdownFile)> = journal_page_index
+        .iter_entries()
+        .map(|(name, file)| (name.as_journal_page(), file))
+        .collect();
+    journal_pages.sort_by(|a, b| a.0.name.name.cmp(&b.0.name.name));
+    user_pages.extend(journal_pages);
+    user_pages
+}
+
+fn find_broken_links(
+    pages: &[(PageId, &ParsedMarkdownFile)],
+    user_page_index: &UserPageIndex,
+    block_properties_index: &BlockPropertiesIndex,
+    locked_pages: &[PageId],
+) -> Vec<HealthFinding> {
+    let mut result = vec![];
+    for (page_id, file) in pages {
+        for (block_number, block) in file.blocks.iter().enumerate() {
+            for content in &block.content {
+                for token in &content.as_tokens {
+                    if token.block_token_type != BlockTokenType::Link {
+                        continue;
+                    }
+                    let target = page_name(token.payload.clone());
+                    if user_page_index.entries.contains_key(&target)
+                        || locked_pages.contains(&target.as_user_page())
+                        || find_alias_owner(&target, block_properties_index).is_some()
+                    {
+                        continue;
+                    }
+                    result.push(HealthFinding {
+                        finding_type: HealthFindingType::BrokenLink,
+                        reference: page_id.block_reference(block_number),
+                        message: format!("Link to missing page \"{}\"", target.name),
+                    });
+                }
+            }
+        }
+    }
+    result
+}
+
+fn find_orphan_pages(
+    user_page_index: &UserPageIndex,
+    tag_index: &TagIndex,
+    block_properties_index: &BlockPropertiesIndex,
+) -> Vec<HealthFinding> {
+    let mut names: Vec<&SimplePageName> = user_page_index.entries.keys().collect();
+    names.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut result = vec![];
+    for name in names {
+        let page_id = name.as_user_page();
+        let mut references =
+            find_references_including_aliases(name, tag_index, block_properties_index);
+        references.remove(&page_id);
+        if references.is_empty() {
+            result.push(HealthFinding {
+                finding_type: HealthFindingType::OrphanPage,
+                reference: page_id.block_reference(0),
+                message: format!("No page links to \"{}\"", name.name),
+            });
+        }
+    }
+    result
+}
+
+fn find_malformed_journal_names(journal_page_index: &JournalPageIndex) -> Vec<HealthFinding> {
+    let mut names: Vec<&SimplePageName> = journal_page_index
+        .iter_entries()
+        .map(|(name, _)| name)
+        .filter(|name| parse_journal_date(name).is_none())
+        .collect();
+    names.sort_by(|a, b| a.name.cmp(&b.name));
+
+    names
+        .into_iter()
+        .map(|name| HealthFinding {
+            finding_type: HealthFindingType::MalformedJournalName,
+            reference: name.as_journal_page().block_reference(0),
+            message: format!(
+                "Journal name \"{}\" does not match the format YYYY_MM_DD",
+                name.name
+            ),
+        })
+        .collect()
+}
+
+fn find_page_file_collisions(page_files: &[PageFileName]) -> Vec<HealthFinding> {
+    let mut groups: HashMap<(bool, String), Vec<&PageFileName>> = HashMap::new();
+    for page_file in page_files {
+        groups
+            .entry((
+                page_file.page_id.is_user_page(),
+                page_file.page_id.name.name.clone(),
+            ))
+            .or_default()
+            .push(page_file);
+    }
+
+    let mut colliding: Vec<Vec<&PageFileName>> = groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .collect();
+    for group in colliding.iter_mut() {
+        group.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+    }
+    colliding.sort_by(|a, b| a[0].file_name.cmp(&b[0].file_name));
+
+    colliding
+        .into_iter()
+        .map(|group| HealthFinding {
+            finding_type: HealthFindingType::PageFileCollision,
+            reference: group[0].page_id.block_reference(0),
+            message: format!(
+                "Files {} resolve to the same page",
+                group
+                    .iter()
+                    .map(|page_file| format!("\"{}\"", page_file.file_name))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        })
+        .collect()
+}
+
+fn find_invalid_queries(pages: &[(PageId, &ParsedMarkdownFile)]) -> Vec<HealthFinding> {
+    let mut result = vec![];
+    for (page_id, file) in pages {
+        for (block_number, block) in file.blocks.iter().enumerate() {
+            for content in &block.content {
+                for token in &content.as_tokens {
+                    if token.block_token_type != BlockTokenType::Query {
+                        continue;
+                    }
+                    let message = match parse_query(&token.payload) {
+                        Err(error) => format!("Query can not be parsed: {error}"),
+                        Ok(query) if query.query_type == QueryType::Unknown => {
+                            format!("Unknown query \"{}\"", token.payload.trim())
+                        }
+                        Ok(_) => continue,
+                    };
+                    result.push(HealthFinding {
+                        finding_type: HealthFindingType::InvalidQuery,
+                        reference: page_id.block_reference(block_number),
+                        message,
+                    });
+                }
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::page_name_str;
+    use crate::looksyk::builder::test_builder::{
+        empty_block_properties_index, empty_journal_index, journal_page_id, user_page_id,
+    };
+    use crate::looksyk::index::tag::create_tag_index;
+    use crate::looksyk::parser::parse_markdown_file;
+    use crate::looksyk::reader::read_file_contents;
+    use crate::state::journal::builder::journal_page_index;
+    use crate::state::userpage::builder::user_page_index_with;
+
+    fn parse(content: &str) -> ParsedMarkdownFile {
+        parse_markdown_file(read_file_contents(content))
+    }
+
+    fn report(user_pages: UserPageIndex, journal_pages: JournalPageIndex) -> HealthReport {
+        let tag_index = create_tag_index(&user_pages, &journal_pages);
+        create_health_report(
+            &user_pages,
+            &journal_pages,
+            &tag_index,
+            &empty_block_properties_index(),
+            &[],
+        )
+    }
+
+    #[test]
+    fn test_create_health_report_should_find_broken_links() {
+        let user_pages = user_page_index_with(HashMap::from([
+            (
+                page_name_str("a"),
+                parse("- first\n- see [[b]] and [[missing]]"),
+            ),
+            (page_name_str("b"), parse("- back to [[a]]")),
+        ]));
+
+        let result = report(user_pages, empty_journal_index());
+
+        assert_eq!(
+            result.findings_of_type(HealthFindingType::BrokenLink),
+            vec![&HealthFinding {
+                finding_type: HealthFindingType::BrokenLink,
+                reference: user_page_id("a").block_reference(1),
+                message: "Link to missing page \"missing\"".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_create_health_report_should_find_orphan_pages() {
+        let user_pages = user_page_index_with(HashMap::from([
+            (page_name_str("a"), parse("- see [[b]] and [[a]]")),
+            (page_name_str("b"), parse("- content")),
+        ]));
+
+        let result = report(user_pages, empty_journal_index());
+
+        let orphans = result.findings_of_type(HealthFindingType::OrphanPage);
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].reference, user_page_id("a").block_reference(0));
+    }
+
+    #[test]
+    fn test_create_health_report_should_find_malformed_journal_names() {
+        let result = report(
+            user_page_index_with(HashMap::new()),
+            journal_page_index("2024_1_1", parse("- content")),
+        );
+
+        let malformed = result.findings_of_type(HealthFindingType::MalformedJournalName);
+        assert_eq!(malformed.len(), 1);
+        assert_eq!(
+            malformed[0].reference,
+            journal_page_id("2024_1_1").block_reference(0)
+        );
+    }
+
+    #[test]
+    fn test_create_health_report_should_find_invalid_queries() {
+        let result = report(
+            user_page_index_with(HashMap::new()),
+            journal_page_index(
+                "2024_01_01",
+                parse(
+                    "- {query: todos tag:\"a\" state:\"todo\" display:\"referenced-list\" }\n- {query: unknown-query }\n- {query: todos state:\"todo\" }",
+                ),
+            ),
+        );
+
+        let invalid = result.findings_of_type(HealthFindingType::InvalidQuery);
+        assert_eq!(invalid.len(), 2);
+        assert_eq!(
+            invalid[0].reference,
+            journal_page_id("2024_01_01").block_reference(1)
+        );
+        assert_eq!(
+            invalid[1].reference,
+            journal_page_id("2024_01_01").block_reference(2)
+        );
+    }
+
+    #[test]
+    fn test_create_health_report_should_find_colliding_page_files() {
+        let page_files = vec![
+            PageFileName {
+                file_name: "Project%2Fplan.md".to_string(),
+                page_id: user_page_id("Project/plan"),
+            },
+            PageFileName {
+                file_name: "Project%2Fplan .md".to_string(),
+                page_id: user_page_id("Project/plan"),
+            },
+            PageFileName {
+                file_name: "other.md".to_string(),
+                page_id: user_page_id("other"),
+            },
+        ];
+
+        let result = find_page_file_collisions(&page_files);
+
+        assert_eq!(
+            result,
+            vec![HealthFinding {
+                finding_type: HealthFindingType::PageFileCollision,
+                reference: user_page_id("Project/plan").block_reference(0),
+                message:
+                    "Files \"Project%2Fplan .md\", \"Project%2Fplan.md\" resolve to the same page"
+                        .to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_find_page_file_collisions_should_compare_names_exactly() {
+        let page_files = vec![
+            PageFileName {
+                file_name: "Rust.md".to_string(),
+                page_id: user_page_id("Rust"),
+            },
+            PageFileName {
+                file_name: "rust.md".to_string(),
+                page_id: user_page_id("rust"),
+            },
+            PageFileName {
+                file_name: "project %2F plan.md".to_string(),
+                page_id: user_page_id("project / plan"),
+            },
+            PageFileName {
+                file_name: "project%2Fplan.md".to_string(),
+                page_id: user_page_id("project/plan"),
+            },
+        ];
+
+        assert!(find_page_file_collisions(&page_files).is_empty());
+    }
+
+    #[test]
+    fn test_create_health_report_should_report_locked_pages() {
+        let user_pages = user_page_index_with(HashMap::from([
+            (page_name_str("a"), parse("- [[secret]]")),
+            (page_name_str("linked-from-secret"), parse("- text")),
+        ]));
+        let journal_pages = journal_page_index("2024_01_01", parse("- [[a]]"));
+        let tag_index = create_tag_index(&user_pages, &journal_pages);
+        let page_files = vec![PageFileName {
+            file_name: "secret.md".to_string(),
+            page_id: user_page_id("secret"),
+        }];
+
+        let result = create_health_report(
+            &user_pages,
+            &journal_pages,
+            &tag_index,
+            &empty_block_properties_index(),
+            &page_files,
+        );
+
+        assert_eq!(
+            result
+                .findings
+                .iter()
+                .map(|f| f.finding_type)
+                .collect::<Vec<_>>(),
+            vec![HealthFindingType::LockedPage]
+        );
+        assert_eq!(
+            result.findings[0].reference,
+            user_page_id("secret").block_reference(0)
+        );
+    }
+
+    #[test]
+    fn test_create_health_report_should_be_healthy_for_linked_pages() {
+        let user_pages = user_page_index_with(HashMap::from([
+            (page_name_str("a"), parse("- [[b]]")),
+            (page_name_str("b"), parse("- [[a]]")),
+        ]));
+
+        let result = report(user_pages, journal_page_index("2024_01_01", parse("- ok")));
+
+        assert!(result.is_healthy());
+    }
+}
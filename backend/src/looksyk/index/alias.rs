@@ -0,0 +1,195 @@
+use crate::looksyk::model::{PageId, PageType, SimplePageName};
+use crate::state::block_properties::{
+    BlockPropertiesIndex, BlockPropertyKey, BlockPropertyOccurence,
+};
+use crate::state::tag::TagIndex;
+use std::collections::{HashMap, HashSet};
+
+pub const ALIAS_PROPERTY: &str = "alias";
+
+pub fn resolve_alias(
+    page_name: &SimplePageName,
+    block_properties_index: &BlockPropertiesIndex,
+) -> SimplePageName {
+    find_alias_owner(page_name, block_properties_index).unwrap_or(page_name.clone())
+}
+
+pub fn find_alias_owner(
+    alias: &SimplePageName,
+    block_properties_index: &BlockPropertiesIndex,
+) -> Option<SimplePageName> {
+    block_properties_index.aliases.get(alias).cloned()
+}
+
+/// Maps every alias to the page declaring it. An alias declared by several pages belongs to
+/// the first one.
+pub fn create_alias_index(
+    block_properties_index: &BlockPropertiesIndex,
+) -> HashMap<SimplePageName, SimplePageName> {
+    let mut result = HashMap::new();
+    for occurence in alias_occurences(block_properties_index) {
+        for alias in parse_aliases(&occurence.value.value) {
+            result
+                .entry(alias)
+                .or_insert_with(|| occurence.block.page_id.name.clone());
+        }
+    }
+    result
+}
+
+pub fn all_names_of(
+    page_name: &SimplePageName,
+    block_properties_index: &BlockPropertiesIndex,
+) -> Vec<SimplePageName> {
+    let canonical = resolve_alias(page_name, block_properties_index);
+    let mut result = vec![canonical.clone()];
+    for occurence in alias_occurences(block_properties_index) {
+        if occurence.block.page_id.name != canonical {
+            continue;
+        }
+        for alias in parse_aliases(&occurence.value.value) {
+            if !result.contains(&alias) {
+                result.push(alias);
+            }
+        }
+    }
+    result
+}
+
+pub fn find_references_including_aliases(
+    page_name: &SimplePageName,
+    tag_index: &TagIndex,
+    block_properties_index: &BlockPropertiesIndex,
+) -> HashSet<PageId> {
+    let mut result = HashSet::new();
+    for name in all_names_of(page_name, block_properties_index) {
+        if let Some(references) = tag_index.entries.get(&name.as_user_page()) {
+            result.extend(references.iter().cloned());
+        }
+    }
+    result
+}
+
+fn alias_occurences(
+    block_properties_index: &BlockPropertiesIndex,
+) -> impl Iterator<Item = &BlockPropertyOccurence> {
+    block_properties_index
+        .find(&BlockPropertyKey {
+            value: ALIAS_PROPERTY.to_string(),
+        })
+        .into_iter()
+        .flatten()
+        .filter(|occurence| {
+            occurence.block.block_number == 0
+                && occurence.block.page_id.page_type == PageType::UserPage
+        })
+}
+
+fn parse_aliases(value: &str) -> Vec<SimplePageName> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|alias| !alias.is_empty())
+        .map(|alias| SimplePageName {
+            name: alias.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::page_name_str;
+    use crate::looksyk::builder::test_builder::{journal_page_id, user_page_id};
+    use crate::state::block_properties::builder::{
+        block_properties_index_with, block_property_key, block_property_occurance,
+    };
+    use std::collections::HashMap;
+
+    fn alias_index() -> BlockPropertiesIndex {
+        block_properties_index_with(
+            block_property_key(ALIAS_PROPERTY),
+            vec![
+                block_property_occurance(
+                    "K8s, kube",
+                    user_page_id("Kubernetes").block_reference(0),
+                ),
+                block_property_occurance("ignored", user_page_id("Other").block_reference(1)),
+                block_property_occurance(
+                    "journal",
+                    journal_page_id("2024_01_01").block_reference(0),
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_resolve_alias_should_return_canonical_page() {
+        assert_eq!(
+            resolve_alias(&page_name_str("K8s"), &alias_index()),
+            page_name_str("Kubernetes")
+        );
+        assert_eq!(
+            resolve_alias(&page_name_str("kube"), &alias_index()),
+            page_name_str("Kubernetes")
+        );
+    }
+
+    #[test]
+    fn test_resolve_alias_should_keep_unknown_names() {
+        assert_eq!(
+            resolve_alias(&page_name_str("Docker"), &alias_index()),
+            page_name_str("Docker")
+        );
+    }
+
+    #[test]
+    fn test_resolve_alias_should_only_use_first_block_of_user_pages() {
+        assert_eq!(
+            find_alias_owner(&page_name_str("ignored"), &alias_index()),
+            None
+        );
+        assert_eq!(
+            find_alias_owner(&page_name_str("journal"), &alias_index()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_all_names_of_should_work_from_alias_and_canonical_name() {
+        let expected = vec![
+            page_name_str("Kubernetes"),
+            page_name_str("K8s"),
+            page_name_str("kube"),
+        ];
+
+        assert_eq!(
+            all_names_of(&page_name_str("Kubernetes"), &alias_index()),
+            expected
+        );
+        assert_eq!(
+            all_names_of(&page_name_str("K8s"), &alias_index()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_find_references_including_aliases_should_merge_backlinks() {
+        let mut entries = HashMap::new();
+        entries.insert(
+            user_page_id("Kubernetes"),
+            HashSet::from([user_page_id("a")]),
+        );
+        entries.insert(user_page_id("K8s"), HashSet::from([user_page_id("b")]));
+        entries.insert(user_page_id("Docker"), HashSet::from([user_page_id("c")]));
+        let tag_index = TagIndex { entries };
+
+        let result =
+            find_references_including_aliases(&page_name_str("K8s"), &tag_index, &alias_index());
+
+        assert_eq!(
+            result,
+            HashSet::from([user_page_id("a"), user_page_id("b")])
+        );
+    }
+}
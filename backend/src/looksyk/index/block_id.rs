@@ -0,0 +1,151 @@
+use crate::looksyk::model::{BlockContent, PageType, ParsedBlock};
+use crate::looksyk::parser::parse_text_content;
+use crate::state::block::BlockReference;
+use crate::state::block_properties::{BlockPropertiesIndex, BlockPropertyKey};
+use crate::state::journal::JournalPageIndex;
+use crate::state::userpage::UserPageIndex;
+
+pub const BLOCK_ID_PROPERTY: &str = "block-id";
+pub const BLOCK_ID_LENGTH: usize = 16;
+
+/// Block ids are lowercase hex strings of a fixed length.
+pub fn is_block_id(value: &str) -> bool {
+    value.len() == BLOCK_ID_LENGTH
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+}
+
+pub fn find_block_by_id(
+    block_id: &str,
+    block_properties_index: &BlockPropertiesIndex,
+) -> Option<BlockReference> {
+    block_properties_index
+        .find(&BlockPropertyKey {
+            value: BLOCK_ID_PROPERTY.to_string(),
+        })?
+        .iter()
+        .find(|occurence| occurence.value.value == block_id)
+        .map(|occurence| occurence.block.clone())
+}
+
+pub fn find_block<'a>(
+    block_reference: &BlockReference,
+    user_pages: &'a UserPageIndex,
+    journal_pages: &'a JournalPageIndex,
+) -> Option<&'a ParsedBlock> {
+    let page = match block_reference.page_id.page_type {
+        PageType::UserPage => user_pages.find(&block_reference.page_id.name),
+        PageType::JournalPage => journal_pages.find(&block_reference.page_id.name),
+    }?;
+    page.block(block_reference.block_number)
+}
+
+pub fn get_block_id(block: &ParsedBlock) -> Option<String> {
+    block
+        .properties
+        .properties
+        .iter()
+        .find(|property| property.key == BLOCK_ID_PROPERTY)
+        .map(|property| property.value.clone())
+}
+
+pub fn with_block_id(block: &ParsedBlock, block_id: &str) -> ParsedBlock {
+    let id_line = format!("{BLOCK_ID_PROPERTY}:: {block_id}");
+    let mut parsed_id_line = parse_text_content(&id_line);
+
+    let mut content = block.content.clone();
+    content.push(BlockContent {
+        as_text: id_line,
+        as_tokens: parsed_id_line.tokens,
+    });
+    let mut properties = block.properties.clone();
+    properties.append(&mut parsed_id_line.properties);
+
+    ParsedBlock {
+        indentation: block.indentation,
+        content,
+        properties,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::test_builder::{empty_journal_index, user_page_id};
+    use crate::looksyk::model::builder::block_with_text_content;
+    use crate::looksyk::model::ParsedMarkdownFile;
+    use crate::state::block_properties::builder::{
+        block_properties_index_with, block_property_key, block_property_occurance,
+    };
+    use crate::state::userpage::builder::user_page_index;
+
+    #[test]
+    fn test_find_block_by_id_should_resolve_current_position() {
+        let reference = user_page_id("page").block_reference(3);
+        let index = block_properties_index_with(
+            block_property_key(BLOCK_ID_PROPERTY),
+            vec![
+                block_property_occurance("other", user_page_id("page").block_reference(1)),
+                block_property_occurance("00a1b2c3d4e5f607", reference.clone()),
+            ],
+        );
+
+        assert_eq!(
+            find_block_by_id("00a1b2c3d4e5f607", &index),
+            Some(reference)
+        );
+        assert_eq!(find_block_by_id("missing", &index), None);
+    }
+
+    #[test]
+    fn test_find_block_by_id_on_empty_index() {
+        assert_eq!(
+            find_block_by_id("00a1b2c3d4e5f607", &BlockPropertiesIndex::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_with_block_id_should_append_id_property() {
+        let block = with_block_id(&block_with_text_content("content"), "00a1b2c3d4e5f607");
+
+        assert_eq!(block.content.len(), 2);
+        assert_eq!(block.content[1].as_text, "block-id:: 00a1b2c3d4e5f607");
+        assert_eq!(get_block_id(&block), Some("00a1b2c3d4e5f607".to_string()));
+    }
+
+    #[test]
+    fn test_is_block_id() {
+        assert!(is_block_id("00a1b2c3d4e5f607"));
+        assert!(!is_block_id("a1b2c3"));
+        assert!(!is_block_id("00A1B2C3D4E5F607"));
+        assert!(!is_block_id("00a1b2c3d4e5f60x"));
+    }
+
+    #[test]
+    fn test_get_block_id_without_id() {
+        assert_eq!(get_block_id(&block_with_text_content("content")), None);
+    }
+
+    #[test]
+    fn test_find_block() {
+        let user_pages = user_page_index(
+            "page",
+            ParsedMarkdownFile::from_blocks(vec![
+                block_with_text_content("first"),
+                block_with_text_content("second"),
+            ]),
+        );
+
+        let journal_pages = empty_journal_index();
+
+        let result = find_block(
+            &user_page_id("page").block_reference(1),
+            &user_pages,
+            &journal_pages,
+        );
+
+        assert_eq!(result.unwrap().content[0].as_text, "second");
+    }
+}
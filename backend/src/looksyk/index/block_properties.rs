@@ -1,4 +1,6 @@
+use crate::looksyk::index::alias::create_alias_index;
 use crate::looksyk::model::{PageId, ParsedMarkdownFile, SimplePageName};
+use crate::looksyk::property_schema::PropertySchema;
 use crate::state::block_properties::BlockPropertiesIndex;
 use crate::state::journal::JournalPageIndex;
 use crate::state::userpage::UserPageIndex;
@@ -7,17 +9,25 @@ use std::collections::HashMap;
 pub fn create_block_properties_index(
     journal_pages: &JournalPageIndex,
     user_pages: &UserPageIndex,
+    schema: &PropertySchema,
 ) -> BlockPropertiesIndex {
     let mut index = BlockPropertiesIndex {
         entries: HashMap::new(),
+        schema: schema.clone(),
+        aliases: HashMap::new(),
     };
     for (journal_page_name, journal_page) in journal_pages.iter_entries() {
-        insert_journal_page_to_block_properties(&mut index, journal_page_name, journal_page);
+        insert_page_to_block_properties(
+            &mut index,
+            journal_page_name.as_journal_page(),
+            journal_page,
+        );
     }
 
     for (user_page_name, user_page) in user_pages.iter_entries() {
-        insert_user_page_to_block_properties(&mut index, user_page_name, user_page);
+        insert_page_to_block_properties(&mut index, user_page_name.as_user_page(), user_page);
     }
+    index.aliases = create_alias_index(&index);
     index
 }
 
@@ -26,14 +36,8 @@ pub fn insert_user_page_to_block_properties(
     user_page_name: &SimplePageName,
     user_page: &ParsedMarkdownFile,
 ) {
-    for (block_index, block) in user_page.blocks.iter().enumerate() {
-        for property in block.properties.properties.iter() {
-            index.append_elements(
-                user_page_name.as_user_page().block_reference(block_index),
-                property.clone(),
-            );
-        }
-    }
+    insert_page_to_block_properties(index, user_page_name.as_user_page(), user_page);
+    index.aliases = create_alias_index(index);
 }
 
 pub fn insert_journal_page_to_block_properties(
@@ -41,14 +45,23 @@ pub fn insert_journal_page_to_block_properties(
     journal_page_name: &SimplePageName,
     journal_page: &ParsedMarkdownFile,
 ) {
-    for (block_index, block) in journal_page.blocks.iter().enumerate() {
+    insert_page_to_block_properties(index, journal_page_name.as_journal_page(), journal_page);
+}
+
+fn insert_page_to_block_properties(
+    index: &mut BlockPropertiesIndex,
+    page_id: PageId,
+    page: &ParsedMarkdownFile,
+) {
+    let has_page_properties = !page.page_properties.properties.is_empty();
+    for (block_index, block) in page.blocks.iter().enumerate() {
         for property in block.properties.properties.iter() {
-            index.append_elements(
-                journal_page_name
-                    .as_journal_page()
-                    .block_reference(block_index),
-                property.clone(),
-            );
+            let block_reference = page_id.block_reference(block_index);
+            if block_index == 0 && has_page_properties {
+                index.append_page_property(block_reference, property.clone());
+            } else {
+                index.append_elements(block_reference, property.clone());
+            }
         }
     }
 }
@@ -66,15 +79,25 @@ pub fn remove_file_from_index(
             .collect();
         new_index.insert(key.clone(), filtered);
     });
-    BlockPropertiesIndex { entries: new_index }
+    let mut new_index = BlockPropertiesIndex {
+        entries: new_index,
+        schema: index.schema.clone(),
+        aliases: HashMap::new(),
+    };
+    new_index.aliases = create_alias_index(&new_index);
+    new_index
 }
 
 #[cfg(test)]
 pub mod tests {
+    use crate::looksyk::builder::page_name_str;
     use crate::looksyk::builder::test_builder::{journal_page_id, user_page_id};
     use crate::looksyk::model::builder::block_with_property;
     use crate::looksyk::model::ParsedMarkdownFile;
-    use crate::state::block_properties::builder::{block_property_key, block_property_occurance};
+    use crate::looksyk::property_schema::PropertySchema;
+    use crate::state::block_properties::builder::{
+        block_property_key, block_property_occurance, page_property_occurance,
+    };
     use crate::state::journal::builder::journal_page_index;
     use crate::state::userpage::builder::user_page_index;
 
@@ -82,27 +105,24 @@ pub mod tests {
     fn test_create_block_properties_index() {
         let journals = journal_page_index(
             "journal-page-name",
-            ParsedMarkdownFile {
-                blocks: vec![
-                    block_with_property("key1", "value1"),
-                    block_with_property("key2", "key3"),
-                ],
-            },
+            ParsedMarkdownFile::from_blocks(vec![
+                block_with_property("key1", "value1"),
+                block_with_property("key2", "key3"),
+            ]),
         );
         let users = user_page_index(
             "user-page-name",
-            ParsedMarkdownFile {
-                blocks: vec![block_with_property("key2", "value2")],
-            },
+            ParsedMarkdownFile::from_blocks(vec![block_with_property("key2", "value2")]),
         );
 
-        let index = super::create_block_properties_index(&journals, &users);
+        let index =
+            super::create_block_properties_index(&journals, &users, &PropertySchema::default());
 
         assert_eq!(index.entries.len(), 2);
 
         assert_eq!(
             index.entries.get(&block_property_key("key1")).unwrap(),
-            &vec![block_property_occurance(
+            &vec![page_property_occurance(
                 "value1",
                 journal_page_id("journal-page-name").block_reference(0)
             )]
@@ -115,7 +135,7 @@ pub mod tests {
                     "key3",
                     journal_page_id("journal-page-name").block_reference(1)
                 ),
-                block_property_occurance(
+                page_property_occurance(
                     "value2",
                     user_page_id("user-page-name").block_reference(0)
                 ),
@@ -125,26 +145,22 @@ pub mod tests {
 
     #[test]
     fn test_remove_file_from_index() {
-        let mut index = super::create_block_properties_index(
+        let index = super::create_block_properties_index(
             &journal_page_index(
                 "journal-page-name",
-                ParsedMarkdownFile {
-                    blocks: vec![
-                        block_with_property("key1", "value1"),
-                        block_with_property("key2", "key3"),
-                    ],
-                },
+                ParsedMarkdownFile::from_blocks(vec![
+                    block_with_property("key1", "value1"),
+                    block_with_property("key2", "key3"),
+                ]),
             ),
             &user_page_index(
                 "user-page-name",
-                ParsedMarkdownFile {
-                    blocks: vec![block_with_property("key2", "value2")],
-                },
+                ParsedMarkdownFile::from_blocks(vec![block_with_property("key2", "value2")]),
             ),
+            &PropertySchema::default(),
         );
 
-        let result =
-            super::remove_file_from_index(&mut index, &journal_page_id("journal-page-name"));
+        let result = super::remove_file_from_index(&index, &journal_page_id("journal-page-name"));
 
         assert_eq!(result.entries.len(), 2);
 
@@ -155,10 +171,69 @@ pub mod tests {
 
         assert_eq!(
             result.entries.get(&block_property_key("key2")).unwrap(),
-            &vec![block_property_occurance(
+            &vec![page_property_occurance(
                 "value2",
                 user_page_id("user-page-name").block_reference(0)
             ),]
         );
     }
+
+    #[test]
+    fn test_alias_index_should_follow_added_and_removed_pages() {
+        let mut index = super::create_block_properties_index(
+            &journal_page_index("2024_01_01", ParsedMarkdownFile::empty()),
+            &user_page_index(
+                "Kubernetes",
+                ParsedMarkdownFile::from_blocks(vec![block_with_property("alias", "K8s")]),
+            ),
+            &PropertySchema::default(),
+        );
+        assert_eq!(
+            index.aliases.get(&page_name_str("K8s")),
+            Some(&page_name_str("Kubernetes"))
+        );
+
+        super::insert_user_page_to_block_properties(
+            &mut index,
+            &page_name_str("Docker"),
+            &ParsedMarkdownFile::from_blocks(vec![block_with_property("alias", "container")]),
+        );
+        assert_eq!(
+            index.aliases.get(&page_name_str("container")),
+            Some(&page_name_str("Docker"))
+        );
+
+        let result = super::remove_file_from_index(&index, &user_page_id("Kubernetes"));
+        assert_eq!(result.aliases.get(&page_name_str("K8s")), None);
+        assert_eq!(result.aliases.len(), 1);
+    }
+
+    #[test]
+    fn test_find_block_properties_should_skip_page_properties() {
+        let index = super::create_block_properties_index(
+            &journal_page_index("2024_01_01", ParsedMarkdownFile::empty()),
+            &user_page_index(
+                "user-page-name",
+                ParsedMarkdownFile::from_blocks(vec![
+                    block_with_property("type", "project"),
+                    block_with_property("type", "task"),
+                ]),
+            ),
+            &PropertySchema::default(),
+        );
+
+        let result: Vec<_> = index
+            .find_block_properties(&block_property_key("type"))
+            .cloned()
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![block_property_occurance(
+                "task",
+                user_page_id("user-page-name").block_reference(1)
+            )]
+        );
+        assert_eq!(index.find(&block_property_key("type")).unwrap().len(), 2);
+    }
 }
@@ -2,6 +2,9 @@ use crate::looksyk::index::block_properties::{
     insert_journal_page_to_block_properties, insert_user_page_to_block_properties,
     remove_file_from_index,
 };
+use crate::looksyk::index::page_properties::{
+    insert_page_to_page_properties, remove_file_from_page_properties_index,
+};
 use crate::looksyk::index::tag::{create_tag_index_file, remove_file_from_tag_index};
 use crate::looksyk::index::todo::{create_todo_index_file, remove_file_from_todo_index};
 use crate::looksyk::index::userpage::{
@@ -27,6 +30,8 @@ pub fn update_index_for_file(
     create_tag_index_file(&mut tag_index_entries, &page_id, update);
 
     let mut block_properties_index = new_page_associated_state.block_properties_index;
+    let mut page_properties_index = new_page_associated_state.page_properties_index;
+    insert_page_to_page_properties(&mut page_properties_index, page_id.clone(), update);
     match page_id.page_type {
         PageType::UserPage => {
             new_page_associated_state
@@ -61,6 +66,7 @@ pub fn update_index_for_file(
             entries: tag_index_entries,
         },
         block_properties_index,
+        page_properties_index,
     }
 }
 
@@ -85,6 +91,10 @@ pub fn remove_page_from_internal_state(
 
     let new_block_properties_index =
         remove_file_from_index(page_associated_state.block_properties_index, page_id);
+    let new_page_properties_index = remove_file_from_page_properties_index(
+        page_associated_state.page_properties_index,
+        page_id,
+    );
 
     NewPageAssociatedState {
         user_pages: new_page_index,
@@ -92,6 +102,7 @@ pub fn remove_page_from_internal_state(
         todo_index: new_todo_index,
         tag_index: new_tag_index,
         block_properties_index: new_block_properties_index,
+        page_properties_index: new_page_properties_index,
     }
 }
 
@@ -101,9 +112,13 @@ mod tests {
         empty_block_properties_index, empty_journal_index, user_page_id,
     };
     use crate::looksyk::index::index_operations::update_index_for_file;
+    use crate::looksyk::model::builder::block_with_property;
     use crate::looksyk::model::{ParsedMarkdownFile, RawBlock};
     use crate::looksyk::parser::parse_block;
+    use crate::looksyk::property_schema::PropertySchema;
     use crate::state::application_state::CurrentPageAssociatedState;
+    use crate::state::block_properties::builder::{block_property_key, block_property_value};
+    use crate::state::page_properties::PagePropertiesIndex;
     use crate::state::tag::TagIndex;
     use crate::state::todo::builder::empty_todo_index;
     use crate::state::userpage::builder::empty_user_page_index;
@@ -112,12 +127,10 @@ mod tests {
     #[test]
     fn test_update_index_for_file_should_refresh_tag_index() {
         let page_id = user_page_id("test-page-name");
-        let parsed_markdown_file = ParsedMarkdownFile {
-            blocks: vec![parse_block(&RawBlock {
-                indentation: 0,
-                text_content: vec!["[[new-link-on-page]]".to_string()],
-            })],
-        };
+        let parsed_markdown_file = ParsedMarkdownFile::from_blocks(vec![parse_block(&RawBlock {
+            indentation: 0,
+            text_content: vec!["[[new-link-on-page]]".to_string()],
+        })]);
 
         let mut tag_index_map = HashMap::new();
         let mut test_page_backlinks = HashSet::new();
@@ -136,6 +149,7 @@ mod tests {
             user_pages: &empty_user_page_index(),
             journal_pages: &empty_journal_index(),
             block_properties_index: &empty_block_properties_index(),
+            page_properties_index: &PagePropertiesIndex::default(),
         };
 
         let result = update_index_for_file(
@@ -158,4 +172,52 @@ mod tests {
             .unwrap()
             .contains(&page_id));
     }
+
+    #[test]
+    fn test_update_index_for_file_should_refresh_page_properties_index() {
+        let page_id = user_page_id("test-page-name");
+        let mut page_properties_index = PagePropertiesIndex::default();
+        page_properties_index.append_elements(
+            page_id.clone(),
+            crate::looksyk::parser::BlockProperty {
+                key: "type".to_string(),
+                value: "person".to_string(),
+            },
+        );
+        let updated_page =
+            ParsedMarkdownFile::from_blocks(vec![block_with_property("type", "project")]);
+
+        let result = update_index_for_file(
+            page_id.clone(),
+            &updated_page,
+            CurrentPageAssociatedState {
+                tag_index: &TagIndex {
+                    entries: HashMap::new(),
+                },
+                todo_index: &empty_todo_index(),
+                user_pages: &empty_user_page_index(),
+                journal_pages: &empty_journal_index(),
+                block_properties_index: &empty_block_properties_index(),
+                page_properties_index: &page_properties_index,
+            },
+        );
+
+        let schema = PropertySchema::default();
+        assert_eq!(
+            result.page_properties_index.find_pages(
+                &block_property_key("type"),
+                Some(&block_property_value("project")),
+                &schema
+            ),
+            vec![page_id]
+        );
+        assert!(result
+            .page_properties_index
+            .find_pages(
+                &block_property_key("type"),
+                Some(&block_property_value("person")),
+                &schema
+            )
+            .is_empty());
+    }
 }
@@ -1,8 +1,12 @@
+pub mod alias;
 pub mod asset;
+pub mod block_id;
 pub mod block_properties;
 mod hierachy;
 pub mod index_operations;
 pub mod media;
+pub mod page_properties;
+pub mod property_rename;
 pub mod rename;
 pub mod tag;
 pub mod todo;
@@ -0,0 +1,96 @@
+use crate::looksyk::model::{PageId, ParsedMarkdownFile};
+use crate::state::journal::JournalPageIndex;
+use crate::state::page_properties::PagePropertiesIndex;
+use crate::state::userpage::UserPageIndex;
+use std::collections::HashMap;
+
+pub fn create_page_properties_index(
+    journal_pages: &JournalPageIndex,
+    user_pages: &UserPageIndex,
+) -> PagePropertiesIndex {
+    let mut index = PagePropertiesIndex::default();
+    for (journal_page_name, journal_page) in journal_pages.iter_entries() {
+        insert_page_to_page_properties(
+            &mut index,
+            journal_page_name.as_journal_page(),
+            journal_page,
+        );
+    }
+
+    for (user_page_name, user_page) in user_pages.iter_entries() {
+        insert_page_to_page_properties(&mut index, user_page_name.as_user_page(), user_page);
+    }
+    index
+}
+
+pub fn insert_page_to_page_properties(
+    index: &mut PagePropertiesIndex,
+    page_id: PageId,
+    page: &ParsedMarkdownFile,
+) {
+    for property in page.page_properties.properties.iter() {
+        index.append_elements(page_id.clone(), property.clone());
+    }
+}
+
+pub fn remove_file_from_page_properties_index(
+    index: &PagePropertiesIndex,
+    page_id: &PageId,
+) -> PagePropertiesIndex {
+    let mut new_index = HashMap::new();
+    index.entries.iter().for_each(|(key, occurences)| {
+        let filtered: Vec<_> = occurences
+            .iter()
+            .filter(|occurence| &occurence.page_id != page_id)
+            .cloned()
+            .collect();
+        if !filtered.is_empty() {
+            new_index.insert(key.clone(), filtered);
+        }
+    });
+    PagePropertiesIndex { entries: new_index }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::looksyk::builder::test_builder::{journal_page_id, user_page_id};
+    use crate::looksyk::model::builder::{block_with_property, block_with_text_content};
+    use crate::looksyk::model::ParsedMarkdownFile;
+    use crate::looksyk::property_schema::PropertySchema;
+    use crate::state::block_properties::builder::{block_property_key, block_property_value};
+    use crate::state::journal::builder::journal_page_index;
+    use crate::state::page_properties::PagePropertyOccurence;
+    use crate::state::userpage::builder::user_page_index;
+
+    #[test]
+    fn test_create_page_properties_index_should_only_use_first_block() {
+        let journals = journal_page_index(
+            "2024_01_01",
+            ParsedMarkdownFile::from_blocks(vec![block_with_property("type", "review")]),
+        );
+        let users = user_page_index(
+            "user-page-name",
+            ParsedMarkdownFile::from_blocks(vec![
+                block_with_text_content("intro"),
+                block_with_property("type", "project"),
+            ]),
+        );
+
+        let index = super::create_page_properties_index(&journals, &users);
+
+        assert_eq!(
+            index.find(&block_property_key("type")).unwrap(),
+            &vec![PagePropertyOccurence {
+                value: block_property_value("review"),
+                page_id: journal_page_id("2024_01_01"),
+            }]
+        );
+        assert!(!index
+            .find_pages(
+                &block_property_key("type"),
+                None,
+                &PropertySchema::default()
+            )
+            .contains(&user_page_id("user-page-name")));
+    }
+}
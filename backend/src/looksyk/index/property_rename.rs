@@ -0,0 +1,308 @@
+use crate::looksyk::index::rename::{FileChanges, RenameTagResult};
+use crate::looksyk::model::{PageId, PageType, ParsedMarkdownFile};
+use crate::looksyk::syntax::looksyk_markdown::serialize_property;
+use crate::state::application_state::{CurrentPageOnDiskState, NewPageOnDiskState};
+use crate::state::block_properties::{BlockPropertiesIndex, BlockPropertyKey, BlockPropertyValue};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub struct PropertyRename {
+    pub old_key: BlockPropertyKey,
+    pub new_key: BlockPropertyKey,
+    pub old_value: Option<BlockPropertyValue>,
+    pub new_value: Option<BlockPropertyValue>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidPropertyRenameError {
+    pub message: String,
+}
+
+impl Display for InvalidPropertyRenameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl PropertyRename {
+    pub fn validate(&self) -> Result<(), InvalidPropertyRenameError> {
+        if !is_valid_key(&self.old_key.value) || !is_valid_key(&self.new_key.value) {
+            return Err(invalid(
+                "property keys must not be empty or contain whitespace",
+            ));
+        }
+        let values = [&self.old_value, &self.new_value];
+        if values
+            .iter()
+            .flat_map(|value| value.iter())
+            .any(|value| value.value.trim().is_empty() || value.value.contains('\n'))
+        {
+            return Err(invalid("property values must not be empty or span lines"));
+        }
+        if self.new_value.is_some() && self.old_value.is_none() {
+            return Err(invalid("a new value requires the value to rename"));
+        }
+        if self.old_key == self.new_key && self.new_value.is_none() {
+            return Err(invalid("nothing to rename"));
+        }
+        Ok(())
+    }
+
+    fn matches(&self, key: &str, value: &str) -> bool {
+        key == self.old_key.value
+            && self
+                .old_value
+                .as_ref()
+                .is_none_or(|old_value| old_value.value == value)
+    }
+
+    fn renamed_value(&self, value: &BlockPropertyValue) -> BlockPropertyValue {
+        self.new_value.clone().unwrap_or_else(|| value.clone())
+    }
+
+    pub fn describe_old_property(&self) -> String {
+        describe_property(&self.old_key, self.old_value.as_ref())
+    }
+
+    pub fn describe_new_property(&self) -> String {
+        describe_property(
+            &self.new_key,
+            self.new_value.as_ref().or(self.old_value.as_ref()),
+        )
+    }
+}
+
+pub fn rename_property_across_all_files(
+    rename: &PropertyRename,
+    block_properties_index: &BlockPropertiesIndex,
+    current_page_associated_state: CurrentPageOnDiskState,
+) -> RenameTagResult {
+    let affected_pages: HashSet<PageId> = block_properties_index
+        .find(&rename.old_key)
+        .map(|occurences| {
+            occurences
+                .iter()
+                .filter(|occurence| rename.matches(&rename.old_key.value, &occurence.value.value))
+                .map(|occurence| occurence.block.page_id.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut changed_files = HashSet::new();
+    let mut new_user_pages = current_page_associated_state.user_pages.clone();
+    let mut new_journal_pages = current_page_associated_state.journal_pages.clone();
+
+    for page_id in affected_pages {
+        let file = match page_id.page_type {
+            PageType::UserPage => current_page_associated_state.user_pages.find(&page_id.name),
+            PageType::JournalPage => current_page_associated_state
+                .journal_pages
+                .find(&page_id.name),
+        };
+        let Some(new_file) = file.and_then(|file| rename_property_in_file(rename, file)) else {
+            continue;
+        };
+        match page_id.page_type {
+            PageType::UserPage => {
+                new_user_pages
+                    .entries
+                    .insert(page_id.name.clone(), new_file);
+            }
+            PageType::JournalPage => new_journal_pages.insert(page_id.name.clone(), new_file),
+        }
+        changed_files.insert(page_id);
+    }
+
+    RenameTagResult {
+        new_page_associated_state: NewPageOnDiskState {
+            user_pages: new_user_pages,
+            journal_pages: new_journal_pages,
+        },
+        file_changes: FileChanges {
+            changed_files,
+            file_to_delete: HashSet::new(),
+        },
+    }
+}
+
+fn rename_property_in_file(
+    rename: &PropertyRename,
+    parsed_markdown_file: &ParsedMarkdownFile,
+) -> Option<ParsedMarkdownFile> {
+    let mut changed = false;
+    let mut new_blocks = vec![];
+    for block in &parsed_markdown_file.blocks {
+        let mut new_block = block.clone();
+        for property in block
+            .properties
+            .properties
+            .iter()
+            .filter(|property| rename.matches(&property.key, &property.value))
+        {
+            let old_value = BlockPropertyValue {
+                value: property.value.clone(),
+            };
+            new_block = new_block.replace_property(
+                &rename.old_key,
+                &old_value,
+                &rename.new_key,
+                &rename.renamed_value(&old_value),
+            );
+        }
+        changed |= &new_block != block;
+        new_blocks.push(new_block);
+    }
+
+    if !changed {
+        return None;
+    }
+    Some(ParsedMarkdownFile::from_blocks(new_blocks))
+}
+
+fn describe_property(key: &BlockPropertyKey, value: Option<&BlockPropertyValue>) -> String {
+    match value {
+        Some(value) => serialize_property(key, value),
+        None => format!("{}::", key.value),
+    }
+}
+
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty() && !key.contains(char::is_whitespace) && !key.contains("::")
+}
+
+fn invalid(message: &str) -> InvalidPropertyRenameError {
+    InvalidPropertyRenameError {
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::page_name_str;
+    use crate::looksyk::index::block_properties::create_block_properties_index;
+    use crate::looksyk::parser::parse_markdown_file;
+    use crate::looksyk::property_schema::PropertySchema;
+    use crate::looksyk::reader::read_file_contents;
+    use crate::looksyk::serializer::serialize_page;
+    use crate::state::block_properties::builder::{block_property_key, block_property_value};
+    use crate::state::journal::builder::journal_page_index;
+    use crate::state::userpage::builder::user_page_index;
+
+    fn page(content: &str) -> ParsedMarkdownFile {
+        parse_markdown_file(read_file_contents(content))
+    }
+
+    fn key_rename(old_key: &str, new_key: &str) -> PropertyRename {
+        PropertyRename {
+            old_key: block_property_key(old_key),
+            new_key: block_property_key(new_key),
+            old_value: None,
+            new_value: None,
+        }
+    }
+
+    fn value_rename(key: &str, old_value: &str, new_value: &str) -> PropertyRename {
+        PropertyRename {
+            old_key: block_property_key(key),
+            new_key: block_property_key(key),
+            old_value: Some(block_property_value(old_value)),
+            new_value: Some(block_property_value(new_value)),
+        }
+    }
+
+    #[test]
+    fn test_rename_property_across_all_files_should_rename_key_in_all_pages() {
+        let user_pages = user_page_index("project", page("- state:: wip\n- other:: x"));
+        let journal_pages = journal_page_index("2024_01_01", page("- task state:: done today"));
+        let index =
+            create_block_properties_index(&journal_pages, &user_pages, &PropertySchema::default());
+
+        let result = rename_property_across_all_files(
+            &key_rename("state", "status"),
+            &index,
+            CurrentPageOnDiskState {
+                user_pages: &user_pages,
+                journal_pages: &journal_pages,
+            },
+        );
+
+        let new_state = result.new_page_associated_state;
+        assert_eq!(result.file_changes.changed_files.len(), 2);
+        assert_eq!(
+            serialize_page(
+                new_state
+                    .user_pages
+                    .find(&page_name_str("project"))
+                    .unwrap()
+            ),
+            vec!["- status:: wip", "- other:: x"]
+        );
+        let journal_page = new_state
+            .journal_pages
+            .find(&page_name_str("2024_01_01"))
+            .unwrap();
+        assert_eq!(
+            serialize_page(journal_page),
+            vec!["- task status:: done today"]
+        );
+        assert_eq!(
+            journal_page.blocks[0].properties.properties[0].key,
+            "status"
+        );
+    }
+
+    #[test]
+    fn test_rename_property_across_all_files_should_only_rename_matching_values() {
+        let user_pages = user_page_index("board", page("- a status:: wip\n- b status:: todo"));
+        let journal_pages = journal_page_index("2024_01_01", page("- c status:: todo"));
+        let index =
+            create_block_properties_index(&journal_pages, &user_pages, &PropertySchema::default());
+
+        let result = rename_property_across_all_files(
+            &value_rename("status", "wip", "doing"),
+            &index,
+            CurrentPageOnDiskState {
+                user_pages: &user_pages,
+                journal_pages: &journal_pages,
+            },
+        );
+
+        assert_eq!(
+            result.file_changes.changed_files,
+            HashSet::from([page_name_str("board").as_user_page()])
+        );
+        assert_eq!(
+            serialize_page(
+                result
+                    .new_page_associated_state
+                    .user_pages
+                    .find(&page_name_str("board"))
+                    .unwrap()
+            ),
+            vec!["- a status:: doing", "- b status:: todo"]
+        );
+    }
+
+    #[test]
+    fn test_validate_should_reject_invalid_renames() {
+        assert!(key_rename("state", "status").validate().is_ok());
+        assert_eq!(
+            key_rename("state", "status").describe_new_property(),
+            "status::"
+        );
+        assert!(value_rename("status", "wip", "doing").validate().is_ok());
+        assert!(key_rename("state", "state").validate().is_err());
+        assert!(key_rename("state", "my status").validate().is_err());
+        assert!(value_rename("status", "wip", " ").validate().is_err());
+        assert_eq!(
+            PropertyRename {
+                old_value: None,
+                new_value: Some(block_property_value("doing")),
+                ..key_rename("status", "status")
+            }
+            .validate(),
+            Err(invalid("a new value requires the value to rename"))
+        );
+    }
+}
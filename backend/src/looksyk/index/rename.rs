@@ -1,12 +1,18 @@
+use crate::looksyk::builder::page_name;
 use crate::looksyk::model::{
-    BlockContent, PageId, PageType, ParsedBlock, ParsedMarkdownFile, SimplePageName,
+    BlockContent, BlockToken, BlockTokenType, PageId, PageType, ParsedBlock, ParsedMarkdownFile,
+    SimplePageName,
 };
 use crate::looksyk::parser::parse_text_content;
+use crate::looksyk::serializer::serialize_tokens;
 use crate::looksyk::syntax::looksyk_markdown::render_as_tag;
 use crate::state::application_state::{CurrentPageOnDiskState, NewPageOnDiskState};
 use crate::state::tag::TagIndex;
 use crate::state::userpage::UserPageIndex;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+const HIERARCHY_SEPARATOR: char = '/';
 
 pub struct RenameTagResult {
     pub new_page_associated_state: NewPageOnDiskState,
@@ -52,6 +58,25 @@ pub struct NewPageName {
     pub page_name: SimplePageName,
 }
 
+#[derive(Debug)]
+pub struct PageRenameCollisionError {
+    pub colliding_pages: Vec<SimplePageName>,
+}
+
+impl Display for PageRenameCollisionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Can not rename hierarchy, pages already exist: {}",
+            self.colliding_pages
+                .iter()
+                .map(|page| page.name.clone())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
 pub fn rename_page_across_all_files(
     old_page_name: OldPageName,
     new_page_name: NewPageName,
@@ -73,6 +98,182 @@ pub fn rename_page_across_all_files(
     state_after_rename_in_file.append(new_state)
 }
 
+pub fn rename_page_hierarchy_across_all_files(
+    old_page_name: OldPageName,
+    new_page_name: NewPageName,
+    current_page_associated_state: CurrentPageOnDiskState,
+    tag_index: &TagIndex,
+) -> Result<RenameTagResult, PageRenameCollisionError> {
+    let renames = collect_hierarchy_renames(
+        &old_page_name,
+        &new_page_name,
+        current_page_associated_state.user_pages,
+        tag_index,
+    );
+    let renamed_names: HashSet<&SimplePageName> = renames.keys().collect();
+
+    let mut colliding_pages: Vec<SimplePageName> = renames
+        .values()
+        .filter(|new_name| {
+            current_page_associated_state
+                .user_pages
+                .entries
+                .contains_key(*new_name)
+                && !renamed_names.contains(new_name)
+        })
+        .cloned()
+        .collect();
+    if !colliding_pages.is_empty() {
+        colliding_pages.sort_by(|a, b| a.name.cmp(&b.name));
+        return Err(PageRenameCollisionError { colliding_pages });
+    }
+
+    let mut referencing_pages: HashSet<PageId> = HashSet::new();
+    for old_name in renames.keys() {
+        if let Some(references) = tag_index.entries.get(&old_name.as_user_page()) {
+            referencing_pages.extend(references.iter().cloned());
+        }
+    }
+
+    let mut new_user_pages = current_page_associated_state.user_pages.clone();
+    let mut new_journal_pages = current_page_associated_state.journal_pages.clone();
+    let mut changed_files: HashSet<PageId> = HashSet::new();
+    for reference in referencing_pages {
+        match reference.page_type {
+            PageType::UserPage => {
+                let file = new_user_pages.entries.get(&reference.name).unwrap();
+                let new_file = rename_links_in_file(&renames, file);
+                new_user_pages
+                    .entries
+                    .insert(reference.name.clone(), new_file);
+            }
+            PageType::JournalPage => {
+                let file = new_journal_pages.find(&reference.name).unwrap();
+                let new_file = rename_links_in_file(&renames, file);
+                new_journal_pages.insert(reference.name.clone(), new_file);
+            }
+        }
+        if !renamed_names.contains(&reference.name) || !reference.is_user_page() {
+            changed_files.insert(reference);
+        }
+    }
+
+    let mut moved_pages = vec![];
+    for (old_name, new_name) in &renames {
+        if let Some(file) = new_user_pages.entries.get(old_name) {
+            moved_pages.push((new_name.clone(), file.clone()));
+        }
+    }
+    let new_names: HashSet<&SimplePageName> = moved_pages.iter().map(|(name, _)| name).collect();
+    let file_to_delete: HashSet<PageId> = renames
+        .keys()
+        .filter(|old_name| {
+            new_user_pages.entries.contains_key(*old_name) && !new_names.contains(old_name)
+        })
+        .map(|old_name| old_name.as_user_page())
+        .collect();
+    for (new_name, file) in moved_pages {
+        changed_files.insert(new_name.as_user_page());
+        new_user_pages.entries.insert(new_name, file);
+    }
+
+    Ok(RenameTagResult {
+        new_page_associated_state: NewPageOnDiskState {
+            user_pages: new_user_pages,
+            journal_pages: new_journal_pages,
+        },
+        file_changes: FileChanges {
+            changed_files,
+            file_to_delete,
+        },
+    })
+}
+
+fn collect_hierarchy_renames(
+    old_page_name: &OldPageName,
+    new_page_name: &NewPageName,
+    user_page_index: &UserPageIndex,
+    tag_index: &TagIndex,
+) -> HashMap<SimplePageName, SimplePageName> {
+    let known_names = user_page_index.entries.keys().chain(
+        tag_index
+            .entries
+            .keys()
+            .filter(|page_id| page_id.is_user_page())
+            .map(|page_id| &page_id.name),
+    );
+
+    let mut result = HashMap::new();
+    for name in known_names {
+        if let Some(remainder) = strip_hierarchy_prefix(&name.name, &old_page_name.page_name.name) {
+            result.insert(
+                name.clone(),
+                SimplePageName {
+                    name: format!("{}{}", new_page_name.page_name.name, remainder),
+                },
+            );
+        }
+    }
+    result
+}
+
+fn strip_hierarchy_prefix<'a>(page_name: &'a str, prefix: &str) -> Option<&'a str> {
+    let remainder = page_name.strip_prefix(prefix)?;
+    if remainder.is_empty() || remainder.trim_start().starts_with(HIERARCHY_SEPARATOR) {
+        return Some(remainder);
+    }
+    None
+}
+
+fn rename_links_in_file(
+    renames: &HashMap<SimplePageName, SimplePageName>,
+    parsed_markdown_file: &ParsedMarkdownFile,
+) -> ParsedMarkdownFile {
+    ParsedMarkdownFile::from_blocks(
+        parsed_markdown_file
+            .blocks
+            .iter()
+            .map(|block| ParsedBlock {
+                indentation: block.indentation,
+                content: block
+                    .content
+                    .iter()
+                    .map(|line| rename_links_in_line(renames, line))
+                    .collect(),
+                properties: block.properties.clone(),
+            })
+            .collect(),
+    )
+}
+
+fn rename_links_in_line(
+    renames: &HashMap<SimplePageName, SimplePageName>,
+    line: &BlockContent,
+) -> BlockContent {
+    let tokens: Vec<BlockToken> = line
+        .as_tokens
+        .iter()
+        .map(|token| {
+            if token.block_token_type != BlockTokenType::Link {
+                return token.clone();
+            }
+            match renames.get(&page_name(token.payload.clone())) {
+                Some(new_name) => BlockToken {
+                    block_token_type: BlockTokenType::Link,
+                    payload: new_name.name.clone(),
+                },
+                None => token.clone(),
+            }
+        })
+        .collect();
+    let new_text = serialize_tokens(&tokens);
+
+    BlockContent {
+        as_tokens: parse_text_content(&new_text).tokens,
+        as_text: new_text,
+    }
+}
+
 fn rename_tag_across_all_files(
     old: &str,
     new: &str,
@@ -131,7 +332,7 @@ fn rename_tag_in_file(
         let new_block = rename_tag_in_block(old, new, block);
         new_blocks.push(new_block);
     }
-    ParsedMarkdownFile { blocks: new_blocks }
+    ParsedMarkdownFile::from_blocks(new_blocks)
 }
 
 fn rename_tag_in_block(old: &str, new: &str, parsed_block: &ParsedBlock) -> ParsedBlock {
@@ -187,7 +388,7 @@ fn combine_two_pages(
         new_blocks.append(&mut source.blocks.clone());
         new_user_state.insert(
             new_tag_name.page_name.clone(),
-            ParsedMarkdownFile { blocks: new_blocks },
+            ParsedMarkdownFile::from_blocks(new_blocks),
         );
     } else {
         new_user_state.insert(new_tag_name.page_name.clone(), source.unwrap().clone());
@@ -210,9 +411,15 @@ fn combine_two_pages(
 #[cfg(test)]
 mod tests {
     use crate::looksyk::builder::page_name_str;
-    use crate::looksyk::index::rename::{rename_page_across_all_files, NewPageName, OldPageName};
+    use crate::looksyk::index::rename::{
+        rename_page_across_all_files, rename_page_hierarchy_across_all_files, NewPageName,
+        OldPageName, PageRenameCollisionError, RenameTagResult,
+    };
+    use crate::looksyk::index::tag::create_tag_index;
     use crate::looksyk::model::builder::block_with_text_content;
     use crate::looksyk::model::{PageId, ParsedMarkdownFile, SimplePageName};
+    use crate::looksyk::parser::parse_markdown_file;
+    use crate::looksyk::reader::read_file_contents;
     use crate::state::application_state::CurrentPageOnDiskState;
     use crate::state::journal::JournalPageIndex;
     use crate::state::tag::TagIndex;
@@ -229,33 +436,25 @@ mod tests {
         let mut user_pages: HashMap<SimplePageName, ParsedMarkdownFile> = HashMap::new();
         user_pages.insert(
             old_page_name.clone(),
-            ParsedMarkdownFile {
-                blocks: vec![block_with_text_content("old page content")],
-            },
+            ParsedMarkdownFile::from_blocks(vec![block_with_text_content("old page content")]),
         );
         user_pages.insert(
             new_page_name.clone(),
-            ParsedMarkdownFile {
-                blocks: vec![block_with_text_content("new page content")],
-            },
+            ParsedMarkdownFile::from_blocks(vec![block_with_text_content("new page content")]),
         );
         user_pages.insert(
             referencing_page_name_user.clone(),
-            ParsedMarkdownFile {
-                blocks: vec![block_with_text_content(
-                    "Here is a link for users [[old_page]]",
-                )],
-            },
+            ParsedMarkdownFile::from_blocks(vec![block_with_text_content(
+                "Here is a link for users [[old_page]]",
+            )]),
         );
 
         let mut journal_pages: JournalPageIndex = Default::default();
         journal_pages.insert(
             referencing_page_name_journal.clone(),
-            ParsedMarkdownFile {
-                blocks: vec![block_with_text_content(
-                    "Here is a link for journal [[old_page]]",
-                )],
-            },
+            ParsedMarkdownFile::from_blocks(vec![block_with_text_content(
+                "Here is a link for journal [[old_page]]",
+            )]),
         );
 
         let mut tags_index: HashMap<PageId, HashSet<PageId>> = HashMap::new();
@@ -338,4 +537,135 @@ mod tests {
         assert_eq!(result.file_changes.file_to_delete.len(), 0);
         assert_eq!(result.file_changes.changed_files.len(), 0);
     }
+
+    fn parse(content: &str) -> ParsedMarkdownFile {
+        parse_markdown_file(read_file_contents(content))
+    }
+
+    fn hierarchy_state() -> (UserPageIndex, JournalPageIndex) {
+        let user_pages = UserPageIndex {
+            entries: HashMap::from([
+                (
+                    page_name_str("Projects/Alpha"),
+                    parse("- see [[Projects/Alpha/Notes]]"),
+                ),
+                (page_name_str("Projects/Alpha/Notes"), parse("- notes")),
+                (page_name_str("Projects/Alphabet"), parse("- unrelated")),
+                (
+                    page_name_str("Overview"),
+                    parse("- [[Projects/Alpha]] and [[Projects/Alpha/Tasks]] and [[Projects/Alphabet]]"),
+                ),
+            ]),
+        };
+        let mut journal_pages: JournalPageIndex = Default::default();
+        journal_pages.insert(
+            page_name_str("2024_01_01"),
+            parse("- worked on [[Projects/Alpha/Notes]]"),
+        );
+        (user_pages, journal_pages)
+    }
+
+    fn rename_hierarchy(old: &str, new: &str) -> Result<RenameTagResult, PageRenameCollisionError> {
+        let (user_pages, journal_pages) = hierarchy_state();
+        let tag_index = create_tag_index(&user_pages, &journal_pages);
+        rename_page_hierarchy_across_all_files(
+            OldPageName {
+                page_name: page_name_str(old),
+            },
+            NewPageName {
+                page_name: page_name_str(new),
+            },
+            CurrentPageOnDiskState {
+                user_pages: &user_pages,
+                journal_pages: &journal_pages,
+            },
+            &tag_index,
+        )
+    }
+
+    #[test]
+    fn rename_hierarchy_should_move_all_pages_with_prefix_and_rewrite_links() {
+        let result = rename_hierarchy("Projects/Alpha", "Archive/Alpha").unwrap();
+
+        assert_eq!(
+            result.file_changes.file_to_delete,
+            HashSet::from([
+                page_name_str("Projects/Alpha").as_user_page(),
+                page_name_str("Projects/Alpha/Notes").as_user_page(),
+            ])
+        );
+        assert_eq!(
+            result.file_changes.changed_files,
+            HashSet::from([
+                page_name_str("Archive/Alpha").as_user_page(),
+                page_name_str("Archive/Alpha/Notes").as_user_page(),
+                page_name_str("Overview").as_user_page(),
+                page_name_str("2024_01_01").as_journal_page(),
+            ])
+        );
+
+        let user_pages = result.new_page_associated_state.user_pages.entries;
+        assert_eq!(
+            user_pages
+                .get(&page_name_str("Archive/Alpha"))
+                .unwrap()
+                .blocks[0]
+                .content[0]
+                .as_text,
+            "see [[Archive/Alpha/Notes]]"
+        );
+        assert_eq!(
+            user_pages.get(&page_name_str("Overview")).unwrap().blocks[0].content[0].as_text,
+            "[[Archive/Alpha]] and [[Archive/Alpha/Tasks]] and [[Projects/Alphabet]]"
+        );
+        let journal_pages = result.new_page_associated_state.journal_pages;
+        assert_eq!(
+            journal_pages
+                .find(&page_name_str("2024_01_01"))
+                .unwrap()
+                .blocks[0]
+                .content[0]
+                .as_text,
+            "worked on [[Archive/Alpha/Notes]]"
+        );
+    }
+
+    #[test]
+    fn rename_hierarchy_should_move_pages_into_own_subtree() {
+        let result = rename_hierarchy("Projects/Alpha", "Projects/Alpha/Notes").unwrap();
+
+        assert_eq!(
+            result.file_changes.file_to_delete,
+            HashSet::from([page_name_str("Projects/Alpha").as_user_page()])
+        );
+        let user_pages = result.new_page_associated_state.user_pages.entries;
+        assert_eq!(
+            user_pages
+                .get(&page_name_str("Projects/Alpha/Notes"))
+                .unwrap()
+                .blocks[0]
+                .content[0]
+                .as_text,
+            "see [[Projects/Alpha/Notes/Notes]]"
+        );
+        assert_eq!(
+            user_pages
+                .get(&page_name_str("Projects/Alpha/Notes/Notes"))
+                .unwrap()
+                .blocks[0]
+                .content[0]
+                .as_text,
+            "notes"
+        );
+    }
+
+    #[test]
+    fn rename_hierarchy_should_fail_on_existing_target_pages() {
+        let result = rename_hierarchy("Projects/Alpha", "Projects/Alphabet");
+
+        assert_eq!(
+            result.err().unwrap().colliding_pages,
+            vec![page_name_str("Projects/Alphabet")]
+        );
+    }
 }
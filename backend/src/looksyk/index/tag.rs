@@ -1,10 +1,12 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::looksyk::builder::{page_name, text_token_str};
+use crate::looksyk::index::alias::find_references_including_aliases;
 use crate::looksyk::model::{
     BlockContent, BlockToken, BlockTokenType, PageId, PageType, ParsedBlock, ParsedMarkdownFile,
 };
 use crate::looksyk::parser::BlockProperties;
+use crate::state::block_properties::BlockPropertiesIndex;
 use crate::state::journal::JournalPageIndex;
 use crate::state::tag::TagIndex;
 use crate::state::userpage::UserPageIndex;
@@ -68,14 +70,16 @@ pub fn remove_file_from_tag_index(tag_index: &TagIndex, page_id: &PageId) -> Tag
     TagIndex { entries: result }
 }
 
-pub fn render_tag_index_for_page(page_id: PageId, tag_index: &TagIndex) -> ParsedMarkdownFile {
-    let empty_set = HashSet::new();
-    let tags_for_page = tag_index.entries.get(&page_id).unwrap_or(&empty_set);
+pub fn render_tag_index_for_page(
+    page_id: PageId,
+    tag_index: &TagIndex,
+    block_properties_index: &BlockPropertiesIndex,
+) -> ParsedMarkdownFile {
+    let tags_for_page =
+        find_references_including_aliases(&page_id.name, tag_index, block_properties_index);
 
     if tags_for_page.is_empty() {
-        return ParsedMarkdownFile {
-            blocks: vec![no_references_found_text(0)],
-        };
+        return ParsedMarkdownFile::from_blocks(vec![no_references_found_text(0)]);
     }
 
     let mut sorted_pages = tags_for_page.clone().into_iter().collect::<Vec<PageId>>();
@@ -96,7 +100,7 @@ pub fn render_tag_index_for_page(page_id: PageId, tag_index: &TagIndex) -> Parse
         "Journal-Pages",
     ));
 
-    ParsedMarkdownFile { blocks }
+    ParsedMarkdownFile::from_blocks(blocks)
 }
 
 fn reference_entry_group(page_references: &Vec<&PageId>, name: &str) -> Vec<ParsedBlock> {
@@ -177,6 +181,7 @@ mod tests {
         BlockContent, BlockToken, BlockTokenType, PageId, ParsedBlock, ParsedMarkdownFile,
     };
     use crate::looksyk::parser::BlockProperties;
+    use crate::state::block_properties::BlockPropertiesIndex;
     use crate::state::journal::builder::journal_page_index;
     use crate::state::journal::JournalPageIndex;
     use crate::state::tag::TagIndex;
@@ -195,9 +200,7 @@ mod tests {
         let result = create_tag_index(
             &user_page_index(
                 "source-page",
-                ParsedMarkdownFile {
-                    blocks: vec![block_with_link_content("target-page")],
-                },
+                ParsedMarkdownFile::from_blocks(vec![block_with_link_content("target-page")]),
             ),
             &empty_journal_index(),
         );
@@ -224,9 +227,7 @@ mod tests {
             &empty_user_page_index(),
             &journal_page_index(
                 "source-page",
-                ParsedMarkdownFile {
-                    blocks: vec![block_with_link_content("target-page")],
-                },
+                ParsedMarkdownFile::from_blocks(vec![block_with_link_content("target-page")]),
             ),
         );
 
@@ -245,7 +246,11 @@ mod tests {
     pub fn render_tag_index_for_page_with_no_tags_should_render_empty_page() {
         let tag_index = create_tag_index(&empty_user_page_index(), &empty_journal_index());
 
-        let result = render_tag_index_for_page(user_page_id("testpage"), &tag_index);
+        let result = render_tag_index_for_page(
+            user_page_id("testpage"),
+            &tag_index,
+            &BlockPropertiesIndex::default(),
+        );
 
         assert_eq!(result.blocks.len(), 1);
         let block = result.blocks.first().unwrap();
@@ -259,23 +264,21 @@ mod tests {
         let result = create_todo_index(
             &user_page_index(
                 "testfile",
-                ParsedMarkdownFile {
-                    blocks: vec![ParsedBlock {
-                        indentation: 0,
-                        content: vec![BlockContent {
-                            as_tokens: vec![
-                                done_token(),
-                                any_text_token(),
-                                BlockToken {
-                                    payload: "MyTag".to_string(),
-                                    block_token_type: BlockTokenType::Link,
-                                },
-                            ],
-                            as_text: "".to_string(),
-                        }],
-                        properties: BlockProperties::empty(),
+                ParsedMarkdownFile::from_blocks(vec![ParsedBlock {
+                    indentation: 0,
+                    content: vec![BlockContent {
+                        as_tokens: vec![
+                            done_token(),
+                            any_text_token(),
+                            BlockToken {
+                                payload: "MyTag".to_string(),
+                                block_token_type: BlockTokenType::Link,
+                            },
+                        ],
+                        as_text: "".to_string(),
                     }],
-                },
+                    properties: BlockProperties::empty(),
+                }]),
             ),
             &empty_journal_index(),
         );
@@ -292,7 +295,11 @@ mod tests {
     fn should_render_tag_index_for_page_with_no_tags_should_render_empty_page() {
         let tag_index = create_tag_index(&empty_user_page_index(), &empty_journal_index());
 
-        let result = render_tag_index_for_page(user_page_id("testpage"), &tag_index);
+        let result = render_tag_index_for_page(
+            user_page_id("testpage"),
+            &tag_index,
+            &BlockPropertiesIndex::default(),
+        );
 
         assert_eq!(result.blocks.len(), 1);
         let block = result.blocks.first().unwrap();
@@ -309,8 +316,11 @@ mod tests {
             vec![user_page_id("referenced-page")].into_iter().collect(),
         );
 
-        let result =
-            render_tag_index_for_page(user_page_id("testpage"), &TagIndex { entries: tag_index });
+        let result = render_tag_index_for_page(
+            user_page_id("testpage"),
+            &TagIndex { entries: tag_index },
+            &BlockPropertiesIndex::default(),
+        );
 
         assert_eq!(result.blocks.len(), 4);
         assert_eq!(result.blocks[0].content.len(), 1);
@@ -342,8 +352,11 @@ mod tests {
             vec![journal_page_id("2011_10_09")].into_iter().collect(),
         );
 
-        let result =
-            render_tag_index_for_page(user_page_id("testpage"), &TagIndex { entries: tag_index });
+        let result = render_tag_index_for_page(
+            user_page_id("testpage"),
+            &TagIndex { entries: tag_index },
+            &BlockPropertiesIndex::default(),
+        );
 
         assert_eq!(result.blocks.len(), 4);
         assert_eq!(result.blocks[0].content.len(), 1);
@@ -83,9 +83,7 @@ mod tests {
         let result = create_todo_index(
             &user_page_index(
                 "testfile",
-                ParsedMarkdownFile {
-                    blocks: vec![ParsedBlock::empty()],
-                },
+                ParsedMarkdownFile::from_blocks(vec![ParsedBlock::empty()]),
             ),
             &empty_journal_index(),
         );
@@ -98,12 +96,10 @@ mod tests {
         let result = create_todo_index(
             &user_page_index(
                 "testfile",
-                ParsedMarkdownFile {
-                    blocks: vec![ParsedBlock::from_tokens(vec![
-                        todo_token(),
-                        any_text_token(),
-                    ])],
-                },
+                ParsedMarkdownFile::from_blocks(vec![ParsedBlock::from_tokens(vec![
+                    todo_token(),
+                    any_text_token(),
+                ])]),
             ),
             &empty_journal_index(),
         );
@@ -126,12 +122,10 @@ mod tests {
         let result = create_todo_index(
             &user_page_index(
                 "testfile",
-                ParsedMarkdownFile {
-                    blocks: vec![ParsedBlock::from_tokens(vec![
-                        done_token(),
-                        any_text_token(),
-                    ])],
-                },
+                ParsedMarkdownFile::from_blocks(vec![ParsedBlock::from_tokens(vec![
+                    done_token(),
+                    any_text_token(),
+                ])]),
             ),
             &empty_journal_index(),
         );
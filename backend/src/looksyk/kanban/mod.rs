@@ -25,22 +25,18 @@ pub fn get_kanban_from_tag(
     for column_value in column_values {
         let mut items = vec![];
 
-        if let Some(occurences) = block_properties_index.entries.get(&column_identifier) {
-            for occurence in occurences {
-                if occurence.value == column_value
-                    && block_contains_tag(&tag, occurence, markdown_file_index)
-                {
-                    let block = markdown_file_index
-                        .resolve(&occurence.block.page_id)
-                        .and_then(|page: &ParsedMarkdownFile| {
-                            page.block(occurence.block.block_number)
-                        })
-                        .unwrap();
-                    items.push(KanbanItem {
-                        block: convert_to_referenced_markdown(occurence, block),
-                        priority: extract_priority(block, block_property_priority_key),
-                    });
-                }
+        for occurence in block_properties_index
+            .find_block_properties_with_value(&column_identifier, &column_value)
+        {
+            if block_contains_tag(&tag, occurence, markdown_file_index) {
+                let block = markdown_file_index
+                    .resolve(&occurence.block.page_id)
+                    .and_then(|page: &ParsedMarkdownFile| page.block(occurence.block.block_number))
+                    .unwrap();
+                items.push(KanbanItem {
+                    block: convert_to_referenced_markdown(occurence, block),
+                    priority: extract_priority(block, block_property_priority_key),
+                });
             }
         }
 
@@ -65,6 +61,7 @@ fn convert_to_referenced_markdown(
     ReferencedMarkdown {
         content: block.clone(),
         reference: occurance.block.clone(),
+        render_deep: false,
     }
 }
 
@@ -118,7 +115,7 @@ pub fn move_kanban_card(
             result.push(block.clone());
         }
     }
-    ParsedMarkdownFile { blocks: result }
+    ParsedMarkdownFile::from_blocks(result)
 }
 
 #[cfg(test)]
@@ -195,9 +192,7 @@ mod tests {
                 &empty_journal_index(),
                 &user_page_index(
                     &name.name,
-                    ParsedMarkdownFile {
-                        blocks: vec![block_with_link_content(&tag.name)],
-                    },
+                    ParsedMarkdownFile::from_blocks(vec![block_with_link_content(&tag.name)]),
                 ),
             ),
         );
@@ -247,15 +242,13 @@ mod tests {
                 &empty_journal_index(),
                 &user_page_index(
                     "page-1",
-                    ParsedMarkdownFile {
-                        blocks: vec![parsed_block_with(
-                            vec![link_token("tag")],
-                            vec![
-                                block_property("priority", "high"),
-                                block_property("status", "To Do"),
-                            ],
-                        )],
-                    },
+                    ParsedMarkdownFile::from_blocks(vec![parsed_block_with(
+                        vec![link_token("tag")],
+                        vec![
+                            block_property("priority", "high"),
+                            block_property("status", "To Do"),
+                        ],
+                    )]),
                 ),
             ),
         );
@@ -298,31 +291,29 @@ mod tests {
                 &empty_journal_index(),
                 &user_page_index(
                     "page-1",
-                    ParsedMarkdownFile {
-                        blocks: vec![
-                            parsed_block_with(
-                                vec![link_token("tag")],
-                                vec![
-                                    block_property("priority", "A"),
-                                    block_property("status", "ToDo"),
-                                ],
-                            ),
-                            parsed_block_with(
-                                vec![link_token("tag")],
-                                vec![
-                                    block_property("priority", "C"),
-                                    block_property("status", "ToDo"),
-                                ],
-                            ),
-                            parsed_block_with(
-                                vec![link_token("tag")],
-                                vec![
-                                    block_property("priority", "B"),
-                                    block_property("status", "ToDo"),
-                                ],
-                            ),
-                        ],
-                    },
+                    ParsedMarkdownFile::from_blocks(vec![
+                        parsed_block_with(
+                            vec![link_token("tag")],
+                            vec![
+                                block_property("priority", "A"),
+                                block_property("status", "ToDo"),
+                            ],
+                        ),
+                        parsed_block_with(
+                            vec![link_token("tag")],
+                            vec![
+                                block_property("priority", "C"),
+                                block_property("status", "ToDo"),
+                            ],
+                        ),
+                        parsed_block_with(
+                            vec![link_token("tag")],
+                            vec![
+                                block_property("priority", "B"),
+                                block_property("status", "ToDo"),
+                            ],
+                        ),
+                    ]),
                 ),
             ),
         );
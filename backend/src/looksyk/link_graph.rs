@@ -0,0 +1,387 @@
+use crate::looksyk::index::alias::resolve_alias;
+use crate::looksyk::model::{PageId, PageType, SimplePageName};
+use crate::state::block_properties::BlockPropertiesIndex;
+use crate::state::journal::JournalPageIndex;
+use crate::state::tag::TagIndex;
+use crate::state::userpage::UserPageIndex;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+const HIERARCHY_SEPARATOR: char = '/';
+
+pub struct LinkGraph {
+    pub nodes: Vec<LinkGraphNode>,
+    pub edges: Vec<LinkGraphEdge>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct LinkGraphNode {
+    pub page_id: PageId,
+    pub reference_count: usize,
+    pub exists: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum LinkGraphEdgeType {
+    Link,
+    Hierarchy,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct LinkGraphEdge {
+    pub source: PageId,
+    pub target: PageId,
+    pub edge_type: LinkGraphEdgeType,
+}
+
+pub struct LinkGraphFilter {
+    pub include_journals: bool,
+    pub min_degree: usize,
+    pub neighbourhood: Option<Neighbourhood>,
+}
+
+pub struct Neighbourhood {
+    pub center: PageId,
+    pub depth: usize,
+}
+
+pub fn create_link_graph(
+    tag_index: &TagIndex,
+    user_page_index: &UserPageIndex,
+    journal_page_index: &JournalPageIndex,
+    block_properties_index: &BlockPropertiesIndex,
+    filter: &LinkGraphFilter,
+) -> LinkGraph {
+    let mut nodes: HashMap<PageId, bool> = HashMap::new();
+    for name in user_page_index.entries.keys() {
+        nodes.insert(name.as_user_page(), true);
+    }
+    for (name, _) in journal_page_index.iter_entries() {
+        nodes.insert(name.as_journal_page(), true);
+    }
+
+    let mut references: HashMap<PageId, HashSet<&PageId>> = HashMap::new();
+    let mut edges: HashSet<(PageId, PageId, LinkGraphEdgeType)> = HashSet::new();
+    for (target, sources) in &tag_index.entries {
+        let target = resolve_page_alias(target, block_properties_index);
+        references
+            .entry(target.clone())
+            .or_default()
+            .extend(sources.iter());
+        for source in sources {
+            if *source == target {
+                continue;
+            }
+            nodes.entry(target.clone()).or_insert(false);
+            nodes.entry(source.clone()).or_insert(false);
+            edges.insert((source.clone(), target.clone(), LinkGraphEdgeType::Link));
+        }
+    }
+
+    let mut to_visit: Vec<PageId> = nodes.keys().cloned().collect();
+    while let Some(page_id) = to_visit.pop() {
+        if let Some(parent) = parent_page(&page_id) {
+            if !nodes.contains_key(&parent) {
+                nodes.insert(parent.clone(), false);
+                to_visit.push(parent.clone());
+            }
+            edges.insert((page_id, parent, LinkGraphEdgeType::Hierarchy));
+        }
+    }
+
+    if !filter.include_journals {
+        nodes.retain(|page_id, _| page_id.page_type != PageType::JournalPage);
+    }
+    edges.retain(|(source, target, _)| nodes.contains_key(source) && nodes.contains_key(target));
+
+    if let Some(neighbourhood) = &filter.neighbourhood {
+        let reachable = find_neighbourhood(
+            &edges,
+            &resolve_page_alias(&neighbourhood.center, block_properties_index),
+            neighbourhood.depth,
+        );
+        nodes.retain(|page_id, _| reachable.contains(page_id));
+        edges
+            .retain(|(source, target, _)| reachable.contains(source) && reachable.contains(target));
+    }
+
+    let degrees = count_degrees(&edges);
+    nodes.retain(|page_id, _| degrees.get(page_id).copied().unwrap_or(0) >= filter.min_degree);
+    edges.retain(|(source, target, _)| nodes.contains_key(source) && nodes.contains_key(target));
+
+    let mut nodes: Vec<(PageId, bool)> = nodes.into_iter().collect();
+    nodes.sort_by(|a, b| sort_key(&a.0).cmp(&sort_key(&b.0)));
+    let mut edges: Vec<(PageId, PageId, LinkGraphEdgeType)> = edges.into_iter().collect();
+    edges.sort_by(|a, b| {
+        sort_key(&a.0)
+            .cmp(&sort_key(&b.0))
+            .then(sort_key(&a.1).cmp(&sort_key(&b.1)))
+    });
+
+    LinkGraph {
+        nodes: nodes
+            .into_iter()
+            .map(|(page_id, exists)| LinkGraphNode {
+                reference_count: references
+                    .get(&page_id)
+                    .map(|references| references.len())
+                    .unwrap_or(0),
+                page_id,
+                exists,
+            })
+            .collect(),
+        edges: edges
+            .into_iter()
+            .map(|(source, target, edge_type)| LinkGraphEdge {
+                source,
+                target,
+                edge_type,
+            })
+            .collect(),
+    }
+}
+
+/// Links to an alias are links to the page owning the alias.
+fn resolve_page_alias(page_id: &PageId, block_properties_index: &BlockPropertiesIndex) -> PageId {
+    match page_id.page_type {
+        PageType::UserPage => resolve_alias(&page_id.name, block_properties_index).as_user_page(),
+        PageType::JournalPage => page_id.clone(),
+    }
+}
+
+fn sort_key(page_id: &PageId) -> (bool, &str) {
+    (!page_id.is_user_page(), &page_id.name.name)
+}
+
+fn parent_page(page_id: &PageId) -> Option<PageId> {
+    if page_id.page_type != PageType::UserPage {
+        return None;
+    }
+    let (parent, _) = page_id.name.name.rsplit_once(HIERARCHY_SEPARATOR)?;
+    let parent = parent.trim();
+    if parent.is_empty() {
+        return None;
+    }
+    Some(
+        SimplePageName {
+            name: parent.to_string(),
+        }
+        .as_user_page(),
+    )
+}
+
+fn find_neighbourhood(
+    edges: &HashSet<(PageId, PageId, LinkGraphEdgeType)>,
+    center: &PageId,
+    depth: usize,
+) -> HashSet<PageId> {
+    let mut adjacent: HashMap<&PageId, Vec<&PageId>> = HashMap::new();
+    for (source, target, _) in edges {
+        adjacent.entry(source).or_default().push(target);
+        adjacent.entry(target).or_default().push(source);
+    }
+
+    let mut result = HashSet::from([center.clone()]);
+    let mut queue = VecDeque::from([(center, 0)]);
+    while let Some((page_id, distance)) = queue.pop_front() {
+        if distance == depth {
+            continue;
+        }
+        for neighbour in adjacent.get(page_id).into_iter().flatten() {
+            if result.insert((*neighbour).clone()) {
+                queue.push_back((neighbour, distance + 1));
+            }
+        }
+    }
+    result
+}
+
+fn count_degrees(edges: &HashSet<(PageId, PageId, LinkGraphEdgeType)>) -> HashMap<&PageId, usize> {
+    let mut result = HashMap::new();
+    for (source, target, _) in edges {
+        *result.entry(source).or_insert(0) += 1;
+        *result.entry(target).or_insert(0) += 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::test_builder::{journal_page_id, user_page_id};
+    use crate::looksyk::index::alias::ALIAS_PROPERTY;
+    use crate::looksyk::model::ParsedMarkdownFile;
+    use crate::state::block_properties::builder::{
+        block_properties_index_with, block_property_key, block_property_occurance,
+    };
+    use crate::state::journal::builder::journal_page_index;
+    use crate::state::userpage::builder::user_page_index;
+
+    fn all() -> LinkGraphFilter {
+        LinkGraphFilter {
+            include_journals: true,
+            min_degree: 0,
+            neighbourhood: None,
+        }
+    }
+
+    fn tag_index() -> TagIndex {
+        let mut entries = HashMap::new();
+        entries.insert(
+            user_page_id("b"),
+            HashSet::from([user_page_id("a"), journal_page_id("2024_01_01")]),
+        );
+        entries.insert(user_page_id("c"), HashSet::from([user_page_id("b")]));
+        entries.insert(user_page_id("d"), HashSet::from([user_page_id("c")]));
+        TagIndex { entries }
+    }
+
+    fn create(filter: LinkGraphFilter) -> LinkGraph {
+        create_link_graph(
+            &tag_index(),
+            &user_page_index("a", ParsedMarkdownFile::empty()),
+            &journal_page_index("2024_01_01", ParsedMarkdownFile::empty()),
+            &BlockPropertiesIndex::default(),
+            &filter,
+        )
+    }
+
+    fn node_names(graph: &LinkGraph) -> Vec<String> {
+        graph
+            .nodes
+            .iter()
+            .map(|node| node.page_id.name.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_create_link_graph_should_contain_all_pages_and_links() {
+        let graph = create(all());
+
+        assert_eq!(node_names(&graph), vec!["a", "b", "c", "d", "2024_01_01"]);
+        assert_eq!(graph.edges.len(), 4);
+        assert_eq!(
+            graph.nodes[1],
+            LinkGraphNode {
+                page_id: user_page_id("b"),
+                reference_count: 2,
+                exists: false,
+            }
+        );
+        assert!(graph.nodes[0].exists);
+    }
+
+    #[test]
+    fn test_create_link_graph_should_exclude_journals() {
+        let graph = create(LinkGraphFilter {
+            include_journals: false,
+            ..all()
+        });
+
+        assert_eq!(node_names(&graph), vec!["a", "b", "c", "d"]);
+        assert_eq!(graph.edges.len(), 3);
+    }
+
+    #[test]
+    fn test_create_link_graph_should_filter_by_min_degree() {
+        let graph = create(LinkGraphFilter {
+            min_degree: 2,
+            ..all()
+        });
+
+        assert_eq!(node_names(&graph), vec!["b", "c"]);
+        assert_eq!(graph.edges.len(), 1);
+    }
+
+    #[test]
+    fn test_create_link_graph_should_select_neighbourhood() {
+        let graph = create(LinkGraphFilter {
+            neighbourhood: Some(Neighbourhood {
+                center: user_page_id("d"),
+                depth: 2,
+            }),
+            ..all()
+        });
+
+        assert_eq!(node_names(&graph), vec!["b", "c", "d"]);
+        assert_eq!(graph.edges.len(), 2);
+    }
+
+    #[test]
+    fn test_create_link_graph_should_add_hierarchy_edges() {
+        let graph = create_link_graph(
+            &TagIndex {
+                entries: HashMap::new(),
+            },
+            &user_page_index("parent / child / leaf", ParsedMarkdownFile::empty()),
+            &journal_page_index("2024_01_01", ParsedMarkdownFile::empty()),
+            &BlockPropertiesIndex::default(),
+            &LinkGraphFilter {
+                include_journals: false,
+                ..all()
+            },
+        );
+
+        assert_eq!(
+            node_names(&graph),
+            vec!["parent", "parent / child", "parent / child / leaf"]
+        );
+        assert_eq!(
+            graph.edges,
+            vec![
+                LinkGraphEdge {
+                    source: user_page_id("parent / child"),
+                    target: user_page_id("parent"),
+                    edge_type: LinkGraphEdgeType::Hierarchy,
+                },
+                LinkGraphEdge {
+                    source: user_page_id("parent / child / leaf"),
+                    target: user_page_id("parent / child"),
+                    edge_type: LinkGraphEdgeType::Hierarchy,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_link_graph_should_resolve_links_to_aliases() {
+        let mut entries = HashMap::new();
+        entries.insert(user_page_id("K8s"), HashSet::from([user_page_id("a")]));
+        entries.insert(
+            user_page_id("Kubernetes"),
+            HashSet::from([journal_page_id("2024_01_01")]),
+        );
+        let alias_index = block_properties_index_with(
+            block_property_key(ALIAS_PROPERTY),
+            vec![block_property_occurance(
+                "K8s",
+                user_page_id("Kubernetes").block_reference(0),
+            )],
+        );
+
+        let graph = create_link_graph(
+            &TagIndex { entries },
+            &user_page_index("a", ParsedMarkdownFile::empty()),
+            &journal_page_index("2024_01_01", ParsedMarkdownFile::empty()),
+            &alias_index,
+            &all(),
+        );
+
+        assert_eq!(node_names(&graph), vec!["Kubernetes", "a", "2024_01_01"]);
+        assert_eq!(graph.nodes[0].reference_count, 2);
+        assert_eq!(graph.edges.len(), 2);
+    }
+
+    #[test]
+    fn test_create_link_graph_should_select_neighbourhood_of_journal() {
+        let graph = create(LinkGraphFilter {
+            neighbourhood: Some(Neighbourhood {
+                center: journal_page_id("2024_01_01"),
+                depth: 1,
+            }),
+            ..all()
+        });
+
+        assert_eq!(node_names(&graph), vec!["b", "2024_01_01"]);
+        assert_eq!(graph.edges.len(), 1);
+    }
+}
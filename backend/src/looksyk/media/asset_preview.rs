@@ -1,12 +1,15 @@
 use crate::io::fs::media::MediaOnDisk;
 use crate::looksyk::datatypes::AssetDescriptor;
 use crate::looksyk::media::media_type::{get_media_type_from_extension, MediaType};
-use crate::looksyk::queries::insert_file_content::render_code_block;
-use crate::looksyk::syntax::markdown::{render_as_audio, render_as_video, render_asset_as_image};
+use crate::looksyk::media::thumbnail::{get_thumbnail_path, PREVIEW_THUMBNAIL_SIZE};
+use crate::looksyk::queries::insert_file_content::{render_code_block, render_table_preview};
+use crate::looksyk::syntax::markdown::{render_as_audio, render_as_image, render_as_video};
 use crate::state::application_state::GraphRootLocation;
 use crate::state::asset_cache::AssetCache;
 use humansize::{format_size, DECIMAL};
 
+const TABLE_PREVIEW_ROWS: usize = 20;
+
 pub struct AssetPreview {
     pub markdown_preview: Option<String>,
     pub html_preview_link: Option<String>,
@@ -27,7 +30,10 @@ pub fn generate_asset_preview(
     let media_type = get_media_type_from_extension(&asset_descriptor);
     match media_type {
         MediaType::Image => AssetPreview {
-            markdown_preview: Some(render_asset_as_image(&asset_descriptor)),
+            markdown_preview: Some(render_as_image(
+                &asset_descriptor.get_display_name(),
+                &get_thumbnail_path(&asset_descriptor, PREVIEW_THUMBNAIL_SIZE),
+            )),
             html_preview_link: None,
             properties: AssetProperties {
                 size: format_size(file_size, DECIMAL),
@@ -88,6 +94,22 @@ pub fn generate_asset_preview(
                 full_qualified_path: asset_descriptor.get_qualified_path(),
             },
         },
+        MediaType::Table => AssetPreview {
+            markdown_preview: Some(
+                render_table_preview(
+                    &MediaOnDisk::new(&asset_descriptor),
+                    cache,
+                    data_root_location,
+                    TABLE_PREVIEW_ROWS,
+                )
+                .inplace_markdown,
+            ),
+            html_preview_link: None,
+            properties: AssetProperties {
+                size: format_size(file_size, DECIMAL),
+                full_qualified_path: asset_descriptor.get_qualified_path(),
+            },
+        },
         MediaType::Html => AssetPreview {
             markdown_preview: None,
             html_preview_link: Some(asset_descriptor.get_qualified_path()),
@@ -125,7 +147,7 @@ mod tests {
 
         assert_eq!(
             result.markdown_preview.unwrap(),
-            "![filename.png](/assets/filename.png)"
+            "![filename.png](/assets/thumbnail/filename.png?size=720)"
         );
         assert_eq!(result.html_preview_link, None);
         assert_eq!(result.properties.size, "100 B");
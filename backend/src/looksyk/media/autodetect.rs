@@ -4,7 +4,8 @@ use crate::looksyk::datatypes::AssetDescriptor;
 use crate::looksyk::media::media_type::{get_media_type_from_extension, MediaType};
 use crate::looksyk::queries::insert_file_content::{
     query_insert_file_content_as_audio, query_insert_file_content_as_code,
-    query_insert_file_content_as_text, query_insert_file_content_as_video,
+    query_insert_file_content_as_table, query_insert_file_content_as_text,
+    query_insert_file_content_as_video,
 };
 use crate::looksyk::syntax::markdown::{render_asset_as_image, render_asset_as_link};
 
@@ -26,6 +27,7 @@ pub fn inver_markdown_media_link(index_filename: &String) -> String {
         MediaType::Audio => query_insert_file_content_as_audio(&asset_descriptor),
         MediaType::Code => query_insert_file_content_as_code(&asset_descriptor),
         MediaType::Text => query_insert_file_content_as_text(&asset_descriptor),
+        MediaType::Table => query_insert_file_content_as_table(&asset_descriptor),
         MediaType::Image => render_asset_as_image(&asset_descriptor),
     }
 }
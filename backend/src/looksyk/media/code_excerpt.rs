@@ -0,0 +1,191 @@
+#[derive(Default)]
+pub struct CodeExcerpt {
+    pub lines: Option<LineRange>,
+    pub from_marker: Option<String>,
+    pub to_marker: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct LineRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+pub fn parse_line_range(value: &str) -> Option<LineRange> {
+    let (start, end) = match value.split_once('-') {
+        Some((start, end)) => (start.trim(), end.trim()),
+        None => (value.trim(), value.trim()),
+    };
+    Some(LineRange {
+        start: start.parse().ok()?,
+        end: end.parse().ok()?,
+    })
+}
+
+pub fn select_excerpt(content: &str, excerpt: &CodeExcerpt) -> Result<String, String> {
+    let has_markers = excerpt.from_marker.is_some() || excerpt.to_marker.is_some();
+    if excerpt.lines.is_some() && has_markers {
+        return Err("Use either 'lines' or 'from-marker'/'to-marker', not both.".to_string());
+    }
+    if let Some(range) = excerpt.lines {
+        return select_lines(content, range);
+    }
+    if has_markers {
+        return select_between_markers(
+            content,
+            excerpt.from_marker.as_deref(),
+            excerpt.to_marker.as_deref(),
+        );
+    }
+    Ok(content.to_string())
+}
+
+fn select_lines(content: &str, range: LineRange) -> Result<String, String> {
+    let lines: Vec<&str> = content.lines().collect();
+    if range.start == 0 || range.start > range.end || range.end > lines.len() {
+        return Err(format!(
+            "Line range {}-{} is out of bounds, the file has {} lines.",
+            range.start,
+            range.end,
+            lines.len()
+        ));
+    }
+    Ok(lines[range.start - 1..range.end].join("\n"))
+}
+
+fn select_between_markers(
+    content: &str,
+    from_marker: Option<&str>,
+    to_marker: Option<&str>,
+) -> Result<String, String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let start = match from_marker {
+        Some(marker) => {
+            lines
+                .iter()
+                .position(|line| line.contains(marker))
+                .ok_or(format!("Marker '{marker}' not found."))?
+                + 1
+        }
+        None => 0,
+    };
+    let end = match to_marker {
+        Some(marker) => {
+            lines[start..]
+                .iter()
+                .position(|line| line.contains(marker))
+                .ok_or(format!("Marker '{marker}' not found."))?
+                + start
+        }
+        None => lines.len(),
+    };
+    Ok(lines[start..end].join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "one\ntwo\n// region foo\nthree\nfour\n// endregion foo\nfive";
+
+    #[test]
+    fn test_parse_line_range() {
+        assert_eq!(
+            parse_line_range("40-80"),
+            Some(LineRange { start: 40, end: 80 })
+        );
+        assert_eq!(parse_line_range("7"), Some(LineRange { start: 7, end: 7 }));
+        assert_eq!(parse_line_range("a-b"), None);
+    }
+
+    #[test]
+    fn test_select_excerpt_without_options_should_return_content() {
+        assert_eq!(
+            select_excerpt(CONTENT, &CodeExcerpt::default()).unwrap(),
+            CONTENT
+        );
+    }
+
+    #[test]
+    fn test_select_excerpt_with_lines() {
+        let result = select_excerpt(
+            CONTENT,
+            &CodeExcerpt {
+                lines: Some(LineRange { start: 2, end: 4 }),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(result.unwrap(), "two\n// region foo\nthree");
+    }
+
+    #[test]
+    fn test_select_excerpt_with_lines_out_of_bounds() {
+        let result = select_excerpt(
+            CONTENT,
+            &CodeExcerpt {
+                lines: Some(LineRange { start: 5, end: 80 }),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            "Line range 5-80 is out of bounds, the file has 7 lines."
+        );
+    }
+
+    #[test]
+    fn test_select_excerpt_with_markers_should_exclude_marker_lines() {
+        let result = select_excerpt(
+            CONTENT,
+            &CodeExcerpt {
+                from_marker: Some("// region foo".to_string()),
+                to_marker: Some("// endregion foo".to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(result.unwrap(), "three\nfour");
+    }
+
+    #[test]
+    fn test_select_excerpt_with_from_marker_only_should_read_to_end() {
+        let result = select_excerpt(
+            CONTENT,
+            &CodeExcerpt {
+                from_marker: Some("// endregion foo".to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(result.unwrap(), "five");
+    }
+
+    #[test]
+    fn test_select_excerpt_with_missing_marker() {
+        let result = select_excerpt(
+            CONTENT,
+            &CodeExcerpt {
+                to_marker: Some("// region bar".to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(result.unwrap_err(), "Marker '// region bar' not found.");
+    }
+
+    #[test]
+    fn test_select_excerpt_with_lines_and_markers_should_fail() {
+        let result = select_excerpt(
+            CONTENT,
+            &CodeExcerpt {
+                lines: Some(LineRange { start: 1, end: 2 }),
+                from_marker: Some("one".to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert!(result.is_err());
+    }
+}
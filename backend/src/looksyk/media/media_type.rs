@@ -10,6 +10,7 @@ pub enum MediaType {
     Text,
     Html,
     Pdf,
+    Table,
 }
 
 pub fn get_media_type_from_extension(asset_descriptor: &AssetDescriptor) -> MediaType {
@@ -27,10 +28,24 @@ pub fn get_media_type_from_extension(asset_descriptor: &AssetDescriptor) -> Medi
         | "yaml" | "json" | "bash" => MediaType::Code,
         "html" | "htm" => MediaType::Html,
         "pdf" => MediaType::Pdf,
+        "csv" | "tsv" => MediaType::Table,
         _ => MediaType::Other,
     }
 }
 
+pub fn is_raster_image(asset_descriptor: &AssetDescriptor) -> bool {
+    if get_media_type_from_extension(asset_descriptor) != MediaType::Image {
+        return false;
+    }
+    !matches!(
+        asset_descriptor
+            .find_extension()
+            .map(|extension| extension.to_lowercase())
+            .as_deref(),
+        Some("svg") | Some("avif")
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,6 +86,14 @@ mod tests {
         assert_eq!(result, MediaType::Other);
     }
 
+    #[test]
+    fn test_is_raster_image() {
+        assert!(is_raster_image(&asset_descriptor("photo.JPG")));
+        assert!(is_raster_image(&asset_descriptor("screen.png")));
+        assert!(!is_raster_image(&asset_descriptor("vector.svg")));
+        assert!(!is_raster_image(&asset_descriptor("video.mp4")));
+    }
+
     #[test]
     fn test_get_media_type_from_extension_with_no_extension_should_return_other() {
         let result = get_media_type_from_extension(&asset_descriptor("file"));
@@ -1,4 +1,10 @@
 pub mod asset_preview;
 pub mod autodetect;
+pub mod code_excerpt;
 pub mod media_type;
+pub mod rename;
 pub mod suggestion;
+pub mod table;
+pub mod thumbnail;
+pub mod upload_policy;
+pub mod usage;
@@ -0,0 +1,263 @@
+use crate::io::http::media::config::REL_MEDIA_LOCATION;
+use crate::looksyk::datatypes::AssetDescriptor;
+use crate::looksyk::index::rename::{FileChanges, RenameTagResult};
+use crate::looksyk::model::{BlockContent, ParsedBlock, ParsedMarkdownFile};
+use crate::looksyk::parser::parse_text_content;
+use crate::state::application_state::{CurrentPageOnDiskState, NewPageOnDiskState};
+use std::collections::HashSet;
+
+pub struct OldMediaName {
+    pub file_name: String,
+}
+
+pub struct NewMediaName {
+    pub file_name: String,
+}
+
+pub fn rename_media_across_all_files(
+    old_media_name: &OldMediaName,
+    new_media_name: &NewMediaName,
+    current_page_associated_state: CurrentPageOnDiskState,
+) -> RenameTagResult {
+    let mut changed_files = HashSet::new();
+    let mut new_user_pages = current_page_associated_state.user_pages.clone();
+    let mut new_journal_pages = current_page_associated_state.journal_pages.clone();
+
+    for (name, file) in current_page_associated_state.user_pages.iter_entries() {
+        if let Some(new_file) = rename_media_in_file(old_media_name, new_media_name, file) {
+            new_user_pages.entries.insert(name.clone(), new_file);
+            changed_files.insert(name.as_user_page());
+        }
+    }
+
+    for (name, file) in current_page_associated_state.journal_pages.iter_entries() {
+        if let Some(new_file) = rename_media_in_file(old_media_name, new_media_name, file) {
+            new_journal_pages.insert(name.clone(), new_file);
+            changed_files.insert(name.as_journal_page());
+        }
+    }
+
+    RenameTagResult {
+        new_page_associated_state: NewPageOnDiskState {
+            user_pages: new_user_pages,
+            journal_pages: new_journal_pages,
+        },
+        file_changes: FileChanges {
+            changed_files,
+            file_to_delete: HashSet::new(),
+        },
+    }
+}
+
+fn rename_media_in_file(
+    old_media_name: &OldMediaName,
+    new_media_name: &NewMediaName,
+    parsed_markdown_file: &ParsedMarkdownFile,
+) -> Option<ParsedMarkdownFile> {
+    let mut changed = false;
+    let mut new_blocks = vec![];
+    for block in &parsed_markdown_file.blocks {
+        let mut new_content = vec![];
+        for line in &block.content {
+            let new_text = rename_media_in_text(old_media_name, new_media_name, &line.as_text);
+            if new_text == line.as_text {
+                new_content.push(line.clone());
+                continue;
+            }
+            changed = true;
+            new_content.push(BlockContent {
+                as_tokens: parse_text_content(&new_text).tokens,
+                as_text: new_text,
+            });
+        }
+        new_blocks.push(ParsedBlock {
+            indentation: block.indentation,
+            content: new_content,
+            properties: block.properties.clone(),
+        });
+    }
+
+    if !changed {
+        return None;
+    }
+    Some(ParsedMarkdownFile::from_blocks(new_blocks))
+}
+
+fn rename_media_in_text(
+    old_media_name: &OldMediaName,
+    new_media_name: &NewMediaName,
+    text: &str,
+) -> String {
+    let old_asset = AssetDescriptor::new(old_media_name.file_name.clone());
+    let new_asset = AssetDescriptor::new(new_media_name.file_name.clone());
+
+    let mut result = replace_media_link(
+        text,
+        &old_asset.get_qualified_path(),
+        &new_asset.get_qualified_path(),
+    );
+    let unencoded_old_link = format!("/{}/{}", REL_MEDIA_LOCATION, old_media_name.file_name);
+    if unencoded_old_link != old_asset.get_qualified_path() {
+        result = replace_media_link(
+            &result,
+            &unencoded_old_link,
+            &new_asset.get_qualified_path(),
+        );
+    }
+
+    result.replace(
+        &format!("target-file:\"{}\"", old_media_name.file_name),
+        &format!("target-file:\"{}\"", new_media_name.file_name),
+    )
+}
+
+fn replace_media_link(text: &str, old_link: &str, new_link: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(index) = rest.find(old_link) {
+        let after_link = &rest[index + old_link.len()..];
+        result.push_str(&rest[..index]);
+        if ends_media_link(after_link) {
+            result.push_str(new_link);
+        } else {
+            result.push_str(old_link);
+        }
+        rest = after_link;
+    }
+    result.push_str(rest);
+    result
+}
+
+fn ends_media_link(text_after_link: &str) -> bool {
+    match text_after_link.chars().next() {
+        None => true,
+        Some(c) => matches!(c, ')' | '"' | '\'' | ' ' | '\n' | '\t' | '?' | '#'),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::page_name_str;
+    use crate::looksyk::builder::test_builder::empty_journal_index;
+    use crate::looksyk::model::builder::block_with_text_content;
+    use crate::state::journal::builder::journal_page_index;
+    use crate::state::userpage::builder::{empty_user_page_index, user_page_index};
+
+    fn rename(old: &str, new: &str, text: &str) -> String {
+        rename_media_in_text(
+            &OldMediaName {
+                file_name: old.to_string(),
+            },
+            &NewMediaName {
+                file_name: new.to_string(),
+            },
+            text,
+        )
+    }
+
+    #[test]
+    fn test_rename_media_in_text_should_rewrite_image_links() {
+        assert_eq!(
+            rename(
+                "a.png",
+                "b.png",
+                "![x](/assets/a.png) and [y](/assets/a.png)"
+            ),
+            "![x](/assets/b.png) and [y](/assets/b.png)"
+        );
+    }
+
+    #[test]
+    fn test_rename_media_in_text_should_encode_new_name() {
+        assert_eq!(
+            rename(
+                "old file.png",
+                "new file.png",
+                "![x](/assets/old%20file.png)"
+            ),
+            "![x](/assets/new%20file.png)"
+        );
+    }
+
+    #[test]
+    fn test_rename_media_in_text_should_not_touch_longer_file_names() {
+        assert_eq!(
+            rename("a.png", "b.png", "![x](/assets/a.png.bak)"),
+            "![x](/assets/a.png.bak)"
+        );
+    }
+
+    #[test]
+    fn test_rename_media_in_text_should_rewrite_target_file() {
+        assert_eq!(
+            rename(
+                "code.rs",
+                "main.rs",
+                "{query: insert-file-content target-file:\"code.rs\" display:\"code-block\" }"
+            ),
+            "{query: insert-file-content target-file:\"main.rs\" display:\"code-block\" }"
+        );
+    }
+
+    #[test]
+    fn test_rename_media_across_all_files_should_only_report_referencing_pages() {
+        let user_pages = user_page_index(
+            "referencing",
+            ParsedMarkdownFile::from_blocks(vec![block_with_text_content("![x](/assets/a.png)")]),
+        );
+        let journal_pages = journal_page_index(
+            "2024_01_01",
+            ParsedMarkdownFile::from_blocks(vec![block_with_text_content("nothing here")]),
+        );
+
+        let result = rename_media_across_all_files(
+            &OldMediaName {
+                file_name: "a.png".to_string(),
+            },
+            &NewMediaName {
+                file_name: "b.png".to_string(),
+            },
+            CurrentPageOnDiskState {
+                user_pages: &user_pages,
+                journal_pages: &journal_pages,
+            },
+        );
+
+        let referencing = page_name_str("referencing");
+        assert_eq!(
+            result.file_changes.changed_files,
+            HashSet::from([referencing.as_user_page()])
+        );
+        assert!(result.file_changes.file_to_delete.is_empty());
+        assert_eq!(
+            result
+                .new_page_associated_state
+                .user_pages
+                .find(&referencing)
+                .unwrap()
+                .blocks[0]
+                .content[0]
+                .as_text,
+            "![x](/assets/b.png)"
+        );
+    }
+
+    #[test]
+    fn test_rename_media_across_all_files_should_handle_empty_state() {
+        let result = rename_media_across_all_files(
+            &OldMediaName {
+                file_name: "a.png".to_string(),
+            },
+            &NewMediaName {
+                file_name: "b.png".to_string(),
+            },
+            CurrentPageOnDiskState {
+                user_pages: &empty_user_page_index(),
+                journal_pages: &empty_journal_index(),
+            },
+        );
+
+        assert!(result.file_changes.changed_files.is_empty());
+    }
+}
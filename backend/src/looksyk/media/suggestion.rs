@@ -2,7 +2,8 @@ use crate::looksyk::datatypes::AssetDescriptor;
 use crate::looksyk::media::media_type::{get_media_type_from_extension, MediaType};
 use crate::looksyk::queries::insert_file_content::{
     query_insert_file_content_as_audio, query_insert_file_content_as_code,
-    query_insert_file_content_as_text, query_insert_file_content_as_video,
+    query_insert_file_content_as_table, query_insert_file_content_as_text,
+    query_insert_file_content_as_video,
 };
 use crate::looksyk::syntax::markdown::{
     render_as_audio, render_as_video, render_asset_as_image, render_asset_as_link,
@@ -53,6 +54,13 @@ pub fn get_suggestion_for_file(asset_descriptor: &AssetDescriptor) -> Suggestion
                 suggest_as_link(asset_descriptor),
             ],
         },
+        MediaType::Table => Suggestions {
+            suggestions: vec![
+                suggest_as_table_query(asset_descriptor),
+                suggest_as_code_query(asset_descriptor),
+                suggest_as_link(asset_descriptor),
+            ],
+        },
         MediaType::Text => Suggestions {
             suggestions: vec![
                 suggest_as_text_query(asset_descriptor),
@@ -73,6 +81,7 @@ const INSERT_QUERY_AUDIO: &str = "Query: Show audio (renders a audio player)";
 const INSERT_HTML_AUDIO: &str = "Html-Code audio player";
 const INSERT_QUERY_TEXT: &str = "Query: Insert text as text block";
 const INSERT_QUERY_CODE: &str = "Query: Insert text as code block with code highlighting";
+const INSERT_QUERY_TABLE: &str = "Query: Insert file as table";
 
 fn suggest_as_link(asset_descriptor: &AssetDescriptor) -> Suggestion {
     Suggestion {
@@ -137,13 +146,20 @@ fn suggest_as_code_query(asset_descriptor: &AssetDescriptor) -> Suggestion {
     }
 }
 
+fn suggest_as_table_query(asset_descriptor: &AssetDescriptor) -> Suggestion {
+    Suggestion {
+        explanation: INSERT_QUERY_TABLE.to_string(),
+        inplace_markdown: query_insert_file_content_as_table(asset_descriptor),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::looksyk::builder::test_builder::asset_descriptor;
     use crate::looksyk::media::suggestion::{
         get_suggestion_for_file, INSERT_HTML_AUDIO, INSERT_HTML_VIDEO, INSERT_LINK_TEXT,
         INSERT_MARKDOWN_PREVIEW, INSERT_MARKDOWN_PREVIEW_NOT_SUPPORTED, INSERT_QUERY_AUDIO,
-        INSERT_QUERY_CODE, INSERT_QUERY_TEXT, INSERT_QUERY_VIDEO,
+        INSERT_QUERY_CODE, INSERT_QUERY_TABLE, INSERT_QUERY_TEXT, INSERT_QUERY_VIDEO,
     };
 
     #[test]
@@ -262,4 +278,18 @@ mod tests {
             "[test.rs](/assets/test.rs)"
         );
     }
+
+    #[test]
+    fn test_get_suggestion_for_csv() {
+        let suggestions = get_suggestion_for_file(&asset_descriptor("test.csv"));
+
+        assert_eq!(suggestions.suggestions.len(), 3);
+        assert_eq!(suggestions.suggestions[0].explanation, INSERT_QUERY_TABLE);
+        assert_eq!(
+            suggestions.suggestions[0].inplace_markdown,
+            "{query: insert-file-content target-file:\"test.csv\" display:\"table\" }"
+        );
+        assert_eq!(suggestions.suggestions[1].explanation, INSERT_QUERY_CODE);
+        assert_eq!(suggestions.suggestions[2].explanation, INSERT_LINK_TEXT);
+    }
 }
@@ -0,0 +1,255 @@
+use crate::looksyk::datatypes::AssetDescriptor;
+
+pub const DELIMITER_TAB: &str = "tab";
+
+pub struct TableOptions {
+    pub delimiter: char,
+    pub header: bool,
+    pub limit: Option<usize>,
+    pub columns: Option<Vec<String>>,
+}
+
+pub fn default_delimiter(asset_descriptor: &AssetDescriptor) -> char {
+    match asset_descriptor
+        .get_extension("csv")
+        .to_lowercase()
+        .as_str()
+    {
+        "tsv" => '\t',
+        _ => ',',
+    }
+}
+
+pub fn parse_delimiter(value: &str) -> Option<char> {
+    if value == DELIMITER_TAB {
+        return Some('\t');
+    }
+    let mut chars = value.chars();
+    let delimiter = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+    Some(delimiter)
+}
+
+pub fn parse_delimited(content: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    cell.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                cell.push(c);
+            }
+            continue;
+        }
+        match c {
+            '"' if cell.is_empty() => in_quotes = true,
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut cell));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ if c == delimiter => row.push(std::mem::take(&mut cell)),
+            _ => cell.push(c),
+        }
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+
+    rows.retain(|row| !(row.len() == 1 && row[0].is_empty()));
+    rows
+}
+
+pub fn render_as_markdown_table(content: &str, options: &TableOptions) -> String {
+    let rows = parse_delimited(content, options.delimiter);
+    let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if column_count == 0 {
+        return "Table is empty".to_string();
+    }
+
+    let (header, body) = if options.header {
+        (rows[0].clone(), &rows[1..])
+    } else {
+        (
+            (1..=column_count).map(|i| format!("Column {i}")).collect(),
+            &rows[..],
+        )
+    };
+
+    let selected_columns = match &options.columns {
+        Some(columns) => match select_columns(&header, columns) {
+            Ok(selected) => selected,
+            Err(unknown) => return format!("Unknown column '{unknown}'"),
+        },
+        None => (0..column_count).collect(),
+    };
+
+    let limit = options.limit.unwrap_or(body.len());
+    let mut result = render_row(&header, &selected_columns);
+    result.push_str(&format!("|{}\n", " :-- |".repeat(selected_columns.len())));
+    for row in body.iter().take(limit) {
+        result.push_str(&render_row(row, &selected_columns));
+    }
+    if body.len() > limit {
+        result.push_str(&format!("\n*{} more rows*\n", body.len() - limit));
+    }
+    result
+}
+
+fn select_columns(header: &[String], columns: &[String]) -> Result<Vec<usize>, String> {
+    columns
+        .iter()
+        .map(|column| {
+            header
+                .iter()
+                .position(|name| name.trim() == column)
+                .or_else(|| {
+                    column
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|index| *index >= 1 && *index <= header.len())
+                        .map(|index| index - 1)
+                })
+                .ok_or(column.clone())
+        })
+        .collect()
+}
+
+fn render_row(row: &[String], selected_columns: &[usize]) -> String {
+    let mut result = "|".to_string();
+    for column in selected_columns {
+        let cell = row.get(*column).map(String::as_str).unwrap_or("");
+        result.push_str(&format!(" {} |", escape_cell(cell)));
+    }
+    result.push('\n');
+    result
+}
+
+pub fn escape_cell(cell: &str) -> String {
+    cell.trim().replace('|', "\\|").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::test_builder::asset_descriptor;
+
+    fn options() -> TableOptions {
+        TableOptions {
+            delimiter: ',',
+            header: true,
+            limit: None,
+            columns: None,
+        }
+    }
+
+    #[test]
+    fn test_default_delimiter_should_use_tab_for_tsv() {
+        assert_eq!(default_delimiter(&asset_descriptor("data.tsv")), '\t');
+        assert_eq!(default_delimiter(&asset_descriptor("data.csv")), ',');
+    }
+
+    #[test]
+    fn test_parse_delimiter() {
+        assert_eq!(parse_delimiter("tab"), Some('\t'));
+        assert_eq!(parse_delimiter(";"), Some(';'));
+        assert_eq!(parse_delimiter(";;"), None);
+        assert_eq!(parse_delimiter(""), None);
+    }
+
+    #[test]
+    fn test_parse_delimited_should_handle_quotes() {
+        let result = parse_delimited("a,\"b, c\",\"say \"\"hi\"\"\"\r\n1,2,3\n", ',');
+
+        assert_eq!(
+            result,
+            vec![
+                vec![
+                    "a".to_string(),
+                    "b, c".to_string(),
+                    "say \"hi\"".to_string()
+                ],
+                vec!["1".to_string(), "2".to_string(), "3".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_as_markdown_table_with_header() {
+        let result = render_as_markdown_table("name,size\na|b,1\nc,2", &options());
+
+        assert_eq!(
+            result,
+            "| name | size |\n| :-- | :-- |\n| a\\|b | 1 |\n| c | 2 |\n"
+        );
+    }
+
+    #[test]
+    fn test_render_as_markdown_table_without_header() {
+        let result = render_as_markdown_table(
+            "a;b\nc;d",
+            &TableOptions {
+                delimiter: ';',
+                header: false,
+                ..options()
+            },
+        );
+
+        assert_eq!(
+            result,
+            "| Column 1 | Column 2 |\n| :-- | :-- |\n| a | b |\n| c | d |\n"
+        );
+    }
+
+    #[test]
+    fn test_render_as_markdown_table_should_limit_rows() {
+        let result = render_as_markdown_table(
+            "n\n1\n2\n3",
+            &TableOptions {
+                limit: Some(1),
+                ..options()
+            },
+        );
+
+        assert_eq!(result, "| n |\n| :-- |\n| 1 |\n\n*2 more rows*\n");
+    }
+
+    #[test]
+    fn test_render_as_markdown_table_should_select_columns_by_name_and_index() {
+        let result = render_as_markdown_table(
+            "a,b,c\n1,2,3",
+            &TableOptions {
+                columns: Some(vec!["c".to_string(), "1".to_string()]),
+                ..options()
+            },
+        );
+
+        assert_eq!(result, "| c | a |\n| :-- | :-- |\n| 3 | 1 |\n");
+    }
+
+    #[test]
+    fn test_render_as_markdown_table_should_report_unknown_column() {
+        let result = render_as_markdown_table(
+            "a,b\n1,2",
+            &TableOptions {
+                columns: Some(vec!["x".to_string()]),
+                ..options()
+            },
+        );
+
+        assert_eq!(result, "Unknown column 'x'");
+    }
+}
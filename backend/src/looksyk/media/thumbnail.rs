@@ -0,0 +1,69 @@
+use crate::looksyk::datatypes::AssetDescriptor;
+use crate::looksyk::media::media_type::is_raster_image;
+use crate::looksyk::syntax::markdown::encode_uri_component;
+
+pub const DEFAULT_THUMBNAIL_SIZE: u32 = 256;
+pub const OVERVIEW_THUMBNAIL_SIZE: u32 = 64;
+pub const PREVIEW_THUMBNAIL_SIZE: u32 = 720;
+const MIN_THUMBNAIL_SIZE: u32 = 16;
+const MAX_THUMBNAIL_SIZE: u32 = 2048;
+
+pub fn thumbnail_size(requested_size: Option<u32>) -> u32 {
+    requested_size
+        .unwrap_or(DEFAULT_THUMBNAIL_SIZE)
+        .clamp(MIN_THUMBNAIL_SIZE, MAX_THUMBNAIL_SIZE)
+}
+
+pub fn supports_thumbnail(asset_descriptor: &AssetDescriptor) -> bool {
+    is_raster_image(asset_descriptor)
+}
+
+pub fn get_thumbnail_path(asset_descriptor: &AssetDescriptor, size: u32) -> String {
+    if !supports_thumbnail(asset_descriptor) {
+        return asset_descriptor.get_qualified_path();
+    }
+    format!(
+        "/assets/thumbnail/{}?size={}",
+        encode_uri_component(&asset_descriptor.get_display_name()),
+        size
+    )
+}
+
+pub fn thumbnail_file_name(sha3: &str, size: u32) -> String {
+    format!("{sha3}_{size}.png")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::test_builder::asset_descriptor;
+
+    #[test]
+    fn test_thumbnail_size_should_use_default() {
+        assert_eq!(thumbnail_size(None), DEFAULT_THUMBNAIL_SIZE);
+    }
+
+    #[test]
+    fn test_thumbnail_size_should_clamp() {
+        assert_eq!(thumbnail_size(Some(1)), MIN_THUMBNAIL_SIZE);
+        assert_eq!(thumbnail_size(Some(100_000)), MAX_THUMBNAIL_SIZE);
+        assert_eq!(thumbnail_size(Some(128)), 128);
+    }
+
+    #[test]
+    fn test_get_thumbnail_path() {
+        assert_eq!(
+            get_thumbnail_path(&asset_descriptor("my photo.jpg"), 64),
+            "/assets/thumbnail/my%20photo.jpg?size=64"
+        );
+        assert_eq!(
+            get_thumbnail_path(&asset_descriptor("vector.svg"), 64),
+            "/assets/vector.svg"
+        );
+    }
+
+    #[test]
+    fn test_thumbnail_file_name() {
+        assert_eq!(thumbnail_file_name("abc", 64), "abc_64.png");
+    }
+}
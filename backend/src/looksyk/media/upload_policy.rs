@@ -0,0 +1,168 @@
+use crate::looksyk::datatypes::AssetDescriptor;
+use crate::looksyk::media::media_type::is_raster_image;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub const DEFAULT_UPLOAD_QUALITY: u8 = 85;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UploadPolicy {
+    pub max_dimension: Option<u32>,
+    pub target_format: Option<ImageTargetFormat>,
+    pub quality: u8,
+    pub strip_metadata: bool,
+}
+
+impl Default for UploadPolicy {
+    fn default() -> Self {
+        UploadPolicy {
+            max_dimension: None,
+            target_format: None,
+            quality: DEFAULT_UPLOAD_QUALITY,
+            strip_metadata: false,
+        }
+    }
+}
+
+/// WebP is not offered as a target, the available encoder only writes lossless WebP,
+/// which ignores the quality setting and makes photos larger than the original upload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImageTargetFormat {
+    Jpeg,
+    Png,
+}
+
+impl ImageTargetFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageTargetFormat::Jpeg => "jpg",
+            ImageTargetFormat::Png => "png",
+        }
+    }
+}
+
+impl FromStr for ImageTargetFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "jpeg" | "jpg" => Ok(ImageTargetFormat::Jpeg),
+            "png" => Ok(ImageTargetFormat::Png),
+            "webp" => Err("webp is not supported as upload target format, only lossless encoding is available".to_string()),
+            _ => Err(format!("Unknown image target format: {s}")),
+        }
+    }
+}
+
+impl Display for ImageTargetFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageTargetFormat::Jpeg => write!(f, "jpeg"),
+            ImageTargetFormat::Png => write!(f, "png"),
+        }
+    }
+}
+
+/// Formats that may be animated or lossy encoded. Decoding and encoding them again would drop
+/// all frames but the first or grow the file, so they are only processed for a format conversion.
+const PASS_THROUGH_EXTENSIONS: [&str; 2] = ["gif", "webp"];
+
+pub fn should_process_upload(upload_policy: &UploadPolicy, file_name: &str) -> bool {
+    let asset_descriptor = AssetDescriptor::new(file_name.to_string());
+    if !is_raster_image(&asset_descriptor) {
+        return false;
+    }
+    if upload_policy.target_format.is_some() {
+        return true;
+    }
+    let passes_through = asset_descriptor.find_extension().is_some_and(|extension| {
+        PASS_THROUGH_EXTENSIONS.contains(&extension.to_lowercase().as_str())
+    });
+    !passes_through && (upload_policy.max_dimension.is_some() || upload_policy.strip_metadata)
+}
+
+pub fn processed_file_name(upload_policy: &UploadPolicy, file_name: &str) -> String {
+    match &upload_policy.target_format {
+        None => file_name.to_string(),
+        Some(target_format) => {
+            let stem = match file_name.rsplit_once('.') {
+                Some((stem, _)) => stem,
+                None => file_name,
+            };
+            format!("{}.{}", stem, target_format.extension())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_process_upload_without_policy() {
+        assert!(!should_process_upload(
+            &UploadPolicy::default(),
+            "photo.jpg"
+        ));
+    }
+
+    #[test]
+    fn test_should_process_upload_only_raster_images() {
+        let policy = UploadPolicy {
+            strip_metadata: true,
+            ..UploadPolicy::default()
+        };
+
+        assert!(should_process_upload(&policy, "photo.jpg"));
+        assert!(!should_process_upload(&policy, "drawing.svg"));
+        assert!(!should_process_upload(&policy, "notes.txt"));
+    }
+
+    #[test]
+    fn test_should_process_upload_should_pass_gif_and_webp_through() {
+        let policy = UploadPolicy {
+            max_dimension: Some(800),
+            strip_metadata: true,
+            ..UploadPolicy::default()
+        };
+        let converting_policy = UploadPolicy {
+            target_format: Some(ImageTargetFormat::Png),
+            ..policy.clone()
+        };
+
+        assert!(!should_process_upload(&policy, "animation.GIF"));
+        assert!(!should_process_upload(&policy, "photo.webp"));
+        assert!(should_process_upload(&policy, "photo.png"));
+        assert!(should_process_upload(&converting_policy, "animation.gif"));
+        assert!(should_process_upload(&converting_policy, "photo.webp"));
+    }
+
+    #[test]
+    fn test_processed_file_name_should_replace_extension() {
+        let policy = UploadPolicy {
+            target_format: Some(ImageTargetFormat::Jpeg),
+            ..UploadPolicy::default()
+        };
+
+        assert_eq!(processed_file_name(&policy, "my.photo.png"), "my.photo.jpg");
+        assert_eq!(processed_file_name(&policy, "photo"), "photo.jpg");
+    }
+
+    #[test]
+    fn test_processed_file_name_should_keep_name_without_target_format() {
+        assert_eq!(
+            processed_file_name(&UploadPolicy::default(), "photo.png"),
+            "photo.png"
+        );
+    }
+
+    #[test]
+    fn test_image_target_format_from_str() {
+        assert_eq!(
+            ImageTargetFormat::from_str("JPG").unwrap(),
+            ImageTargetFormat::Jpeg
+        );
+        assert!(ImageTargetFormat::from_str("webp").is_err());
+        assert!(ImageTargetFormat::from_str("tiff").is_err());
+    }
+}
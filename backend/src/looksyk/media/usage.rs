@@ -0,0 +1,215 @@
+use crate::io::http::media::config::REL_MEDIA_LOCATION;
+use crate::looksyk::index::media::{IndexedMedia, MediaIndex};
+use crate::looksyk::model::{BlockTokenType, PageId, ParsedMarkdownFile, SimplePageName};
+use crate::looksyk::queries::insert_file_content::get_target_file;
+use crate::looksyk::query::{parse_query, QueryType};
+use crate::state::journal::JournalPageIndex;
+use crate::state::userpage::UserPageIndex;
+use std::collections::{HashMap, HashSet};
+
+pub struct ReferencedMedia {
+    pub file_names: HashSet<String>,
+}
+
+/// Media referenced from any page. Templates are user pages and therefore included.
+pub fn find_referenced_media(
+    user_pages: &UserPageIndex,
+    journal_pages: &JournalPageIndex,
+) -> ReferencedMedia {
+    let mut file_names = HashSet::new();
+    collect_referenced_media(user_pages.iter_entries(), &mut file_names);
+    collect_referenced_media(journal_pages.iter_entries(), &mut file_names);
+    ReferencedMedia { file_names }
+}
+
+pub fn find_unused_media(
+    media_index: &MediaIndex,
+    referenced_media: &ReferencedMedia,
+) -> Vec<IndexedMedia> {
+    media_index
+        .media
+        .iter()
+        .filter(|media| !referenced_media.file_names.contains(&media.file_name))
+        .cloned()
+        .collect()
+}
+
+pub fn find_pages_referencing_media(
+    user_pages: &UserPageIndex,
+    journal_pages: &JournalPageIndex,
+) -> HashMap<String, Vec<PageId>> {
+    let mut result: HashMap<String, Vec<PageId>> = HashMap::new();
+    let all_pages = user_pages
+        .iter_entries()
+        .map(|(name, page)| (name.as_user_page(), page))
+        .chain(
+            journal_pages
+                .iter_entries()
+                .map(|(name, page)| (name.as_journal_page(), page)),
+        );
+    for (page_id, page) in all_pages {
+        for file_name in referenced_media_in_page(page) {
+            result.entry(file_name).or_default().push(page_id.clone());
+        }
+    }
+    result
+}
+
+fn collect_referenced_media<'a, I>(pages: I, file_names: &mut HashSet<String>)
+where
+    I: IntoIterator<Item = (&'a SimplePageName, &'a ParsedMarkdownFile)>,
+{
+    for (_, page) in pages {
+        file_names.extend(referenced_media_in_page(page));
+    }
+}
+
+fn referenced_media_in_page(page: &ParsedMarkdownFile) -> HashSet<String> {
+    let mut file_names = HashSet::new();
+    for block in &page.blocks {
+        for content in &block.content {
+            for token in &content.as_tokens {
+                match token.block_token_type {
+                    BlockTokenType::Query => {
+                        if let Some(file_name) = find_query_target_file(&token.payload) {
+                            file_names.insert(file_name);
+                        }
+                    }
+                    _ => file_names.extend(find_media_links(&token.payload)),
+                }
+            }
+        }
+    }
+    file_names
+}
+
+fn find_query_target_file(query_payload: &str) -> Option<String> {
+    let query = parse_query(query_payload).ok()?;
+    if query.query_type != QueryType::InsertFileContent {
+        return None;
+    }
+    get_target_file(&query).cloned()
+}
+
+pub fn find_media_links(text: &str) -> Vec<String> {
+    let prefix = format!("/{REL_MEDIA_LOCATION}/");
+    let mut result = vec![];
+    for (index, _) in text.match_indices(&prefix) {
+        let link_start = index + prefix.len();
+        let link: String = text[link_start..]
+            .chars()
+            .take_while(|c| !matches!(c, ')' | '"' | '\'' | ' ' | '\n' | '\t'))
+            .collect();
+        if link.is_empty() {
+            continue;
+        }
+        result.push(decode_media_link(&link));
+    }
+    result
+}
+
+fn decode_media_link(link: &str) -> String {
+    urlencoding::decode(link)
+        .map(|decoded| decoded.into_owned())
+        .unwrap_or_else(|_| link.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::test_builder::{empty_journal_index, parsed_block_with};
+    use crate::looksyk::builder::{page_name_str, text_token_str};
+    use crate::looksyk::index::media::builder::indexed_media;
+    use crate::looksyk::model::BlockToken;
+    use crate::state::userpage::builder::user_page_index;
+
+    #[test]
+    fn test_find_media_links_should_find_image_and_link() {
+        let result =
+            find_media_links("see ![a b](/assets/a%20b.png) and [doc](/assets/doc.pdf) here");
+
+        assert_eq!(result, vec!["a b.png".to_string(), "doc.pdf".to_string()]);
+    }
+
+    #[test]
+    fn test_find_media_links_should_find_html_sources() {
+        let result = find_media_links("<source src=\"/assets/movie.mp4\" type=\"video/mp4\">");
+
+        assert_eq!(result, vec!["movie.mp4".to_string()]);
+    }
+
+    #[test]
+    fn test_find_referenced_media_should_include_insert_file_content_queries() {
+        let user_pages = user_page_index(
+            "page",
+            ParsedMarkdownFile::from_blocks(vec![parsed_block_with(
+                vec![
+                    text_token_str("![image](/assets/image.png)"),
+                    BlockToken {
+                        block_token_type: BlockTokenType::Query,
+                        payload:
+                            "insert-file-content target-file:\"code.rs\" display:\"code-block\""
+                                .to_string(),
+                    },
+                ],
+                vec![],
+            )]),
+        );
+
+        let result = find_referenced_media(&user_pages, &empty_journal_index());
+
+        assert!(result.file_names.contains("image.png"));
+        assert!(result.file_names.contains("code.rs"));
+    }
+
+    #[test]
+    fn test_find_referenced_media_should_include_templates() {
+        let user_pages = user_page_index(
+            "Template / Meeting",
+            ParsedMarkdownFile::from_blocks(vec![parsed_block_with(
+                vec![text_token_str("![logo](/assets/logo.png)")],
+                vec![],
+            )]),
+        );
+
+        let result = find_referenced_media(&user_pages, &empty_journal_index());
+
+        assert!(result.file_names.contains("logo.png"));
+    }
+
+    #[test]
+    fn test_find_unused_media() {
+        let media_index = MediaIndex {
+            media: vec![
+                indexed_media("used.png", ""),
+                indexed_media("unused.png", ""),
+            ],
+        };
+        let referenced_media = ReferencedMedia {
+            file_names: HashSet::from(["used.png".to_string()]),
+        };
+
+        let result = find_unused_media(&media_index, &referenced_media);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].file_name, "unused.png");
+    }
+
+    #[test]
+    fn test_find_pages_referencing_media() {
+        let user_pages = user_page_index(
+            "page",
+            ParsedMarkdownFile::from_blocks(vec![parsed_block_with(
+                vec![text_token_str("[doc](/assets/doc.pdf)")],
+                vec![],
+            )]),
+        );
+
+        let result = find_pages_referencing_media(&user_pages, &empty_journal_index());
+
+        assert_eq!(
+            result.get("doc.pdf"),
+            Some(&vec![page_name_str("page").as_user_page()])
+        );
+    }
+}
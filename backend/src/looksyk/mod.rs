@@ -1,14 +1,22 @@
+pub mod aggregate;
 pub mod builder;
 pub mod builtinpage;
+pub mod calendar;
 pub mod data;
 pub mod datatypes;
+pub mod encryption;
 pub mod favourite;
+pub mod habit;
+pub mod health;
 pub mod index;
 pub mod kanban;
+pub mod link_graph;
 pub mod media;
 pub mod model;
 pub mod parser;
 pub mod plot;
+pub mod property_schema;
+pub mod property_statistics;
 mod queries;
 mod query;
 pub mod reader;
@@ -17,3 +25,4 @@ pub mod search;
 pub mod serializer;
 pub mod syntax;
 pub mod templates;
+pub mod unlinked_references;
@@ -1,4 +1,5 @@
 use crate::looksyk::parser::BlockProperties;
+use crate::looksyk::serializer::serialize_tokens;
 use crate::looksyk::syntax::looksyk_markdown::serialize_property;
 use crate::state::block::BlockReference;
 use crate::state::block_properties::{BlockPropertyKey, BlockPropertyValue};
@@ -30,6 +31,17 @@ impl ParsedBlock {
         })
     }
 
+    pub fn contains_only_properties(&self) -> bool {
+        !self.properties.properties.is_empty()
+            && self.content.iter().all(|block_content| {
+                block_content.as_tokens.iter().all(|block_token| {
+                    block_token.block_token_type == BlockTokenType::Property
+                        || (block_token.block_token_type == BlockTokenType::Text
+                            && block_token.payload.trim().is_empty())
+                })
+            })
+    }
+
     pub fn artificial_text_block(text: &str) -> Self {
         ParsedBlock {
             indentation: 0,
@@ -49,21 +61,28 @@ impl ParsedBlock {
         property_key: &BlockPropertyKey,
         old_value: &BlockPropertyValue,
         new_value: &BlockPropertyValue,
+    ) -> ParsedBlock {
+        self.replace_property(property_key, old_value, property_key, new_value)
+    }
+
+    pub fn replace_property(
+        &self,
+        old_key: &BlockPropertyKey,
+        old_value: &BlockPropertyValue,
+        new_key: &BlockPropertyKey,
+        new_value: &BlockPropertyValue,
     ) -> ParsedBlock {
         ParsedBlock {
             indentation: self.indentation,
             properties: self
                 .properties
-                .copy_and_rename(property_key, old_value, new_value),
+                .copy_and_rename(old_key, old_value, new_key, new_value),
             content: self
                 .content
                 .iter()
                 .map(|x| {
                     ParsedBlock::rename_property_in_block_content(
-                        x,
-                        property_key,
-                        old_value,
-                        new_value,
+                        x, old_key, old_value, new_key, new_value,
                     )
                 })
                 .collect(),
@@ -72,35 +91,36 @@ impl ParsedBlock {
 
     fn rename_property_in_block_content(
         content: &BlockContent,
-        property_key: &BlockPropertyKey,
+        old_key: &BlockPropertyKey,
         old_value: &BlockPropertyValue,
+        new_key: &BlockPropertyKey,
         new_value: &BlockPropertyValue,
     ) -> BlockContent {
-        let mut token = vec![];
-        let mut text = content.as_text.clone();
-
-        let property_as_text = serialize_property(property_key, old_value);
-
-        for t in content.as_tokens.iter() {
-            if t.block_token_type == BlockTokenType::Property {
-                if t.payload == property_as_text {
-                    let new_property_as_text = serialize_property(property_key, new_value);
-                    text = text.replace(&property_as_text, &new_property_as_text);
-                    token.push(BlockToken {
-                        payload: new_property_as_text,
+        let property_as_text = serialize_property(old_key, old_value);
+        if !content.as_tokens.iter().any(|t| {
+            t.block_token_type == BlockTokenType::Property && t.payload == property_as_text
+        }) {
+            return content.clone();
+        }
+
+        let new_property_as_text = serialize_property(new_key, new_value);
+        let tokens: Vec<BlockToken> = content
+            .as_tokens
+            .iter()
+            .map(|t| {
+                if t.block_token_type == BlockTokenType::Property && t.payload == property_as_text {
+                    return BlockToken {
+                        payload: new_property_as_text.clone(),
                         block_token_type: BlockTokenType::Property,
-                    });
-                } else {
-                    token.push(t.clone());
+                    };
                 }
-            } else {
-                token.push(t.clone());
-            }
-        }
+                t.clone()
+            })
+            .collect();
 
         BlockContent {
-            as_tokens: token,
-            as_text: text,
+            as_text: serialize_tokens(&tokens),
+            as_tokens: tokens,
         }
     }
 
@@ -250,6 +270,7 @@ pub enum BlockTokenType {
     JournalLink,
     Query,
     Todo,
+    BlockReference,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -292,11 +313,38 @@ pub struct RawMarkdownFile {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ParsedMarkdownFile {
     pub blocks: Vec<ParsedBlock>,
+    pub page_properties: BlockProperties,
 }
 
 impl ParsedMarkdownFile {
     pub fn empty() -> Self {
-        ParsedMarkdownFile { blocks: vec![] }
+        ParsedMarkdownFile {
+            blocks: vec![],
+            page_properties: BlockProperties::empty(),
+        }
+    }
+
+    /// Page properties follow the first-block convention: if the first block of a page
+    /// consists only of `key:: value` lines, these properties describe the page itself.
+    pub fn from_blocks(blocks: Vec<ParsedBlock>) -> Self {
+        let page_properties = match blocks.first() {
+            Some(first_block) if first_block.contains_only_properties() => {
+                first_block.properties.clone()
+            }
+            _ => BlockProperties::empty(),
+        };
+        ParsedMarkdownFile {
+            blocks,
+            page_properties,
+        }
+    }
+
+    pub fn page_property(&self, key: &str) -> Option<&str> {
+        self.page_properties
+            .properties
+            .iter()
+            .find(|property| property.key == key)
+            .map(|property| property.value.as_str())
     }
 
     pub fn block(&self, block_number: usize) -> Option<&ParsedBlock> {
@@ -329,6 +377,7 @@ pub struct QueryRenderResult {
 pub struct ReferencedMarkdown {
     pub content: ParsedBlock,
     pub reference: BlockReference,
+    pub render_deep: bool,
 }
 
 #[derive(Clone)]
@@ -431,8 +480,10 @@ mod tests {
     use crate::looksyk::builder::page_name_str;
     use crate::looksyk::builder::test_builder::user_page_id;
     use crate::looksyk::model::builder::{block_with_link_content, block_with_text_content};
-    use crate::looksyk::model::{BlockContent, PageId, PageType, ParsedBlock, ParsedMarkdownFile};
-    use crate::looksyk::parser::parse_text_content;
+    use crate::looksyk::model::{
+        BlockContent, PageId, PageType, ParsedBlock, ParsedMarkdownFile, RawBlock,
+    };
+    use crate::looksyk::parser::{parse_block, parse_text_content};
     use crate::state::block_properties::builder::{block_property_key, block_property_value};
 
     #[test]
@@ -539,6 +590,7 @@ mod tests {
             &block_content,
             &block_property_key("key"),
             &block_property_value("value"),
+            &block_property_key("key"),
             &block_property_value("value2"),
         );
 
@@ -548,4 +600,34 @@ mod tests {
             &parse_text_content(&expected_text).tokens
         )
     }
+
+    #[test]
+    fn test_from_blocks_should_read_page_properties_from_first_property_block() {
+        let file = ParsedMarkdownFile::from_blocks(vec![
+            parse_block_lines(&["type:: project", "status:: active"]),
+            block_with_text_content("content"),
+        ]);
+
+        assert_eq!(file.page_property("type"), Some("project"));
+        assert_eq!(file.page_property("status"), Some("active"));
+        assert_eq!(file.page_property("owner"), None);
+    }
+
+    #[test]
+    fn test_from_blocks_should_ignore_first_block_with_other_content() {
+        let file = ParsedMarkdownFile::from_blocks(vec![parse_block_lines(&[
+            "type:: project",
+            "some text",
+        ])]);
+
+        assert!(file.page_properties.properties.is_empty());
+        assert_eq!(file.blocks[0].properties.properties.len(), 1);
+    }
+
+    fn parse_block_lines(lines: &[&str]) -> ParsedBlock {
+        parse_block(&RawBlock {
+            indentation: 0,
+            text_content: lines.iter().map(|line| line.to_string()).collect(),
+        })
+    }
 }
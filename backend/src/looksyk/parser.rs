@@ -1,3 +1,4 @@
+use crate::looksyk::index::block_id::{is_block_id, BLOCK_ID_LENGTH};
 use crate::looksyk::model::BlockTokenType::Text;
 use crate::looksyk::model::{
     BlockContent, BlockToken, BlockTokenType, ParsedBlock, ParsedMarkdownFile, RawBlock,
@@ -55,6 +56,9 @@ fn feed_pattern(c: char, pattern: &str, state: &MatcherState) -> usize {
 const LINK_START: &str = "[[";
 const LINK_END: &str = "]]";
 
+const BLOCK_REFERENCE_START: &str = "((";
+const BLOCK_REFERENCE_END: &str = "))";
+
 const QUERY_START: &str = "{query: ";
 const QUERY_END: &str = " }";
 
@@ -63,6 +67,33 @@ const PROPERTY_END: &str = " ";
 
 const WORD_BREAKING_CHAR: char = ' ';
 
+const CODE_SPAN_MARKER: char = '`';
+const ESCAPE_CHAR: char = '\\';
+const CODE_FENCES: [&str; 2] = ["```", "~~~"];
+
+pub fn is_code_fence(line: &str) -> bool {
+    let line = line.trim_start();
+    CODE_FENCES.iter().any(|fence| line.starts_with(fence))
+}
+
+/// A closing fence consists of fence characters only, it must not carry an info string.
+pub fn is_closing_code_fence(line: &str) -> bool {
+    let line = line.trim();
+    CODE_FENCES.iter().any(|fence| {
+        let marker = fence.chars().next().unwrap();
+        line.starts_with(fence) && line.chars().all(|c| c == marker)
+    })
+}
+
+/// Whether the lines after the given line are inside a code fence.
+pub fn is_in_code_fence_after(in_code_fence: bool, line: &str) -> bool {
+    if in_code_fence {
+        !is_closing_code_fence(line)
+    } else {
+        is_code_fence(line)
+    }
+}
+
 pub fn parse_markdown_file(file: RawMarkdownFile) -> ParsedMarkdownFile {
     let mut parsed_blocks = vec![];
 
@@ -70,9 +101,7 @@ pub fn parse_markdown_file(file: RawMarkdownFile) -> ParsedMarkdownFile {
         parsed_blocks.push(parse_block(&block));
     }
 
-    ParsedMarkdownFile {
-        blocks: parsed_blocks,
-    }
+    ParsedMarkdownFile::from_blocks(parsed_blocks)
 }
 
 pub fn parse_markdown_update_file(file: UpdateMarkdownFile) -> ParsedMarkdownFile {
@@ -82,9 +111,7 @@ pub fn parse_markdown_update_file(file: UpdateMarkdownFile) -> ParsedMarkdownFil
         parsed_blocks.push(parse_block(&block));
     }
 
-    ParsedMarkdownFile {
-        blocks: parsed_blocks,
-    }
+    ParsedMarkdownFile::from_blocks(parsed_blocks)
 }
 
 pub fn parse_block(raw_block: &RawBlock) -> ParsedBlock {
@@ -104,8 +131,17 @@ pub struct ParseBlockResult {
 pub fn parse_all_text_lines(all_lines: &Vec<String>) -> ParseBlockResult {
     let mut parsed_content = vec![];
     let mut block_properties = BlockProperties::empty();
+    let mut in_code_fence = false;
 
     for line in all_lines {
+        if in_code_fence || is_code_fence(line) {
+            in_code_fence = is_in_code_fence_after(in_code_fence, line);
+            parsed_content.push(BlockContent {
+                as_text: line.clone(),
+                as_tokens: literal_tokens(line),
+            });
+            continue;
+        }
         let mut result = parse_text_content(line);
         parsed_content.push(BlockContent {
             as_text: line.clone(),
@@ -120,6 +156,63 @@ pub fn parse_all_text_lines(all_lines: &Vec<String>) -> ParseBlockResult {
     }
 }
 
+fn literal_tokens(line: &str) -> Vec<BlockToken> {
+    if line.is_empty() {
+        return vec![];
+    }
+    vec![BlockToken {
+        payload: line.to_string(),
+        block_token_type: Text,
+    }]
+}
+
+fn find_literal_end(text: &str, start: usize, c: char) -> usize {
+    if c == ESCAPE_CHAR {
+        return text[start + c.len_utf8()..]
+            .chars()
+            .next()
+            .map(|escaped| start + c.len_utf8() + escaped.len_utf8())
+            .unwrap_or(text.len());
+    }
+    let marker_length = count_code_span_markers(&text[start..]);
+    let mut search_index = start + marker_length;
+    while let Some(position) = text[search_index..].find(CODE_SPAN_MARKER) {
+        let closing_start = search_index + position;
+        let closing_length = count_code_span_markers(&text[closing_start..]);
+        if closing_length == marker_length {
+            return closing_start + closing_length;
+        }
+        search_index = closing_start + closing_length;
+    }
+    start + marker_length
+}
+
+/// Byte ranges of the closed code spans in a line of text, including their markers.
+pub fn find_code_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut result = vec![];
+    let mut index = 0;
+    while let Some(position) = text[index..].find([CODE_SPAN_MARKER, ESCAPE_CHAR]) {
+        let start = index + position;
+        let c = text[start..].chars().next().unwrap();
+        let end = find_literal_end(text, start, c);
+        if c == CODE_SPAN_MARKER && end > start + count_code_span_markers(&text[start..]) {
+            result.push((start, end));
+        }
+        index = end;
+    }
+    result
+}
+
+/// A block reference is only started for a valid block id directly followed by the end marker.
+fn starts_with_block_reference_payload(text: &str) -> bool {
+    text.get(..BLOCK_ID_LENGTH).is_some_and(is_block_id)
+        && text[BLOCK_ID_LENGTH..].starts_with(BLOCK_REFERENCE_END)
+}
+
+fn count_code_span_markers(text: &str) -> usize {
+    text.chars().take_while(|c| *c == CODE_SPAN_MARKER).count()
+}
+
 struct MatcherState {
     current_index: usize,
     active: bool,
@@ -146,6 +239,7 @@ pub struct BlockProperties {
 
 impl BlockProperties {
     #[cfg(test)]
+    #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
         self.properties.len()
     }
@@ -165,8 +259,9 @@ impl BlockProperties {
 
     pub fn copy_and_rename(
         &self,
-        key: &BlockPropertyKey,
+        old_key: &BlockPropertyKey,
         old_value: &BlockPropertyValue,
+        new_key: &BlockPropertyKey,
         new_value: &BlockPropertyValue,
     ) -> Self {
         BlockProperties {
@@ -174,10 +269,10 @@ impl BlockProperties {
                 .properties
                 .iter()
                 .map(|x| {
-                    if x.key == key.value && x.value == old_value.value {
+                    if x.key == old_key.value && x.value == old_value.value {
                         return BlockProperty {
                             value: new_value.value.clone(),
-                            key: key.value.clone(),
+                            key: new_key.value.clone(),
                         };
                     }
                     x.clone()
@@ -241,6 +336,7 @@ pub fn parse_text_content(text_content: &str) -> ParseTextResult {
     let mut current_matcher: Option<BlockTokenType> = None;
     let mut query_matcher = create_inactive_matcher_state();
     let mut link_matcher = create_inactive_matcher_state();
+    let mut block_reference_matcher = create_inactive_matcher_state();
     let mut property_matcher = create_inactive_matcher_state();
 
     let mut current_index = 0;
@@ -253,9 +349,26 @@ pub fn parse_text_content(text_content: &str) -> ParseTextResult {
     let mut property_key_word: &str = "";
 
     let mut properties: Vec<BlockProperty> = vec![];
+    let mut literal_end_index = 0;
 
     for char in remaining_text_content.chars() {
         current_index += char.len_utf8();
+        if current_index <= literal_end_index {
+            continue;
+        }
+
+        if current_matcher.is_none() && (char == CODE_SPAN_MARKER || char == ESCAPE_CHAR) {
+            literal_end_index = find_literal_end(
+                &remaining_text_content,
+                current_index - char.len_utf8(),
+                char,
+            );
+            link_matcher = create_inactive_matcher_state();
+            block_reference_matcher = create_inactive_matcher_state();
+            query_matcher = create_inactive_matcher_state();
+            property_matcher = create_inactive_matcher_state();
+            continue;
+        }
 
         if current_matcher.is_none() {
             link_matcher = feed_inactive(char, link_matcher, LINK_START);
@@ -271,6 +384,25 @@ pub fn parse_text_content(text_content: &str) -> ParseTextResult {
                 current_matcher = Some(BlockTokenType::Link);
             }
 
+            block_reference_matcher =
+                feed_inactive(char, block_reference_matcher, BLOCK_REFERENCE_START);
+            if block_reference_matcher.active
+                && !starts_with_block_reference_payload(&remaining_text_content[current_index..])
+            {
+                block_reference_matcher = create_inactive_matcher_state();
+            }
+            if block_reference_matcher.active {
+                start_matching_index = current_index - BLOCK_REFERENCE_START.len();
+                if end_matching_index != start_matching_index {
+                    parsed_tokens.push(BlockToken {
+                        payload: remaining_text_content[end_matching_index..start_matching_index]
+                            .to_string(),
+                        block_token_type: Text,
+                    })
+                }
+                current_matcher = Some(BlockTokenType::BlockReference);
+            }
+
             query_matcher = feed_inactive(char, query_matcher, QUERY_START);
             if query_matcher.active {
                 start_matching_index = current_index - QUERY_START.len();
@@ -316,6 +448,25 @@ pub fn parse_text_content(text_content: &str) -> ParseTextResult {
                         current_matcher = None;
                         end_matching_index = current_index;
                         link_matcher = create_inactive_matcher_state();
+                        block_reference_matcher = create_inactive_matcher_state();
+                        query_matcher = create_inactive_matcher_state();
+                        property_matcher = create_inactive_matcher_state();
+                    }
+                }
+                Some(BlockTokenType::BlockReference) => {
+                    block_reference_matcher =
+                        feed_active(char, &block_reference_matcher, BLOCK_REFERENCE_END);
+                    if !block_reference_matcher.active {
+                        let end_index = current_index - BLOCK_REFERENCE_END.len();
+                        let start_index = start_matching_index + BLOCK_REFERENCE_START.len();
+                        parsed_tokens.push(BlockToken {
+                            payload: remaining_text_content[start_index..end_index].to_string(),
+                            block_token_type: BlockTokenType::BlockReference,
+                        });
+                        current_matcher = None;
+                        end_matching_index = current_index;
+                        link_matcher = create_inactive_matcher_state();
+                        block_reference_matcher = create_inactive_matcher_state();
                         query_matcher = create_inactive_matcher_state();
                         property_matcher = create_inactive_matcher_state();
                     }
@@ -332,6 +483,7 @@ pub fn parse_text_content(text_content: &str) -> ParseTextResult {
                         current_matcher = None;
                         end_matching_index = current_index;
                         link_matcher = create_inactive_matcher_state();
+                        block_reference_matcher = create_inactive_matcher_state();
                         query_matcher = create_inactive_matcher_state();
                         property_matcher = create_inactive_matcher_state();
                     }
@@ -355,6 +507,7 @@ pub fn parse_text_content(text_content: &str) -> ParseTextResult {
                         end_matching_index = current_index - PROPERTY_END.len();
                         last_matching_word_start = current_index;
                         link_matcher = create_inactive_matcher_state();
+                        block_reference_matcher = create_inactive_matcher_state();
                         query_matcher = create_inactive_matcher_state();
                         property_matcher = create_inactive_matcher_state();
                     }
@@ -416,7 +569,7 @@ mod tests {
     use crate::looksyk::model::BlockTokenType;
     use crate::looksyk::parser::builder::{any_block_property, block_property};
     use crate::looksyk::parser::{
-        parse_text_content, BlockProperties, BlockProperty, ParseTextResult,
+        parse_all_text_lines, parse_text_content, BlockProperties, BlockProperty, ParseTextResult,
     };
     use crate::state::block_properties::builder::{block_property_key, block_property_value};
 
@@ -490,6 +643,76 @@ mod tests {
         assert_eq!(element.block_token_type, BlockTokenType::Text);
     }
 
+    #[test]
+    fn should_create_text_and_block_reference_node() {
+        let input_text = "see ((00a1b2c3d4e5f607)) here".to_string();
+        let result = parse_text_content(&input_text);
+
+        test_not_properties(&result);
+        assert_eq!(result.tokens.len(), 3);
+        assert_eq!(result.tokens[0].payload, "see ");
+        let element = result.tokens.get(1).unwrap();
+        assert_eq!(element.payload, "00a1b2c3d4e5f607");
+        assert_eq!(element.block_token_type, BlockTokenType::BlockReference);
+        assert_eq!(result.tokens[2].payload, " here");
+    }
+
+    #[test]
+    fn should_keep_parentheses_without_block_id_as_text() {
+        let result = parse_text_content("f((x)) and ((00a1b2c3d4e5f607)) here");
+
+        test_not_properties(&result);
+        assert_eq!(result.tokens.len(), 3);
+        assert_eq!(result.tokens[0].payload, "f((x)) and ");
+        assert_eq!(result.tokens[0].block_token_type, BlockTokenType::Text);
+        assert_eq!(result.tokens[1].payload, "00a1b2c3d4e5f607");
+        assert_eq!(
+            result.tokens[1].block_token_type,
+            BlockTokenType::BlockReference
+        );
+        assert_eq!(result.tokens[2].payload, " here");
+    }
+
+    #[test]
+    fn should_keep_text_without_block_id_as_single_text_node() {
+        let result = parse_text_content("f((x))");
+
+        assert_eq!(result.tokens.len(), 1);
+        assert_eq!(result.tokens[0].payload, "f((x))");
+        assert_eq!(result.tokens[0].block_token_type, BlockTokenType::Text);
+    }
+
+    #[test]
+    fn should_find_links_inside_parentheses_without_block_id() {
+        let result = parse_text_content("((see [[Page]]))");
+
+        assert_eq!(result.tokens.len(), 3);
+        assert_eq!(result.tokens[0].payload, "((see ");
+        assert_eq!(result.tokens[1].payload, "Page");
+        assert_eq!(result.tokens[1].block_token_type, BlockTokenType::Link);
+        assert_eq!(result.tokens[2].payload, "))");
+    }
+
+    #[test]
+    fn should_find_links_after_unclosed_parentheses() {
+        let result = parse_text_content("((x [[Page]] y");
+
+        assert_eq!(result.tokens.len(), 3);
+        assert_eq!(result.tokens[0].payload, "((x ");
+        assert_eq!(result.tokens[1].payload, "Page");
+        assert_eq!(result.tokens[1].block_token_type, BlockTokenType::Link);
+        assert_eq!(result.tokens[2].payload, " y");
+    }
+
+    #[test]
+    fn should_create_text_node_on_unclosed_block_reference() {
+        let result = parse_text_content("davor ((00a1b2c3d4e5f607");
+
+        assert_eq!(result.tokens.len(), 1);
+        assert_eq!(result.tokens[0].payload, "davor ((00a1b2c3d4e5f607");
+        assert_eq!(result.tokens[0].block_token_type, BlockTokenType::Text);
+    }
+
     #[test]
     fn should_parse_query_type() {
         let input_text = "davor {query: querycontent } dahinter".to_string();
@@ -736,6 +959,7 @@ mod tests {
         let result = properties.copy_and_rename(
             &block_property_key("key1"),
             &block_property_value("value2"),
+            &block_property_key("key1"),
             &block_property_value("value4"),
         );
 
@@ -748,4 +972,89 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_parse_text_content_should_keep_code_spans_literal() {
+        let result = parse_text_content("use `[[link]]` and ``a :: b {query: x }`` [[real]]");
+
+        assert_eq!(result.tokens.len(), 3);
+        assert_eq!(result.tokens[0].block_token_type, BlockTokenType::Text);
+        assert_eq!(
+            result.tokens[0].payload,
+            "use `[[link]]` and ``a :: b {query: x }`` "
+        );
+        assert_eq!(result.tokens[1].block_token_type, BlockTokenType::Link);
+        assert_eq!(result.tokens[1].payload, "real");
+        test_not_properties(&result);
+    }
+
+    #[test]
+    fn test_is_closing_code_fence() {
+        assert!(super::is_closing_code_fence("```"));
+        assert!(super::is_closing_code_fence("  ~~~~ "));
+        assert!(!super::is_closing_code_fence("```rust"));
+        assert!(!super::is_closing_code_fence("``"));
+    }
+
+    #[test]
+    fn test_find_code_spans() {
+        assert_eq!(
+            super::find_code_spans("a `b` c ``d ` e`` \\`f` `g"),
+            vec![(2, 5), (8, 17), (21, 24)]
+        );
+        assert_eq!(super::find_code_spans("no code"), vec![]);
+    }
+
+    #[test]
+    fn test_parse_text_content_should_not_find_properties_in_code() {
+        let result = parse_text_content("call `Foo:: new()` here");
+
+        assert_eq!(result.tokens.len(), 1);
+        assert_eq!(result.tokens[0].payload, "call `Foo:: new()` here");
+        test_not_properties(&result);
+    }
+
+    #[test]
+    fn test_parse_text_content_should_treat_unclosed_code_span_markers_as_text() {
+        let result = parse_text_content("a ` b [[link]]");
+
+        assert_eq!(result.tokens.len(), 3);
+        assert_eq!(result.tokens[0].payload, "a ` b ");
+        assert_eq!(result.tokens[1].block_token_type, BlockTokenType::Link);
+    }
+
+    #[test]
+    fn test_parse_text_content_should_respect_escapes() {
+        let result = parse_text_content("not a \\[[link]] but [[this]]");
+
+        assert_eq!(result.tokens.len(), 3);
+        assert_eq!(result.tokens[0].payload, "not a \\[[link]] but ");
+        assert_eq!(result.tokens[1].block_token_type, BlockTokenType::Link);
+        assert_eq!(result.tokens[1].payload, "this");
+    }
+
+    #[test]
+    fn test_parse_all_text_lines_should_keep_fenced_code_literal() {
+        let lines = vec![
+            "example:".to_string(),
+            "```rust".to_string(),
+            "let a = [[b]];".to_string(),
+            "key:: value".to_string(),
+            "```".to_string(),
+            "[[after]]".to_string(),
+        ];
+
+        let result = parse_all_text_lines(&lines);
+
+        for line in &result.content[1..5] {
+            assert_eq!(line.as_tokens.len(), 1);
+            assert_eq!(line.as_tokens[0].block_token_type, BlockTokenType::Text);
+            assert_eq!(line.as_tokens[0].payload, line.as_text);
+        }
+        assert_eq!(
+            result.content[5].as_tokens[0].block_token_type,
+            BlockTokenType::Link
+        );
+        assert_eq!(result.properties.properties.len(), 0);
+    }
 }
@@ -1,116 +1,239 @@
+use crate::io::date::{resolve_query_date, TodayContainer};
+use crate::looksyk::model::PageType;
+use crate::state::block::BlockReference;
 use crate::state::block_properties::{BlockPropertiesIndex, BlockPropertyKey};
-use chrono::NaiveDate;
+use chrono::{Datelike, Days, Months, NaiveDate};
+use std::collections::BTreeMap;
+
+const JOURNAL_DATE_FORMAT: &str = "%Y_%m_%d";
 
 pub struct PlotData {
-    pub label: String,
     pub caption: String,
     pub width: u32,
     pub height: u32,
-    pub data: DataPoints,
+    pub chart_type: ChartType,
+    pub aggregation: PlotAggregation,
+    pub series: Vec<DataSeries>,
 }
 
-pub struct DataPoints {
+pub struct DataSeries {
+    pub label: String,
     pub points: Vec<DataPoint>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct DataPoint {
     pub date: NaiveDate,
-    pub value: i32,
+    pub value: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChartType {
+    Line,
+    Bar,
+    Area,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlotAggregation {
+    Day,
+    Week,
+    Month,
 }
 
 pub struct PlotDataQuery {
-    pub property_key: BlockPropertyKey,
+    pub property_keys: Vec<BlockPropertyKey>,
     pub starting_at: String,
     pub ending_at: String,
+    pub aggregation: PlotAggregation,
+    pub cumulative: bool,
 }
 
 pub struct PlotMetadata {
-    pub label: String,
     pub title: String,
     pub width: u32,
     pub height: u32,
+    pub chart_type: ChartType,
+}
+
+impl PlotData {
+    pub fn has_data_points(&self) -> bool {
+        self.series.iter().any(|series| !series.points.is_empty())
+    }
+}
+
+impl ChartType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "linechart" => Some(ChartType::Line),
+            "barchart" => Some(ChartType::Bar),
+            "areachart" => Some(ChartType::Area),
+            _ => None,
+        }
+    }
+}
+
+impl PlotAggregation {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "day" => Some(PlotAggregation::Day),
+            "week" => Some(PlotAggregation::Week),
+            "month" => Some(PlotAggregation::Month),
+            _ => None,
+        }
+    }
+
+    pub fn bucket_start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            PlotAggregation::Day => date,
+            PlotAggregation::Week => date - Days::new(date.weekday().num_days_from_monday() as u64),
+            PlotAggregation::Month => date.with_day(1).unwrap(),
+        }
+    }
+
+    pub fn bucket_end(&self, date: NaiveDate) -> NaiveDate {
+        let bucket_start = self.bucket_start(date);
+        match self {
+            PlotAggregation::Day => bucket_start + Days::new(1),
+            PlotAggregation::Week => bucket_start + Days::new(7),
+            PlotAggregation::Month => bucket_start + Months::new(1),
+        }
+    }
+}
+
+pub fn parse_property_keys(property_keys: &str) -> Vec<BlockPropertyKey> {
+    property_keys
+        .split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(|key| BlockPropertyKey {
+            value: key.to_string(),
+        })
+        .collect()
 }
 
 pub fn calculate_plot_data(
     properties_data: &BlockPropertiesIndex,
     plot_data_query: PlotDataQuery,
     plot_metadata: PlotMetadata,
+    today: &TodayContainer,
 ) -> PlotData {
-    let mut data_points: Vec<DataPoint> = Vec::new();
+    let starting_at = resolve_query_date(&plot_data_query.starting_at, today);
+    let ending_at = resolve_query_date(&plot_data_query.ending_at, today);
 
-    let property_references = properties_data.find(&plot_data_query.property_key);
-
-    if let Some(references) = property_references {
-        for reference in references {
-            if reference.block.page_id.name.name > plot_data_query.ending_at
-                || reference.block.page_id.name.name < plot_data_query.starting_at
-            {
-                continue;
-            }
-            let try_parse_value = reference.value.value.parse::<i32>();
-            if try_parse_value.is_err() {
-                continue;
-            }
+    let series = plot_data_query
+        .property_keys
+        .iter()
+        .map(|property_key| DataSeries {
+            label: property_key.value.clone(),
+            points: calculate_data_points(
+                properties_data,
+                property_key,
+                starting_at,
+                ending_at,
+                plot_data_query.aggregation,
+                plot_data_query.cumulative,
+            ),
+        })
+        .collect();
 
-            let date = &NaiveDate::parse_from_str(&reference.block.page_id.name.name, "%Y_%m_%d");
+    PlotData {
+        caption: plot_metadata.title,
+        width: plot_metadata.width,
+        height: plot_metadata.height,
+        chart_type: plot_metadata.chart_type,
+        aggregation: plot_data_query.aggregation,
+        series,
+    }
+}
 
-            if date.is_err() {
-                continue;
-            }
+fn calculate_data_points(
+    properties_data: &BlockPropertiesIndex,
+    property_key: &BlockPropertyKey,
+    starting_at: Option<NaiveDate>,
+    ending_at: Option<NaiveDate>,
+    aggregation: PlotAggregation,
+    cumulative: bool,
+) -> Vec<DataPoint> {
+    let mut buckets: BTreeMap<NaiveDate, f64> = BTreeMap::new();
 
-            data_points.push(DataPoint {
-                date: date.unwrap(),
-                value: try_parse_value.unwrap(),
-            })
+    for reference in properties_data.find_block_properties(property_key) {
+        let Some(date) = journal_date_of(&reference.block) else {
+            continue;
+        };
+        if !is_within(date, starting_at, ending_at) {
+            continue;
         }
+        let Some(value) = reference.numeric_value() else {
+            continue;
+        };
+        *buckets.entry(aggregation.bucket_start(date)).or_default() += value;
     }
 
-    data_points.sort_by(|a, b| a.date.cmp(&b.date));
+    let mut total = 0.0;
+    buckets
+        .into_iter()
+        .map(|(date, value)| {
+            total += value;
+            DataPoint {
+                date,
+                value: if cumulative { total } else { value },
+            }
+        })
+        .collect()
+}
 
-    PlotData {
-        label: plot_metadata.label,
-        caption: plot_metadata.title,
-        width: plot_metadata.width,
-        height: plot_metadata.height,
-        data: DataPoints {
-            points: data_points,
-        },
+pub fn journal_date_of(block: &BlockReference) -> Option<NaiveDate> {
+    if block.page_id.page_type != PageType::JournalPage {
+        return None;
     }
+    NaiveDate::parse_from_str(&block.page_id.name.name, JOURNAL_DATE_FORMAT).ok()
+}
+
+pub fn is_within(
+    date: NaiveDate,
+    starting_at: Option<NaiveDate>,
+    ending_at: Option<NaiveDate>,
+) -> bool {
+    starting_at.is_none_or(|starting_at| date >= starting_at)
+        && ending_at.is_none_or(|ending_at| date <= ending_at)
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::io::date::builder::today;
     use crate::looksyk::builder::test_builder::journal_page_id;
-    use crate::looksyk::plot::{calculate_plot_data, PlotDataQuery, PlotMetadata};
+    use crate::looksyk::plot::{
+        calculate_plot_data, parse_property_keys, ChartType, DataPoint, PlotAggregation,
+        PlotDataQuery, PlotMetadata,
+    };
+    use crate::looksyk::property_schema::{PropertyDefinition, PropertySchema, PropertyType};
     use crate::state::block_properties::builder::{block_property_key, block_property_occurance};
-    use crate::state::block_properties::BlockPropertyKey;
+    use crate::state::block_properties::{BlockPropertiesIndex, BlockPropertyKey};
     use chrono::NaiveDate;
 
     #[test]
     fn test_calculate_plot_data_with_empty_tags_should_return_nothing() {
         let result = calculate_plot_data(
             &Default::default(),
-            PlotDataQuery {
-                property_key: BlockPropertyKey {
-                    value: "test".to_string(),
-                },
-                starting_at: "2024-01-01".to_string(),
-                ending_at: "2024-12-31".to_string(),
-            },
+            query(vec![BlockPropertyKey {
+                value: "test".to_string(),
+            }]),
             demo_metadata(),
+            &today(),
         );
 
-        assert!(result.data.points.is_empty());
-        assert_eq!(result.label, "Test");
+        assert!(!result.has_data_points());
+        assert_eq!(result.series[0].label, "test");
         assert_eq!(result.caption, "Test Caption");
         assert_eq!(result.width, 800);
         assert_eq!(result.height, 600);
+        assert_eq!(result.chart_type, ChartType::Line);
     }
 
     #[test]
     fn test_calculate_plot_data_with_no_matching_dates_should_return_nothing() {
-        let mut properties_index = crate::state::block_properties::BlockPropertiesIndex::default();
+        let mut properties_index = BlockPropertiesIndex::default();
         let block_property_key = block_property_key("test");
         properties_index.entries.insert(
             block_property_key.clone(),
@@ -123,19 +246,20 @@ mod tests {
         let result = calculate_plot_data(
             &properties_index,
             PlotDataQuery {
-                property_key: block_property_key,
                 starting_at: "2024-01-01".to_string(),
                 ending_at: "2024-12-31".to_string(),
+                ..query(vec![block_property_key])
             },
             demo_metadata(),
+            &today(),
         );
 
-        assert!(result.data.points.is_empty());
+        assert!(!result.has_data_points());
     }
 
     #[test]
     fn test_calculate_plot_data_should_return_sorted_points() {
-        let mut properties_index = crate::state::block_properties::BlockPropertiesIndex::default();
+        let mut properties_index = BlockPropertiesIndex::default();
         let block_property_key = block_property_key("test");
         properties_index.entries.insert(
             block_property_key.clone(),
@@ -148,35 +272,69 @@ mod tests {
 
         let result = calculate_plot_data(
             &properties_index,
-            PlotDataQuery {
-                property_key: block_property_key,
-                starting_at: "1999-01-01".to_string(),
-                ending_at: "2222-12-31".to_string(),
-            },
+            query(vec![block_property_key]),
             demo_metadata(),
+            &today(),
         );
 
-        assert_eq!(result.data.points.len(), 3);
         assert_eq!(
-            result.data.points[0].date,
-            NaiveDate::from_ymd_opt(2023, 12, 31).unwrap()
+            result.series[0].points,
+            vec![
+                point(2023, 12, 31, 12.0),
+                point(2024, 12, 31, 32.0),
+                point(2025, 12, 31, 22.0),
+            ]
         );
-        assert_eq!(result.data.points[0].value, 12);
-        assert_eq!(
-            result.data.points[1].date,
-            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()
+    }
+
+    #[test]
+    fn test_calculate_plot_data_should_use_typed_values_of_schema() {
+        let mut properties_index = BlockPropertiesIndex::default();
+        properties_index.entries.insert(
+            block_property_key("hours"),
+            vec![
+                block_property_occurance("3", journal_page_id("2024_01_01").block_reference(0)),
+                block_property_occurance("1.5", journal_page_id("2024_01_01").block_reference(1)),
+                block_property_occurance(" 04", journal_page_id("2024_01_02").block_reference(0)),
+            ],
+        );
+        properties_index.entries.insert(
+            block_property_key("due"),
+            vec![block_property_occurance(
+                "20240105",
+                journal_page_id("2024_01_01").block_reference(2),
+            )],
         );
-        assert_eq!(result.data.points[1].value, 32);
+        properties_index.apply_schema(PropertySchema {
+            definitions: vec![
+                PropertyDefinition {
+                    key: "hours".to_string(),
+                    property_type: PropertyType::Number,
+                },
+                PropertyDefinition {
+                    key: "due".to_string(),
+                    property_type: PropertyType::Date,
+                },
+            ],
+        });
+
+        let result = calculate_plot_data(
+            &properties_index,
+            query(vec![block_property_key("hours"), block_property_key("due")]),
+            demo_metadata(),
+            &today(),
+        );
+
         assert_eq!(
-            result.data.points[2].date,
-            NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()
+            result.series[0].points,
+            vec![point(2024, 1, 1, 3.0), point(2024, 1, 2, 4.0)]
         );
-        assert_eq!(result.data.points[2].value, 22);
+        assert!(result.series[1].points.is_empty());
     }
 
     #[test]
-    fn test_calculate_plot_data_should_skip_non_integer_values() {
-        let mut properties_index = crate::state::block_properties::BlockPropertiesIndex::default();
+    fn test_calculate_plot_data_should_skip_non_numeric_values() {
+        let mut properties_index = BlockPropertiesIndex::default();
         let block_property_key = block_property_key("test");
         properties_index.entries.insert(
             block_property_key.clone(),
@@ -190,25 +348,141 @@ mod tests {
             ],
         );
 
+        let result = calculate_plot_data(
+            &properties_index,
+            query(vec![block_property_key]),
+            demo_metadata(),
+            &today(),
+        );
+
+        assert_eq!(result.series[0].points, vec![point(2024, 12, 31, 31.22)]);
+    }
+
+    #[test]
+    fn test_calculate_plot_data_should_return_one_series_per_key() {
+        let mut properties_index = BlockPropertiesIndex::default();
+        properties_index.entries.insert(
+            block_property_key("hours"),
+            vec![block_property_occurance(
+                "8",
+                journal_page_id("2024_01_01").block_reference(0),
+            )],
+        );
+        properties_index.entries.insert(
+            block_property_key("overtime"),
+            vec![block_property_occurance(
+                "1.5",
+                journal_page_id("2024_01_01").block_reference(1),
+            )],
+        );
+
+        let result = calculate_plot_data(
+            &properties_index,
+            query(parse_property_keys("hours, overtime")),
+            demo_metadata(),
+            &today(),
+        );
+
+        assert_eq!(result.series.len(), 2);
+        assert_eq!(result.series[0].label, "hours");
+        assert_eq!(result.series[1].label, "overtime");
+        assert_eq!(result.series[1].points, vec![point(2024, 1, 1, 1.5)]);
+    }
+
+    #[test]
+    fn test_calculate_plot_data_should_aggregate_per_week_cumulative() {
+        let mut properties_index = BlockPropertiesIndex::default();
+        let block_property_key = block_property_key("km");
+        properties_index.entries.insert(
+            block_property_key.clone(),
+            vec![
+                block_property_occurance("5", journal_page_id("2024_01_03").block_reference(0)),
+                block_property_occurance("2.5", journal_page_id("2024_01_07").block_reference(0)),
+                block_property_occurance("10", journal_page_id("2024_01_08").block_reference(0)),
+            ],
+        );
+
         let result = calculate_plot_data(
             &properties_index,
             PlotDataQuery {
-                property_key: block_property_key,
-                starting_at: "1999-01-01".to_string(),
-                ending_at: "2222-12-31".to_string(),
+                aggregation: PlotAggregation::Week,
+                cumulative: true,
+                ..query(vec![block_property_key])
             },
             demo_metadata(),
+            &today(),
         );
 
-        assert!(result.data.points.is_empty());
+        assert_eq!(
+            result.series[0].points,
+            vec![point(2024, 1, 1, 7.5), point(2024, 1, 8, 17.5)]
+        );
+    }
+
+    #[test]
+    fn test_calculate_plot_data_should_resolve_relative_date_range() {
+        let mut properties_index = BlockPropertiesIndex::default();
+        let block_property_key = block_property_key("km");
+        properties_index.entries.insert(
+            block_property_key.clone(),
+            vec![
+                block_property_occurance("5", journal_page_id("2024_05_15").block_reference(0)),
+                block_property_occurance("7", journal_page_id("2024_05_16").block_reference(0)),
+                block_property_occurance("9", journal_page_id("2024_06_16").block_reference(0)),
+            ],
+        );
+
+        let result = calculate_plot_data(
+            &properties_index,
+            PlotDataQuery {
+                starting_at: "-30d".to_string(),
+                ending_at: "today".to_string(),
+                ..query(vec![block_property_key])
+            },
+            demo_metadata(),
+            &today(),
+        );
+
+        assert_eq!(result.series[0].points, vec![point(2024, 5, 16, 7.0)]);
+    }
+
+    #[test]
+    fn test_bucket_end_should_respect_month_length() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 14).unwrap();
+
+        assert_eq!(
+            PlotAggregation::Month.bucket_start(date),
+            NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()
+        );
+        assert_eq!(
+            PlotAggregation::Month.bucket_end(date),
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()
+        );
+    }
+
+    fn query(property_keys: Vec<BlockPropertyKey>) -> PlotDataQuery {
+        PlotDataQuery {
+            property_keys,
+            starting_at: "1999-01-01".to_string(),
+            ending_at: "2222-12-31".to_string(),
+            aggregation: PlotAggregation::Day,
+            cumulative: false,
+        }
+    }
+
+    fn point(year: i32, month: u32, day: u32, value: f64) -> DataPoint {
+        DataPoint {
+            date: NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+            value,
+        }
     }
 
     fn demo_metadata() -> PlotMetadata {
         PlotMetadata {
-            label: "Test".to_string(),
             title: "Test Caption".to_string(),
             width: 800,
             height: 600,
+            chart_type: ChartType::Line,
         }
     }
 }
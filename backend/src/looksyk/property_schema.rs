@@ -0,0 +1,364 @@
+use crate::looksyk::model::SimplePageName;
+use crate::state::block::BlockReference;
+use crate::state::block_properties::{BlockPropertiesIndex, BlockPropertyKey};
+use chrono::NaiveDate;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+const DATE_FORMATS: [&str; 2] = ["%Y-%m-%d", "%Y_%m_%d"];
+const TRUE_VALUES: [&str; 2] = ["true", "yes"];
+const FALSE_VALUES: [&str; 2] = ["false", "no"];
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PropertySchema {
+    pub definitions: Vec<PropertyDefinition>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PropertyDefinition {
+    pub key: String,
+    pub property_type: PropertyType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PropertyType {
+    Number,
+    Decimal,
+    Date,
+    Enum(Vec<String>),
+    PageLink,
+    Boolean,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypedPropertyValue {
+    Text(String),
+    Number(i64),
+    Decimal(f64),
+    Date(NaiveDate),
+    Enum(String),
+    PageLink(SimplePageName),
+    Boolean(bool),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PropertyValueError {
+    pub key: String,
+    pub value: String,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PropertyValidationFinding {
+    pub block: BlockReference,
+    pub error: PropertyValueError,
+}
+
+impl PropertySchema {
+    pub fn find(&self, key: &str) -> Option<&PropertyType> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.key == key)
+            .map(|definition| &definition.property_type)
+    }
+
+    pub fn parse_value(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<TypedPropertyValue, PropertyValueError> {
+        let Some(property_type) = self.find(key) else {
+            return Ok(TypedPropertyValue::Text(value.to_string()));
+        };
+        property_type
+            .parse(value.trim())
+            .ok_or_else(|| PropertyValueError {
+                key: key.to_string(),
+                value: value.to_string(),
+                message: format!(
+                    "Value '{value}' of property '{key}' is not a valid {}",
+                    property_type.describe()
+                ),
+            })
+    }
+}
+
+impl PropertyType {
+    pub fn from_name(name: &str, allowed_values: Vec<String>) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "number" => Ok(PropertyType::Number),
+            "decimal" => Ok(PropertyType::Decimal),
+            "date" => Ok(PropertyType::Date),
+            "enum" => Ok(PropertyType::Enum(allowed_values)),
+            "page-link" => Ok(PropertyType::PageLink),
+            "boolean" => Ok(PropertyType::Boolean),
+            _ => Err(format!("Unknown property type: {name}")),
+        }
+    }
+
+    pub fn allowed_values(&self) -> Vec<String> {
+        match self {
+            PropertyType::Enum(allowed_values) => allowed_values.clone(),
+            _ => vec![],
+        }
+    }
+
+    fn parse(&self, value: &str) -> Option<TypedPropertyValue> {
+        match self {
+            PropertyType::Number => value.parse::<i64>().ok().map(TypedPropertyValue::Number),
+            PropertyType::Decimal => value
+                .parse::<f64>()
+                .ok()
+                .filter(|decimal| decimal.is_finite())
+                .map(TypedPropertyValue::Decimal),
+            PropertyType::Date => DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+                .map(TypedPropertyValue::Date),
+            PropertyType::Enum(allowed_values) => allowed_values
+                .iter()
+                .find(|allowed_value| allowed_value.as_str() == value)
+                .map(|allowed_value| TypedPropertyValue::Enum(allowed_value.clone())),
+            PropertyType::PageLink => {
+                let name = value
+                    .strip_prefix("[[")
+                    .and_then(|value| value.strip_suffix("]]"))
+                    .unwrap_or(value)
+                    .trim();
+                if name.is_empty() {
+                    return None;
+                }
+                Some(TypedPropertyValue::PageLink(SimplePageName {
+                    name: name.to_string(),
+                }))
+            }
+            PropertyType::Boolean => {
+                let value = value.to_lowercase();
+                if TRUE_VALUES.contains(&value.as_str()) {
+                    Some(TypedPropertyValue::Boolean(true))
+                } else if FALSE_VALUES.contains(&value.as_str()) {
+                    Some(TypedPropertyValue::Boolean(false))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            PropertyType::Enum(allowed_values) => {
+                format!("value, allowed are: {}", allowed_values.join(", "))
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl Display for PropertyType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertyType::Number => write!(f, "number"),
+            PropertyType::Decimal => write!(f, "decimal"),
+            PropertyType::Date => write!(f, "date"),
+            PropertyType::Enum(_) => write!(f, "enum"),
+            PropertyType::PageLink => write!(f, "page-link"),
+            PropertyType::Boolean => write!(f, "boolean"),
+        }
+    }
+}
+
+impl TypedPropertyValue {
+    /// Numbers and decimals, untyped text only if it parses as a finite number.
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            TypedPropertyValue::Number(number) => Some(*number as f64),
+            TypedPropertyValue::Decimal(decimal) => Some(*decimal),
+            TypedPropertyValue::Text(text) => text
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite()),
+            _ => None,
+        }
+    }
+
+    pub fn compare(&self, other: &TypedPropertyValue) -> Option<Ordering> {
+        match (self, other) {
+            (TypedPropertyValue::Number(a), TypedPropertyValue::Number(b)) => Some(a.cmp(b)),
+            (TypedPropertyValue::Decimal(a), TypedPropertyValue::Decimal(b)) => a.partial_cmp(b),
+            (TypedPropertyValue::Number(a), TypedPropertyValue::Decimal(b)) => {
+                (*a as f64).partial_cmp(b)
+            }
+            (TypedPropertyValue::Decimal(a), TypedPropertyValue::Number(b)) => {
+                a.partial_cmp(&(*b as f64))
+            }
+            (TypedPropertyValue::Date(a), TypedPropertyValue::Date(b)) => Some(a.cmp(b)),
+            (TypedPropertyValue::Boolean(a), TypedPropertyValue::Boolean(b)) => Some(a.cmp(b)),
+            (TypedPropertyValue::Enum(a), TypedPropertyValue::Enum(b))
+            | (TypedPropertyValue::Text(a), TypedPropertyValue::Text(b)) => Some(a.cmp(b)),
+            (TypedPropertyValue::PageLink(a), TypedPropertyValue::PageLink(b)) => {
+                Some(a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub fn values_equal(schema: &PropertySchema, key: &str, a: &str, b: &str) -> bool {
+    match (schema.parse_value(key, a), schema.parse_value(key, b)) {
+        (Ok(a), Ok(b)) => a.compare(&b) == Some(Ordering::Equal),
+        _ => a == b,
+    }
+}
+
+pub fn validate_property_values(
+    block_properties_index: &BlockPropertiesIndex,
+) -> Vec<PropertyValidationFinding> {
+    let mut result = vec![];
+    for definition in &block_properties_index.schema.definitions {
+        let key = BlockPropertyKey {
+            value: definition.key.clone(),
+        };
+        for occurence in block_properties_index.find(&key).into_iter().flatten() {
+            if let Err(error) = &occurence.typed_value {
+                result.push(PropertyValidationFinding {
+                    block: occurence.block.clone(),
+                    error: error.clone(),
+                });
+            }
+        }
+    }
+    result.sort_by(|a, b| {
+        a.block
+            .page_id
+            .cmp(&b.block.page_id)
+            .then(a.block.block_number.cmp(&b.block.block_number))
+            .then(a.error.key.cmp(&b.error.key))
+    });
+    result
+}
+
+#[cfg(test)]
+pub mod builder {
+    use crate::looksyk::property_schema::{PropertyDefinition, PropertySchema, PropertyType};
+
+    pub fn property_schema_with(key: &str, property_type: PropertyType) -> PropertySchema {
+        PropertySchema {
+            definitions: vec![PropertyDefinition {
+                key: key.to_string(),
+                property_type,
+            }],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::test_builder::journal_page_id;
+    use crate::looksyk::property_schema::builder::property_schema_with;
+    use crate::state::block_properties::builder::{
+        block_properties_index_with, block_property_key, block_property_occurance,
+    };
+
+    #[test]
+    fn test_parse_value_should_type_values() {
+        assert_eq!(
+            property_schema_with("hours", PropertyType::Number).parse_value("hours", " 3 "),
+            Ok(TypedPropertyValue::Number(3))
+        );
+        assert_eq!(
+            property_schema_with("km", PropertyType::Decimal).parse_value("km", "5.5"),
+            Ok(TypedPropertyValue::Decimal(5.5))
+        );
+        assert_eq!(
+            property_schema_with("due", PropertyType::Date).parse_value("due", "2024_02_29"),
+            Ok(TypedPropertyValue::Date(
+                NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+            ))
+        );
+        assert_eq!(
+            property_schema_with("done", PropertyType::Boolean).parse_value("done", "Yes"),
+            Ok(TypedPropertyValue::Boolean(true))
+        );
+        assert_eq!(
+            property_schema_with("owner", PropertyType::PageLink).parse_value("owner", "[[Me]]"),
+            Ok(TypedPropertyValue::PageLink(SimplePageName {
+                name: "Me".to_string()
+            }))
+        );
+        assert_eq!(
+            PropertySchema::default().parse_value("other", "free text"),
+            Ok(TypedPropertyValue::Text("free text".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_value_should_reject_values_not_matching_the_schema() {
+        let schema = property_schema_with(
+            "status",
+            PropertyType::Enum(vec!["todo".to_string(), "doing".to_string()]),
+        );
+
+        assert_eq!(
+            schema.parse_value("status", "wip"),
+            Err(PropertyValueError {
+                key: "status".to_string(),
+                value: "wip".to_string(),
+                message: "Value 'wip' of property 'status' is not a valid value, allowed are: todo, doing"
+                    .to_string(),
+            })
+        );
+        assert!(property_schema_with("hours", PropertyType::Number)
+            .parse_value("hours", "3.5")
+            .is_err());
+        assert!(property_schema_with("due", PropertyType::Date)
+            .parse_value("due", "tomorrow")
+            .is_err());
+    }
+
+    #[test]
+    fn test_values_equal_should_compare_typed_values() {
+        let schema = property_schema_with("km", PropertyType::Decimal);
+
+        assert!(values_equal(&schema, "km", "1.50", "1.5"));
+        assert!(!values_equal(&schema, "km", "1.5", "2"));
+        assert!(values_equal(&schema, "other", "a", "a"));
+        assert!(!values_equal(&schema, "other", "1.50", "1.5"));
+    }
+
+    #[test]
+    fn test_compare_should_order_numbers_and_dates() {
+        assert_eq!(
+            TypedPropertyValue::Number(2).compare(&TypedPropertyValue::Decimal(10.0)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            TypedPropertyValue::Number(1).compare(&TypedPropertyValue::Text("1".to_string())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_validate_property_values_should_list_invalid_values() {
+        let mut index = block_properties_index_with(
+            block_property_key("hours"),
+            vec![
+                block_property_occurance("3", journal_page_id("2024_01_02").block_reference(0)),
+                block_property_occurance("3h", journal_page_id("2024_01_01").block_reference(1)),
+            ],
+        );
+
+        index.apply_schema(property_schema_with("hours", PropertyType::Number));
+
+        let result = validate_property_values(&index);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].block,
+            journal_page_id("2024_01_01").block_reference(1)
+        );
+        assert_eq!(result[0].error.value, "3h");
+    }
+}
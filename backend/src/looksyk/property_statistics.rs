@@ -0,0 +1,113 @@
+use crate::looksyk::builtinpage::journal_overview::parse_journal_date;
+use crate::looksyk::model::{PageId, PageType};
+use crate::state::block_properties::{BlockPropertiesIndex, BlockPropertyKey};
+use chrono::NaiveDate;
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PropertyValueStatistic {
+    pub value: String,
+    pub occurences: usize,
+    pub pages: BTreeSet<PageId>,
+    /// Date of the latest journal page using the value. User pages carry no date, values only
+    /// used on user pages have none.
+    pub last_used: Option<NaiveDate>,
+}
+
+pub fn property_value_statistics(
+    block_properties_index: &BlockPropertiesIndex,
+    key: &BlockPropertyKey,
+    value_prefix: &str,
+) -> Vec<PropertyValueStatistic> {
+    let value_prefix = value_prefix.to_lowercase();
+    let mut statistics: HashMap<&str, PropertyValueStatistic> = HashMap::new();
+
+    for occurence in block_properties_index.find(key).into_iter().flatten() {
+        let value = occurence.value.value.as_str();
+        if !value.to_lowercase().starts_with(&value_prefix) {
+            continue;
+        }
+        let statistic = statistics
+            .entry(value)
+            .or_insert_with(|| PropertyValueStatistic {
+                value: value.to_string(),
+                occurences: 0,
+                pages: BTreeSet::new(),
+                last_used: None,
+            });
+        statistic.occurences += 1;
+        let page_id = &occurence.block.page_id;
+        statistic.pages.insert(page_id.clone());
+        if page_id.page_type == PageType::JournalPage {
+            statistic.last_used = statistic.last_used.max(parse_journal_date(&page_id.name));
+        }
+    }
+
+    let mut result: Vec<PropertyValueStatistic> = statistics.into_values().collect();
+    result.sort_by(|a, b| {
+        b.occurences
+            .cmp(&a.occurences)
+            .then(b.last_used.cmp(&a.last_used))
+            .then(a.value.cmp(&b.value))
+    });
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::test_builder::{journal_page_id, user_page_id};
+    use crate::state::block_properties::builder::{
+        block_properties_index_with, block_property_key, block_property_occurance,
+    };
+
+    fn status_index() -> BlockPropertiesIndex {
+        block_properties_index_with(
+            block_property_key("status"),
+            vec![
+                block_property_occurance("wip", user_page_id("board").block_reference(0)),
+                block_property_occurance("done", journal_page_id("2024_01_01").block_reference(0)),
+                block_property_occurance("done", journal_page_id("2024_03_01").block_reference(2)),
+                block_property_occurance("done", journal_page_id("2024_03_01").block_reference(4)),
+                block_property_occurance("Waiting", user_page_id("board").block_reference(1)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_property_value_statistics_should_count_values_per_page() {
+        let result = property_value_statistics(&status_index(), &block_property_key("status"), "");
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result[0],
+            PropertyValueStatistic {
+                value: "done".to_string(),
+                occurences: 3,
+                pages: BTreeSet::from([
+                    journal_page_id("2024_01_01"),
+                    journal_page_id("2024_03_01")
+                ]),
+                last_used: NaiveDate::from_ymd_opt(2024, 3, 1),
+            }
+        );
+        assert_eq!(result[1].value, "Waiting");
+        assert_eq!(result[1].last_used, None);
+        assert_eq!(result[2].pages, BTreeSet::from([user_page_id("board")]));
+    }
+
+    #[test]
+    fn test_property_value_statistics_should_filter_by_prefix_ignoring_case() {
+        let result = property_value_statistics(&status_index(), &block_property_key("status"), "w");
+
+        let values: Vec<&str> = result.iter().map(|s| s.value.as_str()).collect();
+        assert_eq!(values, vec!["Waiting", "wip"]);
+    }
+
+    #[test]
+    fn test_property_value_statistics_should_handle_unknown_key() {
+        assert!(
+            property_value_statistics(&status_index(), &block_property_key("other"), "").is_empty()
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use crate::io::date::resolve_query_date;
+use crate::looksyk::aggregate::{
+    aggregate_property, format_aggregated_value, AggregationFunction, AggregationGrouping,
+    AggregationQuery, AggregationResult, AggregationSources,
+};
+use crate::looksyk::media::table::escape_cell;
+use crate::looksyk::model::{QueryRenderResult, SimplePageName};
+use crate::looksyk::queries::args::{
+    parse_display_type, ParamBuilder, ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX, PARAM_TAG,
+};
+use crate::looksyk::queries::basic::extra_param_validation::ParamValidator;
+use crate::looksyk::queries::basic::unknown::render_display_unknown;
+use crate::looksyk::query::{Query, QueryDisplayType, QueryType};
+use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
+use crate::state::block_properties::{BlockPropertiesIndex, BlockPropertyKey};
+use crate::state::journal::JournalPageIndex;
+use crate::state::userpage::UserPageIndex;
+use std::io::Error;
+
+pub const QUERY_NAME_AGGREGATE: &str = "aggregate";
+
+pub const PARAM_PROPERTY_KEY: &str = "propertyKey";
+
+pub const PARAM_FUNCTION: &str = "function";
+
+pub const PARAM_GROUP_BY: &str = "groupBy";
+
+pub const PARAM_STARTING_AT: &str = "startingAt";
+
+pub const PARAM_ENDING_AT: &str = "endingAt";
+
+const FUNCTION_NAMES: [&str; 5] = ["sum", "average", "min", "max", "count"];
+
+pub fn parse_query_aggregate(query_str: &str) -> Result<Query, Error> {
+    let query_content = query_str
+        .strip_prefix(QUERY_NAME_AGGREGATE)
+        .ok_or(Error::other(ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX))?
+        .trim();
+
+    let parser_result = ParamBuilder::init(query_content.to_string())
+        .next(PARAM_PROPERTY_KEY)?
+        .next(PARAM_FUNCTION)?
+        .next_optional(&[
+            PARAM_TAG,
+            PARAM_STARTING_AT,
+            PARAM_ENDING_AT,
+            PARAM_GROUP_BY,
+        ])?
+        .build();
+
+    let display_type = parse_display_type(parser_result.remaining_value)?;
+
+    Ok(Query {
+        query_type: QueryType::Aggregate,
+        display: display_type,
+        args: parser_result.parsed_args,
+    })
+}
+
+pub fn render_aggregate_query(
+    query: Query,
+    block_properties_index: &BlockPropertiesIndex,
+    user_page_index: &UserPageIndex,
+    journal_page_index: &JournalPageIndex,
+    journal_title_calculator_metadata: &JournalTitleCalculatorMetadata,
+) -> QueryRenderResult {
+    if !matches!(
+        query.display,
+        QueryDisplayType::InlineText | QueryDisplayType::InplaceList | QueryDisplayType::Table
+    ) {
+        return render_display_unknown(
+            query.display,
+            vec![
+                QueryDisplayType::InlineText,
+                QueryDisplayType::InplaceList,
+                QueryDisplayType::Table,
+            ],
+        );
+    }
+
+    let mut validation = ParamValidator::default().validate_one_of(
+        query.get_arg(PARAM_FUNCTION).unwrap(),
+        PARAM_FUNCTION,
+        &FUNCTION_NAMES,
+    );
+    for param in [PARAM_STARTING_AT, PARAM_ENDING_AT] {
+        if let Some(value) = query.get_arg(param) {
+            validation = validation.validate_as_date(value, param);
+        }
+    }
+    if validation.has_errors() {
+        return QueryRenderResult {
+            inplace_markdown: validation.format_errors_as_markdown(),
+            referenced_markdown: vec![],
+            has_dynamic_content: false,
+        };
+    }
+
+    let aggregation_query = AggregationQuery {
+        property_key: BlockPropertyKey {
+            value: query.get_arg(PARAM_PROPERTY_KEY).unwrap().clone(),
+        },
+        function: AggregationFunction::from_name(query.get_arg(PARAM_FUNCTION).unwrap()).unwrap(),
+        grouping: query
+            .get_arg(PARAM_GROUP_BY)
+            .map(|group_by| AggregationGrouping::from_name(group_by))
+            .unwrap_or(AggregationGrouping::None),
+        tag: query
+            .get_arg(PARAM_TAG)
+            .map(|tag| SimplePageName { name: tag.clone() }),
+        starting_at: query
+            .get_arg(PARAM_STARTING_AT)
+            .and_then(|date| resolve_query_date(date, &journal_title_calculator_metadata.today)),
+        ending_at: query
+            .get_arg(PARAM_ENDING_AT)
+            .and_then(|date| resolve_query_date(date, &journal_title_calculator_metadata.today)),
+    };
+    let result = aggregate_property(
+        &aggregation_query,
+        &AggregationSources {
+            block_properties_index,
+            user_pages: user_page_index,
+            journal_pages: journal_page_index,
+            journal_title_calculator_metadata,
+        },
+    );
+
+    let inplace_markdown = match query.display {
+        QueryDisplayType::InplaceList => render_as_list(&aggregation_query, &result),
+        QueryDisplayType::Table => render_as_table(&aggregation_query, &result),
+        _ => format_aggregated_value(result.total),
+    };
+
+    QueryRenderResult {
+        inplace_markdown,
+        referenced_markdown: vec![],
+        has_dynamic_content: false,
+    }
+}
+
+fn render_as_list(query: &AggregationQuery, result: &AggregationResult) -> String {
+    let mut markdown = format!("{}:\n\n", describe(query));
+    for group in &result.groups {
+        markdown.push_str(&format!(
+            "* {}: {}\n",
+            group.label,
+            format_aggregated_value(group.value)
+        ));
+    }
+    if result.groups.is_empty() {
+        markdown.push_str("* No values found!\n");
+    }
+    markdown
+}
+
+fn render_as_table(query: &AggregationQuery, result: &AggregationResult) -> String {
+    let mut markdown = format!(
+        "| {} | {} |\n| :-- | --: |\n",
+        escape_cell(&query.grouping.to_string()),
+        escape_cell(&describe(query))
+    );
+    for group in &result.groups {
+        markdown.push_str(&format!(
+            "| {} | {} |\n",
+            escape_cell(&group.label),
+            format_aggregated_value(group.value)
+        ));
+    }
+    markdown
+}
+
+fn describe(query: &AggregationQuery) -> String {
+    match query.grouping {
+        AggregationGrouping::None => {
+            format!("{} of {}", query.function, query.property_key.value)
+        }
+        _ => format!(
+            "{} of {} by {}",
+            query.function, query.property_key.value, query.grouping
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::looksyk::builder::page_name_str;
+    use crate::looksyk::index::block_properties::create_block_properties_index;
+    use crate::looksyk::parser::parse_markdown_file;
+    use crate::looksyk::property_schema::PropertySchema;
+    use crate::looksyk::queries::aggregate::{
+        parse_query_aggregate, render_aggregate_query, PARAM_FUNCTION, PARAM_GROUP_BY,
+        PARAM_PROPERTY_KEY, PARAM_STARTING_AT,
+    };
+    use crate::looksyk::queries::args::PARAM_TAG;
+    use crate::looksyk::query::{Query, QueryDisplayType, QueryType};
+    use crate::looksyk::reader::read_file_contents;
+    use crate::looksyk::renderer::title::builder::world_journal_title_calculator_metadata;
+    use crate::state::journal::JournalPageIndex;
+    use crate::state::userpage::builder::empty_user_page_index;
+
+    fn render(query: &str) -> String {
+        let mut journal_pages = JournalPageIndex::default();
+        journal_pages.insert(
+            page_name_str("2024_01_01"),
+            parse_markdown_file(read_file_contents("- run km:: 5\n- run km:: 7.5")),
+        );
+        journal_pages.insert(
+            page_name_str("2024_02_01"),
+            parse_markdown_file(read_file_contents("- run km:: 10")),
+        );
+        let user_pages = empty_user_page_index();
+        render_aggregate_query(
+            parse_query_aggregate(query).unwrap(),
+            &create_block_properties_index(&journal_pages, &user_pages, &PropertySchema::default()),
+            &user_pages,
+            &journal_pages,
+            &world_journal_title_calculator_metadata(),
+        )
+        .inplace_markdown
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let result = parse_query_aggregate(
+            "aggregate propertyKey:\"hours\" function:\"sum\" tag:\"work\" startingAt:\"2024-01-01\" groupBy:\"month\" display:\"table\"",
+        )
+        .unwrap();
+
+        assert_eq!(result.query_type, QueryType::Aggregate);
+        assert_eq!(result.display, QueryDisplayType::Table);
+        assert_eq!(result.get_arg(PARAM_PROPERTY_KEY).unwrap(), "hours");
+        assert_eq!(result.get_arg(PARAM_FUNCTION).unwrap(), "sum");
+        assert_eq!(result.get_arg(PARAM_TAG).unwrap(), "work");
+        assert_eq!(result.get_arg(PARAM_STARTING_AT).unwrap(), "2024-01-01");
+        assert_eq!(result.get_arg(PARAM_GROUP_BY).unwrap(), "month");
+    }
+
+    #[test]
+    fn test_render_as_value() {
+        assert_eq!(
+            render("aggregate propertyKey:\"km\" function:\"sum\" display:\"inline-text\""),
+            "22.5"
+        );
+        assert_eq!(
+            render("aggregate propertyKey:\"km\" function:\"average\" endingAt:\"2024-01-31\" display:\"inline-text\""),
+            "6.25"
+        );
+    }
+
+    #[test]
+    fn test_render_as_list() {
+        assert_eq!(
+            render("aggregate propertyKey:\"km\" function:\"max\" groupBy:\"month\" display:\"inplace-list\""),
+            "max of km by month:\n\n* 2024-01: 7.5\n* 2024-02: 10\n"
+        );
+    }
+
+    #[test]
+    fn test_render_as_table() {
+        assert_eq!(
+            render(
+                "aggregate propertyKey:\"km\" function:\"count\" groupBy:\"day\" display:\"table\""
+            ),
+            "| day | count of km by day |\n| :-- | --: |\n| 2024-01-01 | 2 |\n| 2024-02-01 | 1 |\n"
+        );
+    }
+
+    #[test]
+    fn test_render_with_invalid_params() {
+        assert_eq!(
+            render("aggregate propertyKey:\"km\" function:\"median\" startingAt:\"yesterday\" display:\"inline-text\""),
+            "**Parameter Validation Errors:**\n- Parameter 'function' with value 'median' is not one of: sum, average, min, max, count.\n- Parameter 'startingAt' with value 'yesterday' is not a valid date (expected format: YYYY-MM-DD, today, -30d, +2w, -6m, -1y or start-of-week/month/year).\n"
+        );
+    }
+
+    #[test]
+    fn test_render_with_unsupported_display() {
+        let result = render_aggregate_query(
+            Query {
+                query_type: QueryType::Aggregate,
+                display: QueryDisplayType::Cards,
+                args: Default::default(),
+            },
+            &Default::default(),
+            &empty_user_page_index(),
+            &JournalPageIndex::default(),
+            &world_journal_title_calculator_metadata(),
+        );
+        assert!(result.inplace_markdown.contains("not supported"));
+    }
+}
@@ -15,6 +15,10 @@ pub const PARAM_DISPLAY_AUDIO: &str = "audio";
 pub const PARAM_DISPLAY_LINK: &str = "link";
 pub const PARAM_DISPLAY_CARDS: &str = "cards";
 pub const PARAM_DISPLAY_LINECHART: &str = "linechart";
+pub const PARAM_DISPLAY_BARCHART: &str = "barchart";
+pub const PARAM_DISPLAY_AREACHART: &str = "areachart";
+pub const PARAM_DISPLAY_TABLE: &str = "table";
+pub const PARAM_DISPLAY_HEATMAP: &str = "heatmap";
 
 pub const PARAM_TARGET_FILE: &str = "target-file";
 pub const PARAM_TARGET: &str = "target";
@@ -65,6 +69,10 @@ pub fn parse_display_type(input_string: String) -> Result<QueryDisplayType, Erro
         PARAM_DISPLAY_LINK => Ok(QueryDisplayType::Link),
         PARAM_DISPLAY_CARDS => Ok(QueryDisplayType::Cards),
         PARAM_DISPLAY_LINECHART => Ok(QueryDisplayType::Linechart),
+        PARAM_DISPLAY_BARCHART => Ok(QueryDisplayType::Barchart),
+        PARAM_DISPLAY_AREACHART => Ok(QueryDisplayType::Areachart),
+        PARAM_DISPLAY_TABLE => Ok(QueryDisplayType::Table),
+        PARAM_DISPLAY_HEATMAP => Ok(QueryDisplayType::Heatmap),
         _ => Ok(QueryDisplayType::Unknown),
     }
 }
@@ -91,6 +99,16 @@ impl ParamBuilder {
         })
     }
 
+    pub fn next_optional(mut self, keys: &[&str]) -> Result<ParamBuilder, Error> {
+        while let Some(key) = keys
+            .iter()
+            .find(|key| self.current_value.starts_with(&format!("{key}:\"")))
+        {
+            self = self.next(key)?;
+        }
+        Ok(self)
+    }
+
     pub fn build(self) -> ParamBuilderResult {
         ParamBuilderResult {
             parsed_args: self.parsed_args,
@@ -1,3 +1,5 @@
+use crate::io::date::QueryDate;
+
 #[derive(Default)]
 pub struct ParamValidator {
     errors: Vec<String>,
@@ -18,16 +20,36 @@ impl ParamValidator {
     }
 
     pub fn validate_as_date(mut self, param_value: &str, name: &str) -> Self {
-        match chrono::NaiveDate::parse_from_str(param_value, "%Y-%m-%d") {
-            Ok(_) => self,
-            Err(_) => {
-                self.errors.push(format!(
-                    "Parameter '{}' with value '{}' is not a valid date (expected format: YYYY-MM-DD).",
-                    name, param_value
-                ));
-                self
-            }
+        if QueryDate::parse(param_value).is_none() {
+            self.errors.push(format!(
+                "Parameter '{}' with value '{}' is not a valid date (expected format: YYYY-MM-DD, today, -30d, +2w, -6m, -1y or start-of-week/month/year).",
+                name, param_value
+            ));
         }
+        self
+    }
+
+    pub fn validate_one_of(mut self, param_value: &str, name: &str, allowed: &[&str]) -> Self {
+        if !allowed.contains(&param_value) {
+            self.errors.push(format!(
+                "Parameter '{}' with value '{}' is not one of: {}.",
+                name,
+                param_value,
+                allowed.join(", ")
+            ));
+        }
+        self
+    }
+
+    pub fn validate_exactly_one_given(mut self, params: &[(&str, Option<&String>)]) -> Self {
+        if params.iter().filter(|(_, value)| value.is_some()).count() != 1 {
+            let names: Vec<String> = params.iter().map(|(name, _)| format!("'{name}'")).collect();
+            self.errors.push(format!(
+                "Exactly one of the parameters {} is required.",
+                names.join(", ")
+            ));
+        }
+        self
     }
 
     pub fn has_errors(&self) -> bool {
@@ -57,7 +79,7 @@ mod tests {
         let validator =
             super::ParamValidator::default().validate_as_date("15-06-2024", "startDate");
         assert!(!validator.errors.is_empty());
-        assert_eq!(validator.errors[0], "Parameter 'startDate' with value '15-06-2024' is not a valid date (expected format: YYYY-MM-DD).");
+        assert_eq!(validator.errors[0], "Parameter 'startDate' with value '15-06-2024' is not a valid date (expected format: YYYY-MM-DD, today, -30d, +2w, -6m, -1y or start-of-week/month/year).");
     }
 
     #[test]
@@ -76,6 +98,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_param_validator_with_relative_query_date_should_pass() {
+        let validator = super::ParamValidator::default()
+            .validate_as_date("-30d", "startingAt")
+            .validate_as_date("start-of-year", "startingAt")
+            .validate_as_date("2024-06-15", "endingAt");
+        assert!(validator.errors.is_empty());
+    }
+
+    #[test]
+    fn test_param_validator_with_value_not_allowed_should_fail() {
+        let validator = super::ParamValidator::default()
+            .validate_one_of("sum", "function", &["sum", "max"])
+            .validate_one_of("median", "function", &["sum", "max"]);
+        assert_eq!(
+            validator.errors,
+            vec!["Parameter 'function' with value 'median' is not one of: sum, max."]
+        );
+    }
+
+    #[test]
+    fn test_param_validator_with_not_exactly_one_param_given_should_fail() {
+        let value = "Workout".to_string();
+        let validator = super::ParamValidator::default()
+            .validate_exactly_one_given(&[("tag", Some(&value)), ("propertyKey", None)])
+            .validate_exactly_one_given(&[("tag", None), ("propertyKey", None)])
+            .validate_exactly_one_given(&[("tag", Some(&value)), ("propertyKey", Some(&value))]);
+        assert_eq!(
+            validator.errors,
+            vec![
+                "Exactly one of the parameters 'tag', 'propertyKey' is required.",
+                "Exactly one of the parameters 'tag', 'propertyKey' is required."
+            ]
+        );
+    }
+
     #[test]
     fn test_param_validator_format_errors_as_markdown() {
         let validator = super::ParamValidator::default()
@@ -83,7 +141,7 @@ mod tests {
             .validate_as_date("invalid_date", "param2");
 
         let markdown = validator.format_errors_as_markdown();
-        let expected = "**Parameter Validation Errors:**\n- Parameter 'param1' with value 'not_an_integer' is not a valid integer.\n- Parameter 'param2' with value 'invalid_date' is not a valid date (expected format: YYYY-MM-DD).\n";
+        let expected = "**Parameter Validation Errors:**\n- Parameter 'param1' with value 'not_an_integer' is not a valid integer.\n- Parameter 'param2' with value 'invalid_date' is not a valid date (expected format: YYYY-MM-DD, today, -30d, +2w, -6m, -1y or start-of-week/month/year).\n";
 
         assert_eq!(markdown, expected);
     }
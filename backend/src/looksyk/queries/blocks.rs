@@ -1,22 +1,24 @@
+use crate::looksyk::index::alias::{all_names_of, find_references_including_aliases};
 use crate::looksyk::model::{
     PageId, PageType, ParsedBlock, ParsedMarkdownFile, QueryRenderResult, ReferencedMarkdown,
     SimplePageName,
 };
 use crate::looksyk::queries::args::{
-    parse_display_type, parse_property, ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX, PARAM_TAG,
+    parse_display_type, ParamBuilder, ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX, PARAM_TAG,
     PARAM_TARGET,
 };
 use crate::looksyk::queries::basic::count::render_as_count;
 use crate::looksyk::queries::basic::unknown::render_display_unknown;
+use crate::looksyk::queries::pages::{PARAM_PROPERTY_KEY, PARAM_PROPERTY_VALUE};
 use crate::looksyk::query::{Query, QueryDisplayType, QueryType};
 use crate::looksyk::renderer::atomics::{render_block_link, render_user_link};
 use crate::looksyk::renderer::renderer_flat::render_block_flat;
 use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
 use crate::state::block::BlockReference;
+use crate::state::block_properties::{BlockPropertiesIndex, BlockPropertyKey, BlockPropertyValue};
 use crate::state::journal::JournalPageIndex;
 use crate::state::tag::TagIndex;
 use crate::state::userpage::UserPageIndex;
-use std::collections::{HashMap, HashSet};
 use std::io::Error;
 
 pub const QUERY_NAME_BLOCKS: &str = "blocks";
@@ -26,13 +28,17 @@ pub fn parse_query_blocks(query_str: &str) -> Result<Query, Error> {
         .strip_prefix(QUERY_NAME_BLOCKS)
         .ok_or(Error::other(ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX))?
         .trim();
-    let query_tag = parse_property(query_content, PARAM_TAG)?;
+    let parser_result = ParamBuilder::init(query_content.to_string())
+        .next(PARAM_TAG)?
+        .next_optional(&[PARAM_PROPERTY_KEY, PARAM_PROPERTY_VALUE])?
+        .build();
 
-    let display_type = parse_display_type(query_tag.remaining_text.clone())?;
+    let display_type = parse_display_type(parser_result.remaining_value)?;
 
-    let mut args1 = HashMap::new();
-    args1.insert(PARAM_TARGET.to_string(), query_tag.value);
-    let (display_type, args) = (display_type, args1);
+    let mut args = parser_result.parsed_args;
+    if let Some(tag) = args.remove(PARAM_TAG) {
+        args.insert(PARAM_TARGET.to_string(), tag);
+    }
     Ok(Query {
         query_type: QueryType::Blocks,
         display: display_type,
@@ -43,6 +49,7 @@ pub fn parse_query_blocks(query_str: &str) -> Result<Query, Error> {
 pub fn render_blocks_query(
     query: Query,
     tag_index: &TagIndex,
+    block_properties_index: &BlockPropertiesIndex,
     user_page_index: &UserPageIndex,
     journal_page_index: &JournalPageIndex,
     journal_title_calculator_metadata: &JournalTitleCalculatorMetadata,
@@ -50,17 +57,23 @@ pub fn render_blocks_query(
     let target = SimplePageName {
         name: query.args.get(PARAM_TARGET).unwrap().clone(),
     };
+    let target_names = all_names_of(&target, block_properties_index);
 
-    let empty_set: HashSet<PageId> = HashSet::new();
-    let mut references: Vec<&PageId> = tag_index
-        .entries
-        .get(&target.as_user_page())
-        .unwrap_or(&empty_set)
-        .iter()
-        .collect();
+    let references_including_aliases =
+        find_references_including_aliases(&target, tag_index, block_properties_index);
+    let mut references: Vec<&PageId> = references_including_aliases.iter().collect();
     references.sort();
 
-    let resolved_blocks = resolve_blocks(&target, &references, user_page_index, journal_page_index);
+    let resolved_blocks = filter_by_property(
+        resolve_blocks(
+            &target_names,
+            &references,
+            user_page_index,
+            journal_page_index,
+        ),
+        &query,
+        block_properties_index,
+    );
 
     match query.display {
         QueryDisplayType::InplaceList => {
@@ -88,7 +101,7 @@ pub fn render_blocks_query(
 }
 
 fn resolve_blocks(
-    target: &SimplePageName,
+    target_names: &[SimplePageName],
     page_ids: &[&PageId],
     user_page_index: &UserPageIndex,
     journal_page_index: &JournalPageIndex,
@@ -98,12 +111,12 @@ fn resolve_blocks(
     for page_id in page_ids.iter() {
         let blocks = match page_id.page_type {
             PageType::UserPage => resolve_blocks_in_page(
-                target,
+                target_names,
                 page_id,
                 user_page_index.find(&page_id.name).unwrap(),
             ),
             PageType::JournalPage => resolve_blocks_in_page(
-                target,
+                target_names,
                 page_id,
                 journal_page_index.find(&page_id.name).unwrap(),
             ),
@@ -114,14 +127,49 @@ fn resolve_blocks(
     result
 }
 
+/// Keeps the blocks carrying the property of the query. Values are compared as typed values
+/// of the property schema.
+fn filter_by_property(
+    blocks: Vec<BlockQueryResult>,
+    query: &Query,
+    block_properties_index: &BlockPropertiesIndex,
+) -> Vec<BlockQueryResult> {
+    let Some(key) = query.get_arg(PARAM_PROPERTY_KEY) else {
+        return blocks;
+    };
+    let key = BlockPropertyKey { value: key.clone() };
+    let matching_blocks: Vec<&BlockReference> = match query.get_arg(PARAM_PROPERTY_VALUE) {
+        Some(value) => block_properties_index
+            .find_block_properties_with_value(
+                &key,
+                &BlockPropertyValue {
+                    value: value.clone(),
+                },
+            )
+            .map(|occurence| &occurence.block)
+            .collect(),
+        None => block_properties_index
+            .find_block_properties(&key)
+            .map(|occurence| &occurence.block)
+            .collect(),
+    };
+    blocks
+        .into_iter()
+        .filter(|block| matching_blocks.contains(&&block.block_reference))
+        .collect()
+}
+
 fn resolve_blocks_in_page(
-    target: &SimplePageName,
+    target_names: &[SimplePageName],
     page_id: &PageId,
     page: &ParsedMarkdownFile,
 ) -> Vec<BlockQueryResult> {
     let mut result = vec![];
     for (index, block) in page.blocks.iter().enumerate() {
-        if block.contains_reference(target) {
+        if target_names
+            .iter()
+            .any(|target| block.contains_reference(target))
+        {
             result.push(BlockQueryResult {
                 block_reference: page_id.block_reference(index),
                 parsed_block: block.clone(),
@@ -240,6 +288,7 @@ fn render_as_referenced_list(refs: &[BlockQueryResult]) -> QueryRenderResult {
         result.push(ReferencedMarkdown {
             content: r.parsed_block.clone(),
             reference: r.block_reference.clone(),
+            render_deep: false,
         });
     }
 
@@ -254,12 +303,26 @@ fn render_as_referenced_list(refs: &[BlockQueryResult]) -> QueryRenderResult {
 mod tests {
     use super::render_blocks_query;
     use crate::looksyk::builder::test_builder::empty_journal_index;
+    use crate::looksyk::builder::test_builder::user_page_id;
     use crate::looksyk::builder::{link_token, page_name_str, text_token_str};
+    use crate::looksyk::index::alias::ALIAS_PROPERTY;
+    use crate::looksyk::index::block_properties::create_block_properties_index;
+    use crate::looksyk::index::tag::create_tag_index;
     use crate::looksyk::model::ParsedBlock;
+    use crate::looksyk::parser::parse_markdown_file;
+    use crate::looksyk::property_schema::builder::property_schema_with;
+    use crate::looksyk::property_schema::PropertyType;
     use crate::looksyk::queries::args::PARAM_TARGET;
+    use crate::looksyk::queries::pages::{PARAM_PROPERTY_KEY, PARAM_PROPERTY_VALUE};
     use crate::looksyk::query::Query;
+    use crate::looksyk::reader::read_file_contents;
     use crate::looksyk::renderer::renderer_flat::render_block_flat;
     use crate::looksyk::renderer::title::builder::world_journal_title_calculator_metadata;
+    use crate::state::block_properties::builder::{
+        block_properties_index_with, block_property_key, block_property_occurance,
+    };
+    use crate::state::block_properties::BlockPropertiesIndex;
+    use crate::state::journal::JournalPageIndex;
     use crate::state::tag::builder::empty_tag_index;
     use crate::state::tag::TagIndex;
     use crate::state::userpage::builder::empty_user_page_index;
@@ -280,6 +343,7 @@ mod tests {
         let result = render_blocks_query(
             inplace_list_query(),
             &empty_tag_index(),
+            &BlockPropertiesIndex::default(),
             &empty_user_page_index(),
             &empty_journal_index(),
             &world_journal_title_calculator_metadata(),
@@ -323,6 +387,7 @@ mod tests {
         let result = render_blocks_query(
             inplace_list_query(),
             &tag_index_with_existing_tag(),
+            &BlockPropertiesIndex::default(),
             &user_page_index_with_existing_page(),
             &empty_journal_index(),
             &world_journal_title_calculator_metadata(),
@@ -335,6 +400,7 @@ mod tests {
         let result = render_blocks_query(
             paragraph_query(),
             &tag_index_with_existing_tag(),
+            &BlockPropertiesIndex::default(),
             &user_page_index_with_existing_page(),
             &empty_journal_index(),
             &world_journal_title_calculator_metadata(),
@@ -355,9 +421,7 @@ mod tests {
         let mut entries = std::collections::HashMap::new();
         entries.insert(
             page_name_str("referencing"),
-            crate::looksyk::model::ParsedMarkdownFile {
-                blocks: vec![matching_text_block()],
-            },
+            crate::looksyk::model::ParsedMarkdownFile::from_blocks(vec![matching_text_block()]),
         );
 
         UserPageIndex { entries }
@@ -368,6 +432,7 @@ mod tests {
         let result = render_blocks_query(
             referenced_list_query(),
             &tag_index_with_existing_tag(),
+            &BlockPropertiesIndex::default(),
             &user_page_index_with_existing_page(),
             &empty_journal_index(),
             &world_journal_title_calculator_metadata(),
@@ -401,4 +466,97 @@ mod tests {
                 .collect(),
         }
     }
+
+    #[test]
+    fn test_render_blocks_query_on_canonical_page_should_include_alias_references() {
+        let alias_index = block_properties_index_with(
+            block_property_key(ALIAS_PROPERTY),
+            vec![block_property_occurance(
+                "foo",
+                user_page_id("canonical").block_reference(0),
+            )],
+        );
+
+        let result = render_blocks_query(
+            Query {
+                query_type: super::QueryType::Blocks,
+                display: super::QueryDisplayType::Count,
+                args: vec![(PARAM_TARGET.to_string(), "canonical".to_string())]
+                    .into_iter()
+                    .collect(),
+            },
+            &tag_index_with_existing_tag(),
+            &alias_index,
+            &user_page_index_with_existing_page(),
+            &empty_journal_index(),
+            &world_journal_title_calculator_metadata(),
+        );
+
+        assert_eq!(result.inplace_markdown, "1");
+    }
+
+    #[test]
+    fn test_parse_query_with_property() {
+        let result = super::parse_query_blocks(
+            "blocks tag:\"foo\" propertyKey:\"due\" propertyValue:\"2024-06-01\" display:\"count\"",
+        )
+        .unwrap();
+
+        assert_eq!(result.args.get(PARAM_TARGET).unwrap(), "foo");
+        assert_eq!(result.get_arg(PARAM_PROPERTY_KEY).unwrap(), "due");
+        assert_eq!(result.get_arg(PARAM_PROPERTY_VALUE).unwrap(), "2024-06-01");
+    }
+
+    #[test]
+    fn test_render_blocks_query_should_compare_typed_property_values() {
+        let mut journal_pages = JournalPageIndex::default();
+        journal_pages.insert(
+            page_name_str("2024_05_30"),
+            parse_markdown_file(read_file_contents(
+                "- [[foo]] first due:: 2024_06_01 effort:: 03\n- [[foo]] second due:: 2024-06-02 effort:: 3\n- [[foo]] third",
+            )),
+        );
+        let user_pages = empty_user_page_index();
+        let tag_index = create_tag_index(&user_pages, &journal_pages);
+        let render = |query: &str, property_type: PropertyType, key: &str| {
+            render_blocks_query(
+                super::parse_query_blocks(query).unwrap(),
+                &tag_index,
+                &create_block_properties_index(
+                    &journal_pages,
+                    &user_pages,
+                    &property_schema_with(key, property_type),
+                ),
+                &user_pages,
+                &journal_pages,
+                &world_journal_title_calculator_metadata(),
+            )
+            .inplace_markdown
+        };
+
+        assert_eq!(
+            render(
+                "blocks tag:\"foo\" propertyKey:\"due\" propertyValue:\"2024-06-01\" display:\"count\"",
+                PropertyType::Date,
+                "due"
+            ),
+            "1"
+        );
+        assert_eq!(
+            render(
+                "blocks tag:\"foo\" propertyKey:\"effort\" propertyValue:\"3\" display:\"count\"",
+                PropertyType::Number,
+                "effort"
+            ),
+            "2"
+        );
+        assert_eq!(
+            render(
+                "blocks tag:\"foo\" propertyKey:\"due\" display:\"count\"",
+                PropertyType::Date,
+                "due"
+            ),
+            "2"
+        );
+    }
 }
@@ -0,0 +1,204 @@
+use crate::looksyk::model::{
+    PageId, ParsedMarkdownFile, QueryRenderResult, ReferencedMarkdown, SimplePageName,
+};
+use crate::looksyk::queries::args::{
+    parse_display_type, ParamBuilder, ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX,
+};
+use crate::looksyk::queries::basic::unknown::render_display_unknown;
+use crate::looksyk::query::{Query, QueryDisplayType, QueryType};
+use crate::looksyk::renderer::atomics::render_user_link;
+use crate::state::userpage::UserPageIndex;
+use std::io::Error;
+
+pub const QUERY_NAME_EMBED_BLOCK: &str = "embed-block";
+pub const PARAM_PAGE: &str = "page";
+pub const PARAM_FIRST_LINE: &str = "first-line";
+
+pub fn parse_query_embed_block(query_str: &str) -> Result<Query, Error> {
+    let query_content = query_str
+        .strip_prefix(QUERY_NAME_EMBED_BLOCK)
+        .ok_or(Error::other(ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX))?
+        .trim();
+    let params = ParamBuilder::init(query_content.to_string())
+        .next(PARAM_PAGE)?
+        .next(PARAM_FIRST_LINE)?
+        .build();
+
+    let display = if params.remaining_value.is_empty() {
+        QueryDisplayType::ReferencedList
+    } else {
+        parse_display_type(params.remaining_value)?
+    };
+
+    Ok(Query {
+        query_type: QueryType::EmbedBlock,
+        display,
+        args: params.parsed_args,
+    })
+}
+
+pub fn render_embed_block_query(query: Query, user_pages: &UserPageIndex) -> QueryRenderResult {
+    if query.display != QueryDisplayType::ReferencedList {
+        return render_display_unknown(query.display, vec![QueryDisplayType::ReferencedList]);
+    }
+
+    let page_name = SimplePageName {
+        name: query.get_arg(PARAM_PAGE).unwrap().clone(),
+    };
+    let first_line = query.get_arg(PARAM_FIRST_LINE).unwrap();
+
+    let Some(page) = user_pages.find(&page_name) else {
+        return render_error(format!("Page {} not found", render_user_link(&page_name)));
+    };
+    let Some(root_block_number) = find_block_by_first_line(page, first_line) else {
+        return render_error(format!(
+            "Block '{}' not found on page {}",
+            first_line,
+            render_user_link(&page_name)
+        ));
+    };
+
+    QueryRenderResult {
+        inplace_markdown: "".to_string(),
+        referenced_markdown: embed_subtree(page, &page_name.as_user_page(), root_block_number),
+        has_dynamic_content: true,
+    }
+}
+
+fn find_block_by_first_line(page: &ParsedMarkdownFile, first_line: &str) -> Option<usize> {
+    page.blocks.iter().position(|block| {
+        block
+            .content
+            .first()
+            .is_some_and(|content| content.as_text.trim() == first_line.trim())
+    })
+}
+
+fn embed_subtree(
+    page: &ParsedMarkdownFile,
+    page_id: &PageId,
+    root_block_number: usize,
+) -> Vec<ReferencedMarkdown> {
+    let root_indentation = page.blocks[root_block_number].indentation;
+    let subtree_end = page.blocks[root_block_number + 1..]
+        .iter()
+        .position(|block| block.indentation <= root_indentation)
+        .map(|offset| root_block_number + 1 + offset)
+        .unwrap_or(page.blocks.len());
+
+    (root_block_number..subtree_end)
+        .map(|block_number| ReferencedMarkdown {
+            content: page.blocks[block_number].clone(),
+            reference: page_id.block_reference(block_number),
+            render_deep: true,
+        })
+        .collect()
+}
+
+fn render_error(message: String) -> QueryRenderResult {
+    QueryRenderResult {
+        inplace_markdown: message,
+        referenced_markdown: vec![],
+        has_dynamic_content: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::page_name_str;
+    use crate::looksyk::model::builder::block_with_text_content;
+    use crate::looksyk::model::ParsedBlock;
+    use crate::state::userpage::builder::{empty_user_page_index, user_page_index};
+
+    fn indented(text: &str, indentation: usize) -> ParsedBlock {
+        ParsedBlock {
+            indentation,
+            ..block_with_text_content(text)
+        }
+    }
+
+    fn runbook() -> UserPageIndex {
+        user_page_index(
+            "Runbook",
+            ParsedMarkdownFile::from_blocks(vec![
+                block_with_text_content("Intro"),
+                block_with_text_content("Deploy steps"),
+                indented("build", 1),
+                indented("push", 2),
+                indented("release", 1),
+                block_with_text_content("Rollback"),
+            ]),
+        )
+    }
+
+    fn embed_query(page: &str, first_line: &str) -> Query {
+        parse_query_embed_block(&format!(
+            "embed-block page:\"{page}\" first-line:\"{first_line}\""
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_query_should_default_to_referenced_list() {
+        let query = embed_query("Runbook", "Deploy steps");
+
+        assert_eq!(query.query_type, QueryType::EmbedBlock);
+        assert_eq!(query.display, QueryDisplayType::ReferencedList);
+        assert_eq!(query.get_arg(PARAM_PAGE).unwrap(), "Runbook");
+        assert_eq!(query.get_arg(PARAM_FIRST_LINE).unwrap(), "Deploy steps");
+    }
+
+    #[test]
+    fn test_render_should_embed_block_with_subtree_and_origin() {
+        let result = render_embed_block_query(embed_query("Runbook", "Deploy steps"), &runbook());
+
+        let page_id = page_name_str("Runbook").as_user_page();
+        assert_eq!(
+            result
+                .referenced_markdown
+                .iter()
+                .map(|r| r.reference.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                page_id.block_reference(1),
+                page_id.block_reference(2),
+                page_id.block_reference(3),
+                page_id.block_reference(4),
+            ]
+        );
+        assert!(result.referenced_markdown.iter().all(|r| r.render_deep));
+        assert_eq!(result.inplace_markdown, "");
+    }
+
+    #[test]
+    fn test_render_should_embed_last_block_without_children() {
+        let result = render_embed_block_query(embed_query("Runbook", "Rollback"), &runbook());
+
+        assert_eq!(result.referenced_markdown.len(), 1);
+        assert_eq!(result.referenced_markdown[0].reference.block_number, 5);
+    }
+
+    #[test]
+    fn test_render_should_report_missing_block() {
+        let result = render_embed_block_query(embed_query("Runbook", "Unknown"), &runbook());
+
+        assert_eq!(
+            result.inplace_markdown,
+            "Block 'Unknown' not found on page [Runbook](page/Runbook)"
+        );
+    }
+
+    #[test]
+    fn test_render_should_report_missing_page() {
+        let result = render_embed_block_query(
+            embed_query("Runbook", "Deploy steps"),
+            &empty_user_page_index(),
+        );
+
+        assert_eq!(
+            result.inplace_markdown,
+            "Page [Runbook](page/Runbook) not found"
+        );
+    }
+}
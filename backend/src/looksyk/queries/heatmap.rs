@@ -0,0 +1,219 @@
+use crate::io::date::resolve_query_date;
+use crate::looksyk::aggregate::format_aggregated_value;
+use crate::looksyk::habit::{
+    calculate_heatmap, collect_habit_days, HabitSource, HabitSources, Heatmap,
+};
+use crate::looksyk::model::{QueryRenderResult, SimplePageName};
+use crate::looksyk::queries::args::{
+    parse_display_type, ParamBuilder, ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX, PARAM_TAG,
+};
+use crate::looksyk::queries::basic::extra_param_validation::ParamValidator;
+use crate::looksyk::queries::basic::unknown::render_display_unknown;
+use crate::looksyk::query::{Query, QueryDisplayType, QueryType};
+use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
+use crate::state::block_properties::{BlockPropertiesIndex, BlockPropertyKey};
+use crate::state::journal::JournalPageIndex;
+use crate::state::tag::TagIndex;
+use std::io::Error;
+
+pub const QUERY_NAME_HEATMAP: &str = "heatmap";
+
+pub const PARAM_PROPERTY_KEY: &str = "propertyKey";
+
+pub const PARAM_ENDING_AT: &str = "endingAt";
+
+pub fn parse_query_heatmap(query_str: &str) -> Result<Query, Error> {
+    let query_content = query_str
+        .strip_prefix(QUERY_NAME_HEATMAP)
+        .ok_or(Error::other(ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX))?
+        .trim();
+
+    let parser_result = ParamBuilder::init(query_content.to_string())
+        .next_optional(&[PARAM_TAG, PARAM_PROPERTY_KEY, PARAM_ENDING_AT])?
+        .build();
+
+    let display_type = parse_display_type(parser_result.remaining_value)?;
+
+    Ok(Query {
+        query_type: QueryType::Heatmap,
+        display: display_type,
+        args: parser_result.parsed_args,
+    })
+}
+
+pub fn render_heatmap_query(
+    query: Query,
+    tag_index: &TagIndex,
+    block_properties_index: &BlockPropertiesIndex,
+    journal_page_index: &JournalPageIndex,
+    journal_title_calculator_metadata: &JournalTitleCalculatorMetadata,
+) -> QueryRenderResult {
+    if query.display != QueryDisplayType::Heatmap {
+        return render_display_unknown(query.display, vec![QueryDisplayType::Heatmap]);
+    }
+
+    let mut validation = ParamValidator::default().validate_exactly_one_given(&[
+        (PARAM_TAG, query.get_arg(PARAM_TAG)),
+        (PARAM_PROPERTY_KEY, query.get_arg(PARAM_PROPERTY_KEY)),
+    ]);
+    if let Some(ending_at) = query.get_arg(PARAM_ENDING_AT) {
+        validation = validation.validate_as_date(ending_at, PARAM_ENDING_AT);
+    }
+    if validation.has_errors() {
+        return QueryRenderResult {
+            inplace_markdown: validation.format_errors_as_markdown(),
+            referenced_markdown: vec![],
+            has_dynamic_content: false,
+        };
+    }
+
+    let source = match query.get_arg(PARAM_TAG) {
+        Some(tag) => HabitSource::Tag(SimplePageName { name: tag.clone() }),
+        None => HabitSource::Property(BlockPropertyKey {
+            value: query.get_arg(PARAM_PROPERTY_KEY).unwrap().clone(),
+        }),
+    };
+    let today = &journal_title_calculator_metadata.today;
+    let ending_at = query
+        .get_arg(PARAM_ENDING_AT)
+        .and_then(|date| resolve_query_date(date, today))
+        .unwrap_or(today.date());
+
+    let habit_days = collect_habit_days(
+        &source,
+        &HabitSources {
+            tag_index,
+            block_properties_index,
+            journal_pages: journal_page_index,
+        },
+    );
+
+    QueryRenderResult {
+        inplace_markdown: render_as_heatmap(&calculate_heatmap(&habit_days, ending_at)),
+        referenced_markdown: vec![],
+        has_dynamic_content: true,
+    }
+}
+
+fn render_as_heatmap(heatmap: &Heatmap) -> String {
+    let mut markdown = "<div class=\"heatmap\">".to_string();
+    for _ in 0..heatmap.leading_empty_days {
+        markdown.push_str("<div class=\"heatmap-day heatmap-empty\"></div>");
+    }
+    for day in &heatmap.days {
+        markdown.push_str(&format!(
+            "<div class=\"heatmap-day heatmap-level-{}\" title=\"{}: {}\"></div>",
+            day.level,
+            day.date.format("%Y-%m-%d"),
+            format_aggregated_value(Some(day.value))
+        ));
+    }
+    markdown.push_str("</div>\n\n");
+    markdown.push_str(&format!(
+        "Current streak: {}, longest streak: {}",
+        format_days(heatmap.streaks.current),
+        format_days(heatmap.streaks.longest)
+    ));
+    markdown
+}
+
+fn format_days(days: usize) -> String {
+    match days {
+        1 => "1 day".to_string(),
+        _ => format!("{days} days"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::looksyk::builder::page_name_str;
+    use crate::looksyk::index::block_properties::create_block_properties_index;
+    use crate::looksyk::index::tag::create_tag_index;
+    use crate::looksyk::parser::parse_markdown_file;
+    use crate::looksyk::property_schema::PropertySchema;
+    use crate::looksyk::queries::args::PARAM_TAG;
+    use crate::looksyk::queries::heatmap::{
+        parse_query_heatmap, render_heatmap_query, PARAM_ENDING_AT, PARAM_PROPERTY_KEY,
+    };
+    use crate::looksyk::query::{QueryDisplayType, QueryType};
+    use crate::looksyk::reader::read_file_contents;
+    use crate::looksyk::renderer::title::builder::world_journal_title_calculator_metadata;
+    use crate::state::journal::JournalPageIndex;
+    use crate::state::userpage::builder::empty_user_page_index;
+
+    fn render(query: &str) -> String {
+        let mut journal_pages = JournalPageIndex::default();
+        journal_pages.insert(
+            page_name_str("2024_06_13"),
+            parse_markdown_file(read_file_contents("- [[Workout]] run pushups:: 20")),
+        );
+        journal_pages.insert(
+            page_name_str("2024_06_14"),
+            parse_markdown_file(read_file_contents("- [[Workout]] gym\n- [[Workout]] run")),
+        );
+        let user_pages = empty_user_page_index();
+        render_heatmap_query(
+            parse_query_heatmap(query).unwrap(),
+            &create_tag_index(&user_pages, &journal_pages),
+            &create_block_properties_index(&journal_pages, &user_pages, &PropertySchema::default()),
+            &journal_pages,
+            &world_journal_title_calculator_metadata(),
+        )
+        .inplace_markdown
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let result =
+            parse_query_heatmap("heatmap tag:\"Workout\" endingAt:\"-1d\" display:\"heatmap\"")
+                .unwrap();
+
+        assert_eq!(result.query_type, QueryType::Heatmap);
+        assert_eq!(result.display, QueryDisplayType::Heatmap);
+        assert_eq!(result.get_arg(PARAM_TAG).unwrap(), "Workout");
+        assert_eq!(result.get_arg(PARAM_ENDING_AT).unwrap(), "-1d");
+        assert!(result.get_arg(PARAM_PROPERTY_KEY).is_none());
+    }
+
+    #[test]
+    fn test_render_tag_as_heatmap() {
+        let result = render("heatmap tag:\"Workout\" endingAt:\"2024-06-15\" display:\"heatmap\"");
+
+        assert!(result
+            .starts_with("<div class=\"heatmap\"><div class=\"heatmap-day heatmap-empty\"></div>"));
+        assert_eq!(result.matches("heatmap-empty").count(), 5);
+        assert_eq!(
+            result.matches("class=\"heatmap-day heatmap-level-").count(),
+            365
+        );
+        assert!(result.contains(
+            "<div class=\"heatmap-day heatmap-level-2\" title=\"2024-06-13: 1\"></div><div class=\"heatmap-day heatmap-level-4\" title=\"2024-06-14: 2\"></div><div class=\"heatmap-day heatmap-level-0\" title=\"2024-06-15: 0\"></div></div>"
+        ));
+        assert!(result.ends_with("</div>\n\nCurrent streak: 2 days, longest streak: 2 days"));
+    }
+
+    #[test]
+    fn test_render_property_as_heatmap() {
+        let result =
+            render("heatmap propertyKey:\"pushups\" endingAt:\"2024-06-16\" display:\"heatmap\"");
+
+        assert!(result.contains("title=\"2024-06-13: 20\""));
+        assert!(result.ends_with("Current streak: 0 days, longest streak: 1 day"));
+    }
+
+    #[test]
+    fn test_render_with_invalid_params() {
+        assert_eq!(
+            render("heatmap endingAt:\"someday\" display:\"heatmap\""),
+            "**Parameter Validation Errors:**\n- Exactly one of the parameters 'tag', 'propertyKey' is required.\n- Parameter 'endingAt' with value 'someday' is not a valid date (expected format: YYYY-MM-DD, today, -30d, +2w, -6m, -1y or start-of-week/month/year).\n"
+        );
+    }
+
+    #[test]
+    fn test_render_with_unsupported_display() {
+        assert_eq!(
+            render("heatmap tag:\"Workout\" display:\"count\""),
+            "display type count not supported for querytype. Avaliable display types: heatmap"
+        );
+    }
+}
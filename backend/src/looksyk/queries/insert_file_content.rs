@@ -1,13 +1,16 @@
-use std::collections::HashMap;
 use std::io::Error;
 
 use crate::io::fs::asset_cache_loader::load_cachable_asset;
 use crate::io::fs::media::MediaOnDisk;
 use crate::io::http::media::config::create_media_location;
 use crate::looksyk::datatypes::AssetDescriptor;
+use crate::looksyk::media::code_excerpt::{parse_line_range, select_excerpt, CodeExcerpt};
+use crate::looksyk::media::table::{
+    default_delimiter, parse_delimiter, render_as_markdown_table, TableOptions, DELIMITER_TAB,
+};
 use crate::looksyk::model::QueryRenderResult;
 use crate::looksyk::queries::args::{
-    parse_display_type, parse_property, ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX, PARAM_TARGET_FILE,
+    parse_display_type, ParamBuilder, ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX, PARAM_TARGET_FILE,
 };
 use crate::looksyk::queries::basic::unknown::render_display_unknown;
 use crate::looksyk::query::{Query, QueryDisplayType, QueryType};
@@ -19,6 +22,22 @@ use crate::state::asset_cache::{AssetCache, AssetState};
 
 pub const QUERY_NAME_INSERT_FILE_CONTENT: &str = "insert-file-content";
 
+pub const PARAM_DELIMITER: &str = "delimiter";
+
+pub const PARAM_HEADER: &str = "header";
+
+pub const PARAM_LIMIT: &str = "limit";
+
+pub const PARAM_COLUMNS: &str = "columns";
+
+pub const PARAM_LINES: &str = "lines";
+
+pub const PARAM_FROM_MARKER: &str = "from-marker";
+
+pub const PARAM_TO_MARKER: &str = "to-marker";
+
+pub const PARAM_LANG: &str = "lang";
+
 pub fn query_insert_file_content_as_text(asset_descriptor: &AssetDescriptor) -> String {
     format!(
         "{{query: insert-file-content target-file:\"{}\" display:\"inline-text\" }}",
@@ -47,25 +66,46 @@ pub fn query_insert_file_content_as_audio(asset_descriptor: &AssetDescriptor) ->
     )
 }
 
+pub fn query_insert_file_content_as_table(asset_descriptor: &AssetDescriptor) -> String {
+    format!(
+        "{{query: insert-file-content target-file:\"{}\" display:\"table\" }}",
+        asset_descriptor.get_display_name()
+    )
+}
+
 pub fn parse_query_insert_file_content(query_str: &str) -> Result<Query, Error> {
     let query_content = query_str
         .strip_prefix(QUERY_NAME_INSERT_FILE_CONTENT)
         .ok_or(Error::other(ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX))?
         .trim();
-    let query_target_opt = parse_property(query_content, PARAM_TARGET_FILE)?;
 
-    let display_type = parse_display_type(query_target_opt.remaining_text.clone())?;
+    let parser_result = ParamBuilder::init(query_content.to_string())
+        .next(PARAM_TARGET_FILE)?
+        .next_optional(&[
+            PARAM_DELIMITER,
+            PARAM_HEADER,
+            PARAM_LIMIT,
+            PARAM_COLUMNS,
+            PARAM_LINES,
+            PARAM_FROM_MARKER,
+            PARAM_TO_MARKER,
+            PARAM_LANG,
+        ])?
+        .build();
+
+    let display_type = parse_display_type(parser_result.remaining_value)?;
 
-    let mut args1 = HashMap::new();
-    args1.insert(PARAM_TARGET_FILE.to_string(), query_target_opt.value);
-    let (display_type, args) = (display_type, args1);
     Ok(Query {
         query_type: QueryType::InsertFileContent,
         display: display_type,
-        args,
+        args: parser_result.parsed_args,
     })
 }
 
+pub fn get_target_file(query: &Query) -> Option<&String> {
+    query.get_arg(PARAM_TARGET_FILE)
+}
+
 pub fn render_query_insert_file_content(
     query: Query,
     data: &mut AssetCache,
@@ -84,7 +124,10 @@ pub fn render_query_insert_file_content(
             ),
             referenced_markdown: vec![],
         },
-        QueryDisplayType::CodeBlock => render_code_block(&media_on_disk, data, data_root_location),
+        QueryDisplayType::CodeBlock => {
+            render_code_block_query(&query, &media_on_disk, data, data_root_location)
+        }
+        QueryDisplayType::Table => render_table(&query, &media_on_disk, data, data_root_location),
         QueryDisplayType::Video => QueryRenderResult {
             has_dynamic_content: false,
             inplace_markdown: render_as_video(&media_on_disk.as_asset_descriptor()),
@@ -103,6 +146,7 @@ pub fn render_query_insert_file_content(
                 QueryDisplayType::CodeBlock,
                 QueryDisplayType::Video,
                 QueryDisplayType::Audio,
+                QueryDisplayType::Table,
             ],
         ),
     }
@@ -113,19 +157,160 @@ pub fn render_code_block(
     cache: &mut AssetCache,
     data_root_location: &GraphRootLocation,
 ) -> QueryRenderResult {
+    render_code_excerpt(
+        file_name,
+        &CodeExcerpt::default(),
+        infer_language(file_name),
+        cache,
+        data_root_location,
+    )
+}
+
+fn render_code_block_query(
+    query: &Query,
+    file_name: &MediaOnDisk,
+    cache: &mut AssetCache,
+    data_root_location: &GraphRootLocation,
+) -> QueryRenderResult {
+    let lines = match query.get_arg(PARAM_LINES) {
+        Some(value) => match parse_line_range(value) {
+            Some(range) => Some(range),
+            None => {
+                return QueryRenderResult {
+                    has_dynamic_content: false,
+                    inplace_markdown: format!(
+                        "Parameter '{PARAM_LINES}' with value '{value}' is not a valid line range (expected format: 40-80)."
+                    ),
+                    referenced_markdown: vec![],
+                }
+            }
+        },
+        None => None,
+    };
+    let excerpt = CodeExcerpt {
+        lines,
+        from_marker: query.get_arg(PARAM_FROM_MARKER).cloned(),
+        to_marker: query.get_arg(PARAM_TO_MARKER).cloned(),
+    };
+    let language = query
+        .get_arg(PARAM_LANG)
+        .cloned()
+        .unwrap_or_else(|| infer_language(file_name));
+    render_code_excerpt(file_name, &excerpt, language, cache, data_root_location)
+}
+
+fn render_code_excerpt(
+    file_name: &MediaOnDisk,
+    excerpt: &CodeExcerpt,
+    language: String,
+    cache: &mut AssetCache,
+    data_root_location: &GraphRootLocation,
+) -> QueryRenderResult {
+    render_loaded_asset(
+        file_name,
+        cache,
+        data_root_location,
+        |content| match select_excerpt(&content, excerpt) {
+            Ok(code) => render_as_code_block(language, &code),
+            Err(error) => error,
+        },
+    )
+}
+
+fn render_table(
+    query: &Query,
+    file_name: &MediaOnDisk,
+    cache: &mut AssetCache,
+    data_root_location: &GraphRootLocation,
+) -> QueryRenderResult {
+    let options = match parse_table_options(query, file_name) {
+        Ok(options) => options,
+        Err(error) => {
+            return QueryRenderResult {
+                has_dynamic_content: false,
+                inplace_markdown: error,
+                referenced_markdown: vec![],
+            }
+        }
+    };
+    render_loaded_asset(file_name, cache, data_root_location, |content| {
+        render_as_markdown_table(&content, &options)
+    })
+}
+
+pub fn render_table_preview(
+    file_name: &MediaOnDisk,
+    cache: &mut AssetCache,
+    data_root_location: &GraphRootLocation,
+    limit: usize,
+) -> QueryRenderResult {
+    let options = TableOptions {
+        delimiter: default_delimiter(&file_name.as_asset_descriptor()),
+        header: true,
+        limit: Some(limit),
+        columns: None,
+    };
+    render_loaded_asset(file_name, cache, data_root_location, |content| {
+        render_as_markdown_table(&content, &options)
+    })
+}
+
+fn parse_table_options(query: &Query, file_name: &MediaOnDisk) -> Result<TableOptions, String> {
+    let delimiter = match query.get_arg(PARAM_DELIMITER) {
+        Some(value) => parse_delimiter(value).ok_or(format!(
+            "Parameter '{PARAM_DELIMITER}' with value '{value}' must be a single character or '{DELIMITER_TAB}'."
+        ))?,
+        None => default_delimiter(&file_name.as_asset_descriptor()),
+    };
+    let header = match query.get_arg(PARAM_HEADER).map(String::as_str) {
+        None | Some("true") => true,
+        Some("false") => false,
+        Some(value) => {
+            return Err(format!(
+                "Parameter '{PARAM_HEADER}' with value '{value}' must be 'true' or 'false'."
+            ))
+        }
+    };
+    let limit = match query.get_arg(PARAM_LIMIT) {
+        Some(value) => Some(value.parse::<usize>().map_err(|_| {
+            format!("Parameter '{PARAM_LIMIT}' with value '{value}' is not a valid row count.")
+        })?),
+        None => None,
+    };
+    let columns = query.get_arg(PARAM_COLUMNS).map(|value| {
+        value
+            .split(',')
+            .map(|column| column.trim().to_string())
+            .collect()
+    });
+    Ok(TableOptions {
+        delimiter,
+        header,
+        limit,
+        columns,
+    })
+}
+
+fn render_loaded_asset<F>(
+    file_name: &MediaOnDisk,
+    cache: &mut AssetCache,
+    data_root_location: &GraphRootLocation,
+    render: F,
+) -> QueryRenderResult
+where
+    F: FnOnce(String) -> String,
+{
     let mut cache_item = cache.get(file_name);
     if AssetState::Miss == cache_item {
         cache_item = load_cachable_asset(file_name, data_root_location);
         cache.insert(file_name, cache_item.clone());
     }
     match cache_item {
-        AssetState::Found(content) => {
-            QueryRenderResult {
-                has_dynamic_content: false,
-                inplace_markdown: render_as_code_block(infer_language(file_name), &content.content),
-                referenced_markdown: vec![],
-            }
-        }
+        AssetState::Found(content) => QueryRenderResult {
+            has_dynamic_content: false,
+            inplace_markdown: render(content.content),
+            referenced_markdown: vec![],
+        },
         AssetState::NotFound => QueryRenderResult {
             has_dynamic_content: false,
             inplace_markdown: "File not found".to_string(),
@@ -180,44 +365,13 @@ fn render_inline(
     cache: &mut AssetCache,
     data_root_location: &GraphRootLocation,
 ) -> QueryRenderResult {
-    let mut cache_item = cache.get(file_name);
-    if AssetState::Miss == cache_item {
-        cache_item = load_cachable_asset(file_name, data_root_location);
-        cache.insert(file_name, cache_item.clone());
-    }
-    match cache_item {
-        AssetState::Found(content) => QueryRenderResult {
-            has_dynamic_content: false,
-            inplace_markdown: content.content,
-            referenced_markdown: vec![],
-        },
-        AssetState::NotFound => QueryRenderResult {
-            has_dynamic_content: false,
-            inplace_markdown: "File not found".to_string(),
-            referenced_markdown: vec![],
-        },
-        AssetState::NotText => QueryRenderResult {
-            has_dynamic_content: false,
-            inplace_markdown: format!("File is not a text file. Can not inline a binary file. Try display type \"link\" to render a link: {}", render_as_link(&file_name.name, &create_media_location(&file_name.name))),
-            referenced_markdown: vec![],
-        },
-        AssetState::TooLarge(violation) => QueryRenderResult {
-            has_dynamic_content: false,
-            inplace_markdown: format!("File is too large. Max size is {}. File size is {}. Try display type \"link\" to render a link: {}", violation.max_size, violation.file_size, render_as_link(&file_name.name, &create_media_location(&file_name.name))),
-            referenced_markdown: vec![],
-        },
-        _ => QueryRenderResult {
-            has_dynamic_content: false,
-            inplace_markdown: "Unknown error".to_string(),
-            referenced_markdown: vec![],
-        }
-    }
+    render_loaded_asset(file_name, cache, data_root_location, |content| content)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::looksyk::builder::test_builder::asset_descriptor;
+    use crate::looksyk::builder::test_builder::{asset_descriptor, data_root_location};
 
     #[test]
     pub fn test_query_insert_file_content_as_text_with_file_path_should_return_query_string() {
@@ -257,4 +411,80 @@ mod tests {
             "{query: insert-file-content target-file:\"file_path\" display:\"audio\" }"
         );
     }
+
+    #[test]
+    pub fn test_query_insert_file_content_as_table_with_file_path_should_return_query_string() {
+        let result = query_insert_file_content_as_table(&asset_descriptor("file_path"));
+
+        assert_eq!(
+            result,
+            "{query: insert-file-content target-file:\"file_path\" display:\"table\" }"
+        );
+    }
+
+    #[test]
+    pub fn test_parse_query_insert_file_content_with_table_options() {
+        let result = parse_query_insert_file_content(
+            "insert-file-content target-file:\"data.csv\" limit:\"5\" delimiter:\";\" display:\"table\"",
+        )
+        .unwrap();
+
+        assert_eq!(result.display, QueryDisplayType::Table);
+        assert_eq!(result.get_arg(PARAM_TARGET_FILE).unwrap(), "data.csv");
+        assert_eq!(result.get_arg(PARAM_LIMIT).unwrap(), "5");
+        assert_eq!(result.get_arg(PARAM_DELIMITER).unwrap(), ";");
+        assert!(result.get_arg(PARAM_HEADER).is_none());
+    }
+
+    #[test]
+    pub fn test_parse_table_options_should_reject_invalid_limit() {
+        let query = parse_query_insert_file_content(
+            "insert-file-content target-file:\"data.csv\" limit:\"all\" display:\"table\"",
+        )
+        .unwrap();
+
+        let result = parse_table_options(
+            &query,
+            &MediaOnDisk {
+                name: "data.csv".to_string(),
+            },
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    pub fn test_parse_query_insert_file_content_with_code_excerpt_options() {
+        let result = parse_query_insert_file_content(
+            "insert-file-content target-file:\"main.rs\" from-marker:\"// region foo\" to-marker:\"// endregion\" lang:\"rust\" display:\"code-block\"",
+        )
+        .unwrap();
+
+        assert_eq!(result.display, QueryDisplayType::CodeBlock);
+        assert_eq!(result.get_arg(PARAM_FROM_MARKER).unwrap(), "// region foo");
+        assert_eq!(result.get_arg(PARAM_TO_MARKER).unwrap(), "// endregion");
+        assert_eq!(result.get_arg(PARAM_LANG).unwrap(), "rust");
+    }
+
+    #[test]
+    pub fn test_render_code_block_query_with_invalid_lines_should_render_error() {
+        let query = parse_query_insert_file_content(
+            "insert-file-content target-file:\"main.rs\" lines:\"x\" display:\"code-block\"",
+        )
+        .unwrap();
+
+        let result = render_code_block_query(
+            &query,
+            &MediaOnDisk {
+                name: "main.rs".to_string(),
+            },
+            &mut AssetCache::new(),
+            &data_root_location("/nonexistent"),
+        );
+
+        assert_eq!(
+            result.inplace_markdown,
+            "Parameter 'lines' with value 'x' is not a valid line range (expected format: 40-80)."
+        );
+    }
 }
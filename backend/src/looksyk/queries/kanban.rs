@@ -138,7 +138,7 @@ mod tests {
             result.inplace_markdown,
             "[BoardBoardBoard](/special-page/kanban?data=%7B%22title%22%3A%22BoardBoardBoard%22%2C%22tag%22%3A%22my-board%22%2C%22columnKey%22%3A%22status%22%2C%22columnValues%22%3A%5B%22TODO%22%2C%22DOING%22%2C%22DONE%22%2C%22BLOCKED%22%5D%2C%22priorityKey%22%3A%22prio%22%7D)"
         );
-        assert_eq!(result.has_dynamic_content, false);
+        assert!(!result.has_dynamic_content);
         assert_eq!(result.referenced_markdown.len(), 0);
     }
 }
@@ -1,9 +1,13 @@
+pub mod aggregate;
 mod args;
 pub mod available;
 pub mod blocks;
+pub mod embed_block;
+pub mod heatmap;
 pub mod insert_file_content;
 pub mod kanban;
 pub mod pagehierarchy;
+pub mod pages;
 pub mod plot;
 pub mod references_to;
 pub mod todo;
@@ -35,7 +35,7 @@ pub fn render_page_hierarchy(query: Query, data: &UserPageIndex) -> QueryRenderR
     let keys: Vec<&SimplePageName> = data.entries.keys().collect();
 
     let mut result = filter_pages_by_root(root, keys);
-    result.sort_unstable_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    result.sort_unstable_by_key(|a| a.name.to_lowercase());
 
     match query.display {
         QueryDisplayType::InplaceList => render_as_list(root, result),
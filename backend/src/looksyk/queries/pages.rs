@@ -0,0 +1,272 @@
+use crate::looksyk::media::table::escape_cell;
+use crate::looksyk::model::{PageId, PageType, ParsedMarkdownFile, QueryRenderResult};
+use crate::looksyk::property_schema::PropertySchema;
+use crate::looksyk::queries::args::{
+    parse_display_type, ParamBuilder, ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX,
+};
+use crate::looksyk::queries::basic::count::render_as_count;
+use crate::looksyk::queries::basic::unknown::render_display_unknown;
+use crate::looksyk::query::{Query, QueryDisplayType, QueryType};
+use crate::looksyk::renderer::atomics::render_link;
+use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
+use crate::state::block_properties::{BlockPropertyKey, BlockPropertyValue};
+use crate::state::journal::JournalPageIndex;
+use crate::state::page_properties::PagePropertiesIndex;
+use crate::state::userpage::UserPageIndex;
+use std::io::Error;
+
+pub const QUERY_NAME_PAGES: &str = "pages";
+
+pub const PARAM_PROPERTY_KEY: &str = "propertyKey";
+
+pub const PARAM_PROPERTY_VALUE: &str = "propertyValue";
+
+pub const PARAM_COLUMNS: &str = "columns";
+
+pub fn parse_query_pages(query_str: &str) -> Result<Query, Error> {
+    let query_content = query_str
+        .strip_prefix(QUERY_NAME_PAGES)
+        .ok_or(Error::other(ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX))?
+        .trim();
+
+    let parser_result = ParamBuilder::init(query_content.to_string())
+        .next(PARAM_PROPERTY_KEY)?
+        .next_optional(&[PARAM_PROPERTY_VALUE, PARAM_COLUMNS])?
+        .build();
+
+    let display_type = parse_display_type(parser_result.remaining_value)?;
+
+    Ok(Query {
+        query_type: QueryType::Pages,
+        display: display_type,
+        args: parser_result.parsed_args,
+    })
+}
+
+pub fn render_pages_query(
+    query: Query,
+    user_page_index: &UserPageIndex,
+    journal_page_index: &JournalPageIndex,
+    page_properties_index: &PagePropertiesIndex,
+    property_schema: &PropertySchema,
+    journal_title_calculator_metadata: &JournalTitleCalculatorMetadata,
+) -> QueryRenderResult {
+    let property_key = BlockPropertyKey {
+        value: query.get_arg(PARAM_PROPERTY_KEY).unwrap().clone(),
+    };
+    let property_value = query
+        .get_arg(PARAM_PROPERTY_VALUE)
+        .map(|value| BlockPropertyValue {
+            value: value.clone(),
+        });
+    let pages =
+        page_properties_index.find_pages(&property_key, property_value.as_ref(), property_schema);
+
+    match query.display {
+        QueryDisplayType::InplaceList => {
+            render_as_list(&property_key, &pages, journal_title_calculator_metadata)
+        }
+        QueryDisplayType::Count => render_as_count(&pages),
+        QueryDisplayType::Table => render_as_table(
+            &pages,
+            &selected_columns(&query, &property_key),
+            user_page_index,
+            journal_page_index,
+            journal_title_calculator_metadata,
+        ),
+        _ => render_display_unknown(
+            query.display,
+            vec![
+                QueryDisplayType::InplaceList,
+                QueryDisplayType::Count,
+                QueryDisplayType::Table,
+            ],
+        ),
+    }
+}
+
+fn selected_columns(query: &Query, property_key: &BlockPropertyKey) -> Vec<String> {
+    match query.get_arg(PARAM_COLUMNS) {
+        Some(columns) => parse_columns(columns),
+        None => vec![property_key.value.clone()],
+    }
+}
+
+fn parse_columns(columns: &str) -> Vec<String> {
+    columns
+        .split(',')
+        .map(str::trim)
+        .filter(|column| !column.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn render_as_list(
+    property_key: &BlockPropertyKey,
+    pages: &[PageId],
+    journal_title_calculator_metadata: &JournalTitleCalculatorMetadata,
+) -> QueryRenderResult {
+    let mut result = format!("Pages with property {}:\n\n", property_key.value);
+    for page in pages {
+        result.push_str(&format!(
+            "* {}\n",
+            render_link(page, journal_title_calculator_metadata)
+        ));
+    }
+    if pages.is_empty() {
+        result.push_str("* No pages found!\n");
+    }
+
+    QueryRenderResult {
+        referenced_markdown: vec![],
+        inplace_markdown: result,
+        has_dynamic_content: false,
+    }
+}
+
+fn render_as_table(
+    pages: &[PageId],
+    columns: &[String],
+    user_page_index: &UserPageIndex,
+    journal_page_index: &JournalPageIndex,
+    journal_title_calculator_metadata: &JournalTitleCalculatorMetadata,
+) -> QueryRenderResult {
+    let mut result = "| page |".to_string();
+    for column in columns {
+        result.push_str(&format!(" {} |", escape_cell(column)));
+    }
+    result.push_str("\n| :-- |");
+    result.push_str(&" :-- |".repeat(columns.len()));
+    result.push('\n');
+
+    for page_id in pages {
+        let page = match page_id.page_type {
+            PageType::UserPage => user_page_index.find(&page_id.name),
+            PageType::JournalPage => journal_page_index.find(&page_id.name),
+        };
+        result.push_str(&format!(
+            "| {} |",
+            render_link(page_id, journal_title_calculator_metadata)
+        ));
+        for column in columns {
+            result.push_str(&format!(" {} |", page_property_cell(page, column)));
+        }
+        result.push('\n');
+    }
+
+    QueryRenderResult {
+        referenced_markdown: vec![],
+        inplace_markdown: result,
+        has_dynamic_content: false,
+    }
+}
+
+pub fn page_property_cell(page: Option<&ParsedMarkdownFile>, key: &str) -> String {
+    page.and_then(|page| page.page_property(key))
+        .map(escape_cell)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::looksyk::builder::page_name_str;
+    use crate::looksyk::builder::test_builder::empty_journal_index;
+    use crate::looksyk::index::page_properties::create_page_properties_index;
+    use crate::looksyk::model::builder::block_with_property;
+    use crate::looksyk::model::{ParsedBlock, ParsedMarkdownFile};
+    use crate::looksyk::parser::{BlockProperties, BlockProperty};
+    use crate::looksyk::property_schema::PropertySchema;
+    use crate::looksyk::queries::pages::{
+        parse_query_pages, render_pages_query, PARAM_COLUMNS, PARAM_PROPERTY_KEY,
+        PARAM_PROPERTY_VALUE,
+    };
+    use crate::looksyk::query::{QueryDisplayType, QueryType};
+    use crate::looksyk::renderer::title::builder::world_journal_title_calculator_metadata;
+    use crate::state::userpage::builder::user_page_index_with;
+    use crate::state::userpage::UserPageIndex;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_query() {
+        let result = parse_query_pages(
+            "pages propertyKey:\"type\" propertyValue:\"project\" columns:\"status,owner\" display:\"table\"",
+        )
+        .unwrap();
+
+        assert_eq!(result.query_type, QueryType::Pages);
+        assert_eq!(result.display, QueryDisplayType::Table);
+        assert_eq!(result.get_arg(PARAM_PROPERTY_KEY).unwrap(), "type");
+        assert_eq!(result.get_arg(PARAM_PROPERTY_VALUE).unwrap(), "project");
+        assert_eq!(result.get_arg(PARAM_COLUMNS).unwrap(), "status,owner");
+    }
+
+    #[test]
+    fn test_render_as_list_should_filter_by_value() {
+        let query = parse_query_pages(
+            "pages propertyKey:\"type\" propertyValue:\"project\" display:\"inplace-list\"",
+        )
+        .unwrap();
+
+        let result = render_pages_query(
+            query,
+            &pages(),
+            &empty_journal_index(),
+            &create_page_properties_index(&empty_journal_index(), &pages()),
+            &PropertySchema::default(),
+            &world_journal_title_calculator_metadata(),
+        );
+
+        assert_eq!(
+            result.inplace_markdown,
+            "Pages with property type:\n\n* [alpha](page/alpha)\n"
+        );
+    }
+
+    #[test]
+    fn test_render_as_table_should_show_columns() {
+        let query = parse_query_pages(
+            "pages propertyKey:\"type\" columns:\"type, status\" display:\"table\"",
+        )
+        .unwrap();
+
+        let result = render_pages_query(
+            query,
+            &pages(),
+            &empty_journal_index(),
+            &create_page_properties_index(&empty_journal_index(), &pages()),
+            &PropertySchema::default(),
+            &world_journal_title_calculator_metadata(),
+        );
+
+        assert_eq!(
+            result.inplace_markdown,
+            "| page | type | status |\n| :-- | :-- | :-- |\n| [alpha](page/alpha) | project | active |\n| [beta](page/beta) | person |  |\n"
+        );
+    }
+
+    fn pages() -> UserPageIndex {
+        let mut entries = HashMap::new();
+        entries.insert(
+            page_name_str("alpha"),
+            ParsedMarkdownFile::from_blocks(vec![ParsedBlock {
+                indentation: 0,
+                content: vec![],
+                properties: BlockProperties {
+                    properties: vec![property("type", "project"), property("status", "active")],
+                },
+            }]),
+        );
+        entries.insert(
+            page_name_str("beta"),
+            ParsedMarkdownFile::from_blocks(vec![block_with_property("type", "person")]),
+        );
+        user_page_index_with(entries)
+    }
+
+    fn property(key: &str, value: &str) -> BlockProperty {
+        BlockProperty {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
@@ -21,6 +21,10 @@ pub const PARAM_STARTING_AT: &str = "startingAt";
 
 pub const PARAM_ENDING_AT: &str = "endingAt";
 
+pub const PARAM_AGGREGATION: &str = "aggregation";
+
+pub const PARAM_CUMULATIVE: &str = "cumulative";
+
 pub fn parse_query_plot_property(query_str: &str) -> Result<Query, Error> {
     let query_content = query_str
         .strip_prefix(QUERY_NAME_PLOT_PROPERTY)
@@ -34,6 +38,7 @@ pub fn parse_query_plot_property(query_str: &str) -> Result<Query, Error> {
         .next(PARAM_HEIGHT)?
         .next(PARAM_STARTING_AT)?
         .next(PARAM_ENDING_AT)?
+        .next_optional(&[PARAM_AGGREGATION, PARAM_CUMULATIVE])?
         .build();
 
     let display_type = parse_display_type(parser_result.remaining_value)?;
@@ -47,17 +52,37 @@ pub fn parse_query_plot_property(query_str: &str) -> Result<Query, Error> {
 
 pub fn render_plot_property_query(query: Query) -> QueryRenderResult {
     match query.display {
-        QueryDisplayType::Linechart => render_plot_property_as_linechart(query),
-        _ => render_display_unknown(query.display, vec![QueryDisplayType::Linechart]),
+        QueryDisplayType::Linechart | QueryDisplayType::Barchart | QueryDisplayType::Areachart => {
+            render_plot_property_as_chart(query)
+        }
+        _ => render_display_unknown(
+            query.display,
+            vec![
+                QueryDisplayType::Linechart,
+                QueryDisplayType::Barchart,
+                QueryDisplayType::Areachart,
+            ],
+        ),
     }
 }
 
-fn render_plot_property_as_linechart(query: Query) -> QueryRenderResult {
-    let extra_validation = ParamValidator::default()
+fn render_plot_property_as_chart(query: Query) -> QueryRenderResult {
+    let mut extra_validation = ParamValidator::default()
         .validate_as_integer(query.get_arg("width").unwrap(), PARAM_WIDTH)
         .validate_as_integer(query.get_arg("height").unwrap(), PARAM_HEIGHT)
         .validate_as_date(query.get_arg("startingAt").unwrap(), PARAM_STARTING_AT)
         .validate_as_date(query.get_arg("endingAt").unwrap(), PARAM_ENDING_AT);
+    if let Some(aggregation) = query.get_arg(PARAM_AGGREGATION) {
+        extra_validation = extra_validation.validate_one_of(
+            aggregation,
+            PARAM_AGGREGATION,
+            &["day", "week", "month"],
+        );
+    }
+    if let Some(cumulative) = query.get_arg(PARAM_CUMULATIVE) {
+        extra_validation =
+            extra_validation.validate_one_of(cumulative, PARAM_CUMULATIVE, &["true", "false"]);
+    }
 
     if extra_validation.has_errors() {
         return QueryRenderResult {
@@ -67,7 +92,7 @@ fn render_plot_property_as_linechart(query: Query) -> QueryRenderResult {
         };
     }
 
-    let img_source = format!(
+    let mut img_source = format!(
         "/api/plot/?propertyKey={}&title={}&width={}&height={}&startingAt={}&endingAt={}",
         query.args.get(PARAM_PROPERTY_KEY).unwrap(),
         query.args.get(PARAM_TITLE).unwrap(),
@@ -76,6 +101,14 @@ fn render_plot_property_as_linechart(query: Query) -> QueryRenderResult {
         query.args.get(PARAM_STARTING_AT).unwrap(),
         query.args.get(PARAM_ENDING_AT).unwrap(),
     );
+    if query.display != QueryDisplayType::Linechart {
+        img_source.push_str(&format!("&chartType={}", query.display));
+    }
+    for param in [PARAM_AGGREGATION, PARAM_CUMULATIVE] {
+        if let Some(value) = query.get_arg(param) {
+            img_source.push_str(&format!("&{param}={value}"));
+        }
+    }
 
     let img_markdown = format!(
         "<img alt=\"{}\" src=\"{}\"/>",
@@ -93,8 +126,8 @@ fn render_plot_property_as_linechart(query: Query) -> QueryRenderResult {
 #[cfg(test)]
 mod tests {
     use crate::looksyk::queries::plot::{
-        render_plot_property_query, PARAM_ENDING_AT, PARAM_HEIGHT, PARAM_PROPERTY_KEY,
-        PARAM_STARTING_AT, PARAM_TITLE, PARAM_WIDTH,
+        render_plot_property_query, PARAM_AGGREGATION, PARAM_CUMULATIVE, PARAM_ENDING_AT,
+        PARAM_HEIGHT, PARAM_PROPERTY_KEY, PARAM_STARTING_AT, PARAM_TITLE, PARAM_WIDTH,
     };
     use crate::looksyk::query::{Query, QueryDisplayType, QueryType};
 
@@ -138,7 +171,7 @@ mod tests {
             result.inplace_markdown,
             "<img alt=\"This is my kanban board\" src=\"/api/plot/?propertyKey=my-board&title=This is my kanban board&width=800&height=600&startingAt=2023-01-01&endingAt=2023-12-31\"/>"
         );
-        assert_eq!(result.has_dynamic_content, false);
+        assert!(!result.has_dynamic_content);
         assert_eq!(result.referenced_markdown.len(), 0);
     }
 
@@ -170,9 +203,72 @@ mod tests {
         });
         assert_eq!(
             result.inplace_markdown,
-            "**Parameter Validation Errors:**\n- Parameter 'width' with value 'invalid_width' is not a valid integer.\n- Parameter 'height' with value 'invalid_height' is not a valid integer.\n- Parameter 'startingAt' with value 'invalid_starting_date' is not a valid date (expected format: YYYY-MM-DD).\n- Parameter 'endingAt' with value 'invalid_ending_date' is not a valid date (expected format: YYYY-MM-DD).\n"
+            "**Parameter Validation Errors:**\n- Parameter 'width' with value 'invalid_width' is not a valid integer.\n- Parameter 'height' with value 'invalid_height' is not a valid integer.\n- Parameter 'startingAt' with value 'invalid_starting_date' is not a valid date (expected format: YYYY-MM-DD, today, -30d, +2w, -6m, -1y or start-of-week/month/year).\n- Parameter 'endingAt' with value 'invalid_ending_date' is not a valid date (expected format: YYYY-MM-DD, today, -30d, +2w, -6m, -1y or start-of-week/month/year).\n"
         );
-        assert_eq!(result.has_dynamic_content, false);
+        assert!(!result.has_dynamic_content);
         assert_eq!(result.referenced_markdown.len(), 0);
     }
+
+    #[test]
+    fn test_parse_query_with_aggregation() {
+        let query = "plot-property propertyKey:\"hours,overtime\" title:\"Hours\" width:\"800\" height:\"600\" startingAt:\"2023-01-01\" endingAt:\"2023-12-31\" aggregation:\"week\" cumulative:\"true\" display:\"barchart\"";
+
+        let result = super::parse_query_plot_property(query).unwrap();
+
+        assert_eq!(result.display, QueryDisplayType::Barchart);
+        assert_eq!(
+            result.args.get(PARAM_PROPERTY_KEY).unwrap(),
+            "hours,overtime"
+        );
+        assert_eq!(result.args.get(PARAM_AGGREGATION).unwrap(), "week");
+        assert_eq!(result.args.get(PARAM_CUMULATIVE).unwrap(), "true");
+    }
+
+    #[test]
+    fn test_render_plot_as_barchart_with_aggregation() {
+        let result = render_plot_property_query(Query {
+            query_type: QueryType::PlotProperty,
+            display: QueryDisplayType::Barchart,
+            args: vec![
+                (PARAM_PROPERTY_KEY, "hours,overtime"),
+                (PARAM_WIDTH, "800"),
+                (PARAM_HEIGHT, "600"),
+                (PARAM_TITLE, "Hours"),
+                (PARAM_STARTING_AT, "2023-01-01"),
+                (PARAM_ENDING_AT, "2023-12-31"),
+                (PARAM_AGGREGATION, "month"),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        });
+        assert_eq!(
+            result.inplace_markdown,
+            "<img alt=\"Hours\" src=\"/api/plot/?propertyKey=hours,overtime&title=Hours&width=800&height=600&startingAt=2023-01-01&endingAt=2023-12-31&chartType=barchart&aggregation=month\"/>"
+        );
+    }
+
+    #[test]
+    fn test_render_plot_with_invalid_aggregation() {
+        let result = render_plot_property_query(Query {
+            query_type: QueryType::PlotProperty,
+            display: QueryDisplayType::Areachart,
+            args: vec![
+                (PARAM_PROPERTY_KEY, "hours"),
+                (PARAM_WIDTH, "800"),
+                (PARAM_HEIGHT, "600"),
+                (PARAM_TITLE, "Hours"),
+                (PARAM_STARTING_AT, "2023-01-01"),
+                (PARAM_ENDING_AT, "2023-12-31"),
+                (PARAM_AGGREGATION, "year"),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        });
+        assert_eq!(
+            result.inplace_markdown,
+            "**Parameter Validation Errors:**\n- Parameter 'aggregation' with value 'year' is not one of: day, week, month.\n"
+        );
+    }
 }
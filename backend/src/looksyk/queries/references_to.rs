@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use std::io::Error;
 
+use crate::looksyk::index::alias::find_references_including_aliases;
 use crate::looksyk::model::{PageId, QueryRenderResult, SimplePageName};
 use crate::looksyk::queries::args::{
     parse_display_type, parse_property, ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX, PARAM_TARGET,
@@ -9,6 +10,7 @@ use crate::looksyk::queries::basic::unknown::render_display_unknown;
 use crate::looksyk::query::{Query, QueryDisplayType, QueryType};
 use crate::looksyk::renderer::atomics::{render_link, render_user_link};
 use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
+use crate::state::block_properties::BlockPropertiesIndex;
 use crate::state::tag::TagIndex;
 
 pub const QUERY_NAME_REFERENCES_TO: &str = "references-to";
@@ -34,23 +36,20 @@ pub fn parse_query_references_to(query_str: &str) -> Result<Query, Error> {
 pub fn render_references_of_query(
     query: Query,
     data: &TagIndex,
+    block_properties_index: &BlockPropertiesIndex,
     journal_title_calculator_metadata: &JournalTitleCalculatorMetadata,
 ) -> QueryRenderResult {
     let target = SimplePageName {
         name: query.args.get(PARAM_TARGET).unwrap().clone(),
     };
 
-    let empty_set: HashSet<PageId> = HashSet::new();
-    let references = data
-        .entries
-        .get(&target.as_user_page())
-        .unwrap_or(&empty_set);
+    let references = find_references_including_aliases(&target, data, block_properties_index);
 
     match query.display {
         QueryDisplayType::InplaceList => {
-            render_as_list(&target, references, journal_title_calculator_metadata)
+            render_as_list(&target, &references, journal_title_calculator_metadata)
         }
-        QueryDisplayType::Count => render_as_count(references),
+        QueryDisplayType::Count => render_as_count(&references),
         _ => render_display_unknown(
             query.display,
             vec![QueryDisplayType::InplaceList, QueryDisplayType::Count],
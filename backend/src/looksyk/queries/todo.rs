@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::io::Error;
 
 use crate::looksyk::builder::page_name;
+use crate::looksyk::index::alias::all_names_of;
 use crate::looksyk::model::{QueryRenderResult, ReferencedMarkdown};
 use crate::looksyk::queries::args::{
     parse_display_type, parse_property, ERROR_CAN_NOT_STRIP_QUERY_NAME_PREFIX, PARAM_STATE,
@@ -12,6 +13,7 @@ use crate::looksyk::query::{Query, QueryDisplayType, QueryType};
 use crate::looksyk::renderer::atomics::render_link;
 use crate::looksyk::renderer::renderer_flat::render_block_flat_as_string;
 use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
+use crate::state::block_properties::BlockPropertiesIndex;
 use crate::state::todo::{TodoIndex, TodoIndexEntry, TodoState};
 
 pub const QUERY_NAME_TODOS: &str = "todos";
@@ -39,16 +41,21 @@ pub fn parse_query_todo(query_str: &str) -> Result<Query, Error> {
 pub fn render_todo_query(
     query: Query,
     data: &TodoIndex,
+    block_properties_index: &BlockPropertiesIndex,
     journal_title_calculator_metadata: &JournalTitleCalculatorMetadata,
 ) -> QueryRenderResult {
     let expected_tag = query.args.get(PARAM_TAG).unwrap();
-    let expected_tag_page = page_name(expected_tag.clone());
+    let expected_tag_pages = all_names_of(&page_name(expected_tag.clone()), block_properties_index);
     let expected_state = state_from_string(query.args.get(PARAM_STATE).unwrap());
 
     let mut result = vec![];
 
     for todo in &data.entries {
-        if todo.state == expected_state && todo.tags.contains(&expected_tag_page) {
+        if todo.state == expected_state
+            && expected_tag_pages
+                .iter()
+                .any(|expected_tag_page| todo.tags.contains(expected_tag_page))
+        {
             result.push(todo);
         }
     }
@@ -84,6 +91,7 @@ fn render_as_references(selected_todos: Vec<&TodoIndexEntry>) -> QueryRenderResu
             .map(|x| ReferencedMarkdown {
                 content: x.block.clone(),
                 reference: x.source.page_id.block_reference(x.source.block_number),
+                render_deep: false,
             })
             .collect(),
     }
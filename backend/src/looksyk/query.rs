@@ -3,8 +3,17 @@ use std::fmt::{Display, Formatter};
 use std::io::Error;
 
 use crate::looksyk::model::{BlockToken, QueryRenderResult};
+use crate::looksyk::queries::aggregate::{
+    parse_query_aggregate, render_aggregate_query, QUERY_NAME_AGGREGATE,
+};
 use crate::looksyk::queries::available::available_query_types;
 use crate::looksyk::queries::blocks::{parse_query_blocks, render_blocks_query, QUERY_NAME_BLOCKS};
+use crate::looksyk::queries::embed_block::{
+    parse_query_embed_block, render_embed_block_query, QUERY_NAME_EMBED_BLOCK,
+};
+use crate::looksyk::queries::heatmap::{
+    parse_query_heatmap, render_heatmap_query, QUERY_NAME_HEATMAP,
+};
 use crate::looksyk::queries::insert_file_content::{
     parse_query_insert_file_content, render_query_insert_file_content,
     QUERY_NAME_INSERT_FILE_CONTENT,
@@ -13,6 +22,7 @@ use crate::looksyk::queries::kanban::{parse_query_board, render_board_query, QUE
 use crate::looksyk::queries::pagehierarchy::{
     parse_query_page_hierarchy, render_page_hierarchy, QUERY_NAME_PAGE_HIERARCHY,
 };
+use crate::looksyk::queries::pages::{parse_query_pages, render_pages_query, QUERY_NAME_PAGES};
 use crate::looksyk::queries::plot::{
     parse_query_plot_property, render_plot_property_query, QUERY_NAME_PLOT_PROPERTY,
 };
@@ -72,6 +82,14 @@ pub fn parse_query(payload: &str) -> Result<Query, Error> {
         return parse_query_board(query_str);
     } else if query_str.starts_with(QUERY_NAME_PLOT_PROPERTY) {
         return parse_query_plot_property(query_str);
+    } else if query_str.starts_with(QUERY_NAME_EMBED_BLOCK) {
+        return parse_query_embed_block(query_str);
+    } else if query_str.starts_with(QUERY_NAME_PAGES) {
+        return parse_query_pages(query_str);
+    } else if query_str.starts_with(QUERY_NAME_AGGREGATE) {
+        return parse_query_aggregate(query_str);
+    } else if query_str.starts_with(QUERY_NAME_HEATMAP) {
+        return parse_query_heatmap(query_str);
     }
     Ok(Query::unknown())
 }
@@ -88,11 +106,13 @@ pub fn render_parsed_query(
         QueryType::Todo => render_todo_query(
             query,
             render_context.todo_index,
+            render_context.block_properties_index,
             journal_title_calculator_metadata,
         ),
         QueryType::ReferencesTo => render_references_of_query(
             query,
             render_context.tag_index,
+            render_context.block_properties_index,
             journal_title_calculator_metadata,
         ),
         QueryType::InsertFileContent => {
@@ -101,6 +121,7 @@ pub fn render_parsed_query(
         QueryType::Blocks => render_blocks_query(
             query,
             render_context.tag_index,
+            render_context.block_properties_index,
             render_context.user_pages,
             render_context.journal_pages,
             journal_title_calculator_metadata,
@@ -108,6 +129,29 @@ pub fn render_parsed_query(
         QueryType::PlotProperty => render_plot_property_query(query),
         QueryType::TodoProgress => render_todo_query_progress(query, render_context.todo_index),
         QueryType::Board => render_board_query(query),
+        QueryType::EmbedBlock => render_embed_block_query(query, render_context.user_pages),
+        QueryType::Pages => render_pages_query(
+            query,
+            render_context.user_pages,
+            render_context.journal_pages,
+            render_context.page_properties_index,
+            render_context.property_schema,
+            journal_title_calculator_metadata,
+        ),
+        QueryType::Aggregate => render_aggregate_query(
+            query,
+            render_context.block_properties_index,
+            render_context.user_pages,
+            render_context.journal_pages,
+            journal_title_calculator_metadata,
+        ),
+        QueryType::Heatmap => render_heatmap_query(
+            query,
+            render_context.tag_index,
+            render_context.block_properties_index,
+            render_context.journal_pages,
+            journal_title_calculator_metadata,
+        ),
         QueryType::Unknown => QueryRenderResult {
             inplace_markdown: format!(
                 "Query type unknown. Allowed types: {}",
@@ -150,6 +194,10 @@ pub enum QueryType {
     Board,
     Unknown,
     PlotProperty,
+    EmbedBlock,
+    Pages,
+    Aggregate,
+    Heatmap,
 }
 
 #[derive(PartialEq, Debug)]
@@ -165,6 +213,10 @@ pub enum QueryDisplayType {
     Audio,
     Count,
     Linechart,
+    Barchart,
+    Areachart,
+    Table,
+    Heatmap,
     Unknown,
 }
 
@@ -183,6 +235,10 @@ impl Display for QueryDisplayType {
             QueryDisplayType::Audio => write!(f, "audio"),
             QueryDisplayType::Cards => write!(f, "cards"),
             QueryDisplayType::Linechart => write!(f, "linechart"),
+            QueryDisplayType::Barchart => write!(f, "barchart"),
+            QueryDisplayType::Areachart => write!(f, "areachart"),
+            QueryDisplayType::Table => write!(f, "table"),
+            QueryDisplayType::Heatmap => write!(f, "heatmap"),
         }
     }
 }
@@ -312,7 +368,7 @@ mod tests {
     }
 
     fn empty_file() -> ParsedMarkdownFile {
-        ParsedMarkdownFile { blocks: vec![] }
+        ParsedMarkdownFile::empty()
     }
 
     #[test]
@@ -697,7 +753,7 @@ mod tests {
 
         assert_eq!(result.inplace_markdown, "<label>\nbernd -Todos : 0/0 done (100%)\n <progress value=\"100\" max=\"100\"></progress>\n</label>");
         assert_eq!(result.referenced_markdown.len(), 0);
-        assert_eq!(result.has_dynamic_content, true);
+        assert!(result.has_dynamic_content);
     }
 
     #[test]
@@ -720,7 +776,7 @@ mod tests {
 
         assert_eq!(result.inplace_markdown, "<label>\nbernd -Todos : 2/3 done (67%)\n <progress value=\"67\" max=\"100\"></progress>\n</label>");
         assert_eq!(result.referenced_markdown.len(), 0);
-        assert_eq!(result.has_dynamic_content, true);
+        assert!(result.has_dynamic_content);
     }
 
     #[test]
@@ -1,6 +1,7 @@
 use std::str::Lines;
 
 use crate::looksyk::model::{RawBlock, RawMarkdownFile};
+use crate::looksyk::parser::{is_closing_code_fence, is_code_fence};
 
 const INDENTATION: char = '\t';
 const BULLET: char = '-';
@@ -27,8 +28,18 @@ pub fn parse_lines(lines: Lines) -> Vec<RawBlock> {
     let mut result = vec![];
     let mut current_block = vec![];
     let mut indentation = 0;
+    let mut in_code_fence = false;
     for line in lines {
         let mut rest_of_line = line.trim_start_matches(INDENTATION);
+        // Like the end of a list item, a bullet at the block's indentation closes an open fence
+        let ends_block =
+            rest_of_line.starts_with(BULLET) && calculate_indentation(line) <= indentation;
+        if in_code_fence && !ends_block {
+            let rest_of_line = strip_indentation(line, indentation + 1);
+            in_code_fence = !is_closing_code_fence(rest_of_line);
+            current_block.push(rest_of_line.to_string());
+            continue;
+        }
 
         if rest_of_line.starts_with(BULLET) {
             if !current_block.is_empty() {
@@ -41,6 +52,7 @@ pub fn parse_lines(lines: Lines) -> Vec<RawBlock> {
             indentation = calculate_indentation(line);
             rest_of_line = rest_of_line.strip_prefix(BULLET).unwrap().trim_start();
         }
+        in_code_fence = is_code_fence(rest_of_line);
         current_block.push(rest_of_line.to_string());
     }
     if !current_block.is_empty() {
@@ -52,6 +64,17 @@ pub fn parse_lines(lines: Lines) -> Vec<RawBlock> {
     result
 }
 
+fn strip_indentation(line: &str, indentation: usize) -> &str {
+    let mut rest_of_line = line;
+    for _ in 0..indentation {
+        match rest_of_line.strip_prefix(INDENTATION) {
+            Some(stripped) => rest_of_line = stripped,
+            None => break,
+        }
+    }
+    rest_of_line
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,4 +112,39 @@ mod tests {
         let block = result.blocks.first().unwrap();
         assert_eq!(block.text_content, vec!["line1", "line2"]);
     }
+
+    #[test]
+    fn should_keep_lines_in_code_fence_in_block() {
+        let result = read_file_contents(
+            "\t- code\n\t\t```yaml\n\t\t- item\n\t\t\tnested\n\t\t```\n\t- next",
+        );
+
+        assert_eq!(result.blocks.len(), 2);
+        assert_eq!(
+            result.blocks[0].text_content,
+            vec!["code", "```yaml", "- item", "\tnested", "```"]
+        );
+        assert_eq!(result.blocks[1].text_content, vec!["next"]);
+    }
+
+    #[test]
+    fn should_close_unclosed_code_fence_at_next_block() {
+        let result = read_file_contents("\t- ```\n\t\t- inside\n\t- todo [[link]]\n- top");
+
+        assert_eq!(result.blocks.len(), 3);
+        assert_eq!(result.blocks[0].text_content, vec!["```", "- inside"]);
+        assert_eq!(result.blocks[1].text_content, vec!["todo [[link]]"]);
+        assert_eq!(result.blocks[2].text_content, vec!["top"]);
+    }
+
+    #[test]
+    fn should_not_close_code_fence_with_info_string() {
+        let result = read_file_contents("- ```\n\t```rust\n\tcode\n\t```\n- next");
+
+        assert_eq!(result.blocks.len(), 2);
+        assert_eq!(
+            result.blocks[0].text_content,
+            vec!["```", "```rust", "code", "```"]
+        );
+    }
 }
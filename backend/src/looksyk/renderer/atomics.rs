@@ -22,8 +22,12 @@ pub fn render_link(
 }
 
 pub fn render_user_link(destination: &SimplePageName) -> String {
+    render_user_link_with_label(destination, destination)
+}
+
+pub fn render_user_link_with_label(label: &SimplePageName, destination: &SimplePageName) -> String {
     markdown_link(
-        &decode_destination(&destination.name),
+        &decode_destination(&label.name),
         &user_page_path(destination),
     )
 }
@@ -139,6 +143,7 @@ mod tests {
                 page_id: user_page_id("Test/Page"),
             },
             content: ParsedBlock::text_block_on_disk("Content"),
+            render_deep: false,
         };
 
         let serialized =
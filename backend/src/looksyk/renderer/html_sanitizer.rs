@@ -0,0 +1,255 @@
+const ALLOWED_TAGS: [&str; 38] = [
+    "a",
+    "b",
+    "blockquote",
+    "br",
+    "code",
+    "del",
+    "details",
+    "div",
+    "em",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "i",
+    "img",
+    "input",
+    "kbd",
+    "li",
+    "mark",
+    "ol",
+    "p",
+    "pre",
+    "s",
+    "span",
+    "strong",
+    "sub",
+    "summary",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "th",
+    "thead",
+    "tr",
+    "ul",
+];
+
+const ALLOWED_ATTRIBUTES: [&str; 12] = [
+    "alt", "checked", "class", "colspan", "disabled", "height", "href", "rowspan", "src", "title",
+    "type", "width",
+];
+
+const URL_ATTRIBUTES: [&str; 2] = ["href", "src"];
+
+const ALLOWED_URL_SCHEMES: [&str; 3] = ["http", "https", "mailto"];
+
+const TAGS_WITH_DROPPED_CONTENT: [&str; 4] = ["script", "style", "iframe", "object"];
+
+pub fn sanitize_html(html: &str) -> String {
+    let mut result = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        result.push_str(&escape_text(&rest[..start]));
+        let candidate = &rest[start..];
+        let Some(end) = candidate.find('>') else {
+            result.push_str("&lt;");
+            rest = &candidate[1..];
+            continue;
+        };
+        rest = &candidate[end + 1..];
+
+        let Some(tag) = parse_tag(&candidate[1..end]) else {
+            continue;
+        };
+        if !tag.closing && TAGS_WITH_DROPPED_CONTENT.contains(&tag.name.as_str()) {
+            rest = skip_until_closing_tag(rest, &tag.name);
+            continue;
+        }
+        if ALLOWED_TAGS.contains(&tag.name.as_str()) {
+            result.push_str(&render_tag(&tag));
+        }
+    }
+    result.push_str(&escape_text(rest));
+    result
+}
+
+pub fn is_safe_url(url: &str) -> bool {
+    let url = url.trim().to_lowercase();
+    let scheme_end = url.find(':');
+    let path_start = url.find(['/', '?', '#']);
+    match (scheme_end, path_start) {
+        (Some(scheme_end), Some(path_start)) if path_start < scheme_end => true,
+        (Some(scheme_end), _) => ALLOWED_URL_SCHEMES.contains(&&url[..scheme_end]),
+        (None, _) => true,
+    }
+}
+
+pub fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('<', "&lt;").replace('>', "&gt;")
+}
+
+struct HtmlTag {
+    name: String,
+    closing: bool,
+    attributes: Vec<(String, Option<String>)>,
+}
+
+fn parse_tag(content: &str) -> Option<HtmlTag> {
+    let (closing, content) = match content.strip_prefix('/') {
+        Some(content) => (true, content),
+        None => (false, content),
+    };
+    let name_end = content
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(content.len());
+    if name_end == 0 {
+        return None;
+    }
+    let name = content[..name_end].to_lowercase();
+
+    let mut attributes = vec![];
+    let mut rest = content[name_end..].trim_start_matches(['/', ' ', '\t', '\n']);
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_lowercase();
+        rest = rest[key_end..].trim_start();
+
+        let mut value = None;
+        if let Some(after_equals) = rest.strip_prefix('=') {
+            let after_equals = after_equals.trim_start();
+            let (parsed, remaining) = match after_equals.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let inner = &after_equals[1..];
+                    let value_end = inner.find(quote).unwrap_or(inner.len());
+                    (
+                        &inner[..value_end],
+                        inner.get(value_end + 1..).unwrap_or(""),
+                    )
+                }
+                _ => {
+                    let value_end = after_equals
+                        .find(char::is_whitespace)
+                        .unwrap_or(after_equals.len());
+                    (&after_equals[..value_end], &after_equals[value_end..])
+                }
+            };
+            value = Some(parsed.to_string());
+            rest = remaining;
+        }
+        if !key.is_empty() {
+            attributes.push((key, value));
+        }
+        rest = rest.trim_start_matches(['/', ' ', '\t', '\n']);
+    }
+
+    Some(HtmlTag {
+        name,
+        closing,
+        attributes,
+    })
+}
+
+fn render_tag(tag: &HtmlTag) -> String {
+    if tag.closing {
+        return format!("</{}>", tag.name);
+    }
+    let mut result = format!("<{}", tag.name);
+    for (key, value) in &tag.attributes {
+        if !ALLOWED_ATTRIBUTES.contains(&key.as_str()) {
+            continue;
+        }
+        match value {
+            Some(value) => {
+                if URL_ATTRIBUTES.contains(&key.as_str()) && !is_safe_url(value) {
+                    continue;
+                }
+                result.push_str(&format!(" {key}=\"{}\"", escape_attribute(value)));
+            }
+            None => result.push_str(&format!(" {key}")),
+        }
+    }
+    result.push('>');
+    result
+}
+
+fn skip_until_closing_tag<'a>(html: &'a str, tag_name: &str) -> &'a str {
+    let closing_tag = format!("</{tag_name}");
+    let Some(start) = html.char_indices().map(|(index, _)| index).find(|index| {
+        html.as_bytes()[*index..]
+            .get(..closing_tag.len())
+            .is_some_and(|candidate| candidate.eq_ignore_ascii_case(closing_tag.as_bytes()))
+    }) else {
+        return "";
+    };
+    let rest = &html[start..];
+    match rest.find('>') {
+        Some(end) => &rest[end + 1..],
+        None => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_html_should_keep_allowed_tags_and_attributes() {
+        assert_eq!(
+            sanitize_html("<code class=\"inline-property\">key:: value</code>"),
+            "<code class=\"inline-property\">key:: value</code>"
+        );
+        assert_eq!(
+            sanitize_html("<img src='image.png' onerror='alert(1)'>"),
+            "<img src=\"image.png\">"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_html_should_drop_scripts_and_unknown_tags() {
+        assert_eq!(
+            sanitize_html("a<script>alert('x')</script>b<iframe src=x></iframe>c<custom>d"),
+            "abcd"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_html_should_drop_tags_with_non_ascii_content() {
+        assert_eq!(
+            sanitize_html(&format!("<script>{}</SCRIPT>ééé", "İ".repeat(10))),
+            "ééé"
+        );
+        assert_eq!(sanitize_html("<style>\u{212A}</style>ok"), "ok");
+    }
+
+    #[test]
+    fn test_sanitize_html_should_drop_unsafe_urls() {
+        assert_eq!(
+            sanitize_html("<a href=\"javascript:alert(1)\" class=x>link</a>"),
+            "<a class=\"x\">link</a>"
+        );
+    }
+
+    #[test]
+    fn test_is_safe_url() {
+        assert!(is_safe_url("page/Some%2FPage"));
+        assert!(is_safe_url("/assets/file.png?size=1:2"));
+        assert!(is_safe_url("https://example.com"));
+        assert!(!is_safe_url(" JavaScript:alert(1)"));
+        assert!(!is_safe_url("data:text/html;base64,abc"));
+    }
+}
@@ -1,6 +1,8 @@
 pub mod atomics;
+pub mod html_sanitizer;
 pub mod model;
 pub mod renderer_basic_markdown;
 pub mod renderer_deep;
 pub mod renderer_flat;
+pub mod renderer_html;
 pub mod title;
@@ -1,5 +1,8 @@
 use crate::looksyk::model::ReferencedMarkdown;
+use crate::looksyk::property_schema::PropertySchema;
+use crate::state::block_properties::BlockPropertiesIndex;
 use crate::state::journal::JournalPageIndex;
+use crate::state::page_properties::PagePropertiesIndex;
 use crate::state::tag::TagIndex;
 use crate::state::todo::TodoIndex;
 use crate::state::userpage::UserPageIndex;
@@ -10,18 +13,29 @@ pub struct RenderResult {
     pub has_dynamic_content: bool,
 }
 
+pub enum RenderFormat {
+    Markdown,
+    Html,
+}
+
 pub struct StaticRenderContext<'a> {
     pub user_pages: &'a UserPageIndex,
     pub journal_pages: &'a JournalPageIndex,
     pub todo_index: &'a TodoIndex,
     pub tag_index: &'a TagIndex,
+    pub block_properties_index: &'a BlockPropertiesIndex,
+    pub page_properties_index: &'a PagePropertiesIndex,
+    pub property_schema: &'a PropertySchema,
 }
 
 #[cfg(test)]
 pub mod builder {
     use crate::looksyk::builder::test_builder::empty_journal_index;
+    use crate::looksyk::property_schema::PropertySchema;
     use crate::looksyk::renderer::model::StaticRenderContext;
+    use crate::state::block_properties::BlockPropertiesIndex;
     use crate::state::journal::JournalPageIndex;
+    use crate::state::page_properties::PagePropertiesIndex;
     use crate::state::tag::builder::empty_tag_index;
     use crate::state::tag::TagIndex;
     use crate::state::todo::builder::empty_todo_index;
@@ -34,6 +48,9 @@ pub mod builder {
         pub journal_pages: JournalPageIndex,
         pub todo_index: TodoIndex,
         pub tag_index: TagIndex,
+        pub block_properties_index: BlockPropertiesIndex,
+        pub page_properties_index: PagePropertiesIndex,
+        pub property_schema: PropertySchema,
     }
 
     impl TestRenderContext {
@@ -43,6 +60,9 @@ pub mod builder {
                 todo_index: &self.todo_index,
                 tag_index: &self.tag_index,
                 journal_pages: &self.journal_pages,
+                block_properties_index: &self.block_properties_index,
+                page_properties_index: &self.page_properties_index,
+                property_schema: &self.property_schema,
             }
         }
     }
@@ -55,6 +75,9 @@ pub mod builder {
             journal_pages: empty_journal_index(),
             todo_index: empty_todo_index(),
             tag_index: empty_tag_index(),
+            block_properties_index: BlockPropertiesIndex::default(),
+            page_properties_index: PagePropertiesIndex::default(),
+            property_schema: PropertySchema::default(),
         }
     }
 
@@ -64,6 +87,9 @@ pub mod builder {
             journal_pages: empty_journal_index(),
             todo_index,
             tag_index: empty_tag_index(),
+            block_properties_index: BlockPropertiesIndex::default(),
+            page_properties_index: PagePropertiesIndex::default(),
+            property_schema: PropertySchema::default(),
         }
     }
 
@@ -73,6 +99,9 @@ pub mod builder {
             journal_pages: empty_journal_index(),
             todo_index: empty_todo_index(),
             tag_index,
+            block_properties_index: BlockPropertiesIndex::default(),
+            page_properties_index: PagePropertiesIndex::default(),
+            property_schema: PropertySchema::default(),
         }
     }
 
@@ -86,6 +115,9 @@ pub mod builder {
             journal_pages: empty_journal_index(),
             todo_index,
             tag_index,
+            block_properties_index: BlockPropertiesIndex::default(),
+            page_properties_index: PagePropertiesIndex::default(),
+            property_schema: PropertySchema::default(),
         }
     }
 
@@ -95,6 +127,9 @@ pub mod builder {
             journal_pages: empty_journal_index(),
             todo_index: empty_todo_index(),
             tag_index: empty_tag_index(),
+            block_properties_index: BlockPropertiesIndex::default(),
+            page_properties_index: PagePropertiesIndex::default(),
+            property_schema: PropertySchema::default(),
         }
     }
 }
@@ -5,7 +5,7 @@ use crate::looksyk::model::{
 use crate::looksyk::renderer::atomics::{combine_text_content, render_journal_link};
 use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
 use crate::looksyk::syntax::looksyk_markdown::{
-    render_as_tag_str, render_as_todo_without_padding, render_property,
+    render_as_block_reference, render_as_tag_str, render_as_todo_without_padding, render_property,
 };
 
 pub fn render_file_basic_markdown(
@@ -88,6 +88,9 @@ fn render_tokens_text_only(
             BlockTokenType::Property => {
                 inline_markdown_result_list.push(render_property(token));
             }
+            BlockTokenType::BlockReference => {
+                inline_markdown_result_list.push(render_as_block_reference(&token.payload));
+            }
         }
     }
     inline_markdown_result_list.join(" ")
@@ -103,9 +106,10 @@ mod tests {
 
     #[test]
     fn test_render_file_basic_markdown() {
-        let markdown_file = ParsedMarkdownFile {
-            blocks: vec![ParsedBlock::artificial_text_block("Test content")],
-        };
+        let markdown_file =
+            ParsedMarkdownFile::from_blocks(vec![ParsedBlock::artificial_text_block(
+                "Test content",
+            )]);
         let rendered =
             render_file_basic_markdown(&markdown_file, &world_journal_title_calculator_metadata());
         assert_eq!(rendered.blocks.len(), 1);
@@ -114,9 +118,10 @@ mod tests {
 
     #[test]
     fn test_render_file_basic_markdown_should_preserve_links_as_tag_link() {
-        let markdown_file = ParsedMarkdownFile {
-            blocks: vec![ParsedBlock::from_tokens(vec![link_token("Test link")])],
-        };
+        let markdown_file =
+            ParsedMarkdownFile::from_blocks(vec![ParsedBlock::from_tokens(vec![link_token(
+                "Test link",
+            )])]);
         let rendered =
             render_file_basic_markdown(&markdown_file, &world_journal_title_calculator_metadata());
         assert_eq!(rendered.blocks.len(), 1);
@@ -131,9 +136,7 @@ mod tests {
         let input = block_with_block_property_token("key:: value");
 
         let result = render_file_basic_markdown(
-            &ParsedMarkdownFile {
-                blocks: vec![input],
-            },
+            &ParsedMarkdownFile::from_blocks(vec![input]),
             &world_journal_title_calculator_metadata(),
         );
 
@@ -1,14 +1,21 @@
+use crate::looksyk::index::alias::resolve_alias;
+use crate::looksyk::index::block_id::{find_block, find_block_by_id, BLOCK_ID_PROPERTY};
 use crate::looksyk::model::{
-    BlockToken, BlockTokenType, ParsedBlock, ParsedMarkdownFile, PreparedBlock,
-    PreparedBlockContent, PreparedMarkdownFile, SimplePageName,
+    BlockContent, BlockToken, BlockTokenType, ParsedBlock, ParsedMarkdownFile, PreparedBlock,
+    PreparedBlockContent, PreparedMarkdownFile, PreparedReferencedMarkdown, ReferencedMarkdown,
+    SimplePageName,
 };
 use crate::looksyk::query::render_query;
 use crate::looksyk::renderer::atomics::{
-    render_journal_link, render_user_link, serialize_reference,
+    combine_text_content, render_block_link, render_journal_link, render_user_link_with_label,
+    serialize_reference,
 };
 use crate::looksyk::renderer::model::{RenderResult, StaticRenderContext};
+use crate::looksyk::renderer::renderer_flat::render_tokens_flat;
 use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
-use crate::looksyk::syntax::looksyk_markdown::{render_as_todo_without_padding, render_property};
+use crate::looksyk::syntax::looksyk_markdown::{
+    render_as_block_reference, render_as_todo_without_padding, render_property_with_schema,
+};
 use crate::state::application_state::GraphRootLocation;
 use crate::state::asset_cache::AssetCache;
 
@@ -60,10 +67,20 @@ pub fn render_block(
         }
         block_content_markdown_list.push(render_result.inline_markdown);
         for reference in &render_result.referenced_markdown {
-            references.push(serialize_reference(
-                reference,
-                journal_title_calculator_metadata,
-            ));
+            if reference.render_deep {
+                references.push(render_reference_deep(
+                    reference,
+                    render_context,
+                    asset_cache,
+                    graph_root_location,
+                    journal_title_calculator_metadata,
+                ));
+            } else {
+                references.push(serialize_reference(
+                    reference,
+                    journal_title_calculator_metadata,
+                ));
+            }
         }
     }
 
@@ -78,6 +95,36 @@ pub fn render_block(
     }
 }
 
+// references of nested queries are dropped, so embedded blocks can not embed each other endlessly
+fn render_reference_deep(
+    referenced_markdown: &ReferencedMarkdown,
+    render_context: &StaticRenderContext,
+    asset_cache: &mut AssetCache,
+    graph_root_location: &GraphRootLocation,
+    journal_title_calculator_metadata: &JournalTitleCalculatorMetadata,
+) -> PreparedReferencedMarkdown {
+    let mut prepared_markdown_list = vec![];
+    for content_element in &referenced_markdown.content.content {
+        prepared_markdown_list.push(
+            render_tokens_deep(
+                &content_element.as_tokens,
+                render_context,
+                asset_cache,
+                graph_root_location,
+                journal_title_calculator_metadata,
+            )
+            .inline_markdown,
+        );
+    }
+    PreparedReferencedMarkdown {
+        content: PreparedBlockContent {
+            prepared_markdown: prepared_markdown_list.join("\n"),
+            original_text: combine_text_content(&referenced_markdown.content),
+        },
+        reference: referenced_markdown.reference.clone(),
+    }
+}
+
 pub fn render_tokens_deep(
     tokens: &Vec<BlockToken>,
     render_context: &StaticRenderContext,
@@ -94,9 +141,11 @@ pub fn render_tokens_deep(
                 inline_markdown_result_list.push(token.payload.clone());
             }
             BlockTokenType::Link => {
-                inline_markdown_result_list.push(render_user_link(&SimplePageName {
+                let label = SimplePageName {
                     name: token.payload.clone(),
-                }));
+                };
+                let destination = resolve_alias(&label, render_context.block_properties_index);
+                inline_markdown_result_list.push(render_user_link_with_label(&label, &destination));
             }
             BlockTokenType::JournalLink => {
                 inline_markdown_result_list.push(render_journal_link(
@@ -124,7 +173,17 @@ pub fn render_tokens_deep(
                 inline_markdown_result_list.push(render_as_todo_without_padding(token));
             }
             BlockTokenType::Property => {
-                inline_markdown_result_list.push(render_property(token));
+                inline_markdown_result_list.push(render_property_with_schema(
+                    token,
+                    render_context.property_schema,
+                ));
+            }
+            BlockTokenType::BlockReference => {
+                inline_markdown_result_list.push(render_block_reference(
+                    &token.payload,
+                    render_context,
+                    journal_title_calculator_metadata,
+                ));
             }
         }
     }
@@ -135,17 +194,68 @@ pub fn render_tokens_deep(
     }
 }
 
+fn render_block_reference(
+    block_id: &str,
+    render_context: &StaticRenderContext,
+    journal_title_calculator_metadata: &JournalTitleCalculatorMetadata,
+) -> String {
+    let block_reference = find_block_by_id(block_id, render_context.block_properties_index);
+    let block = block_reference.as_ref().and_then(|block_reference| {
+        find_block(
+            block_reference,
+            render_context.user_pages,
+            render_context.journal_pages,
+        )
+    });
+    let (Some(block_reference), Some(block)) = (block_reference, block) else {
+        return format!("Block {} not found", render_as_block_reference(block_id));
+    };
+
+    let mut result_list = vec![];
+    for content in &block.content {
+        if is_block_id_line(content) {
+            continue;
+        }
+        result_list.push(render_tokens_flat(
+            &content.as_tokens,
+            journal_title_calculator_metadata,
+        ));
+    }
+    result_list.push(render_block_link(&block_reference));
+    result_list.join(" ")
+}
+
+fn is_block_id_line(content: &BlockContent) -> bool {
+    content
+        .as_text
+        .trim()
+        .strip_prefix(BLOCK_ID_PROPERTY)
+        .is_some_and(|rest| rest.starts_with("::"))
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::looksyk::builder::test_builder::user_page_id;
     use crate::looksyk::builder::{journal_link_token, link_token, text_token_str};
+    use crate::looksyk::index::alias::ALIAS_PROPERTY;
     use crate::looksyk::index::asset::create_empty_asset_cache;
+    use crate::looksyk::index::block_id::{with_block_id, BLOCK_ID_PROPERTY};
     use crate::looksyk::model::builder::block_with_block_property_token;
+    use crate::looksyk::model::builder::{block_with_link_content, block_with_text_content};
     use crate::looksyk::model::{BlockContent, BlockToken, BlockTokenType, ParsedBlock};
+    use crate::looksyk::model::{ParsedMarkdownFile, RawBlock};
+    use crate::looksyk::parser::parse_block;
     use crate::looksyk::parser::BlockProperties;
-    use crate::looksyk::renderer::model::builder::create_empty_render_context;
+    use crate::looksyk::renderer::model::builder::{
+        create_empty_render_context, create_render_context_with_user_page_index,
+    };
     use crate::looksyk::renderer::renderer_deep::render_block;
     use crate::looksyk::renderer::title::builder::world_journal_title_calculator_metadata;
     use crate::state::application_state::builder::empty_data_root_location;
+    use crate::state::block_properties::builder::{
+        block_properties_index_with, block_property_key, block_property_occurance,
+    };
+    use crate::state::userpage::builder::user_page_index;
 
     #[test]
     fn should_serialize_original_text() {
@@ -446,4 +556,112 @@ mod tests {
             "<code class=\"inline-property\">key:: value</code>"
         );
     }
+
+    #[test]
+    fn should_render_referenced_block_inline() {
+        let target = with_block_id(&block_with_text_content("quoted text"), "00a1b2c3d4e5f607");
+        let mut render_context = create_render_context_with_user_page_index(user_page_index(
+            "target",
+            ParsedMarkdownFile::from_blocks(vec![target]),
+        ));
+        render_context.block_properties_index = block_properties_index_with(
+            block_property_key(BLOCK_ID_PROPERTY),
+            vec![block_property_occurance(
+                "00a1b2c3d4e5f607",
+                user_page_id("target").block_reference(0),
+            )],
+        );
+
+        let result = render_block(
+            &parse_block(&RawBlock {
+                indentation: 0,
+                text_content: vec!["see ((00a1b2c3d4e5f607))".to_string()],
+            }),
+            &render_context.to_static(),
+            &mut create_empty_asset_cache(),
+            &empty_data_root_location(),
+            &world_journal_title_calculator_metadata(),
+        );
+
+        assert_eq!(
+            result.content.prepared_markdown,
+            "see  quoted text [target:0](page/target) "
+        );
+    }
+
+    #[test]
+    fn should_render_missing_block_reference() {
+        let result = render_block(
+            &parse_block(&RawBlock {
+                indentation: 0,
+                text_content: vec!["((0000000000000000))".to_string()],
+            }),
+            &create_empty_render_context().to_static(),
+            &mut create_empty_asset_cache(),
+            &empty_data_root_location(),
+            &world_journal_title_calculator_metadata(),
+        );
+
+        assert_eq!(
+            result.content.prepared_markdown,
+            "Block ((0000000000000000)) not found "
+        );
+    }
+
+    #[test]
+    fn should_render_embedded_blocks_deep() {
+        let render_context = create_render_context_with_user_page_index(user_page_index(
+            "Runbook",
+            ParsedMarkdownFile::from_blocks(vec![parse_block(&RawBlock {
+                indentation: 0,
+                text_content: vec!["Deploy ((0000000000000000))".to_string()],
+            })]),
+        ));
+
+        let result = render_block(
+            &parse_block(&RawBlock {
+                indentation: 0,
+                text_content: vec![
+                    "{query: embed-block page:\"Runbook\" first-line:\"Deploy ((0000000000000000))\" }"
+                        .to_string(),
+                ],
+            }),
+            &render_context.to_static(),
+            &mut create_empty_asset_cache(),
+            &empty_data_root_location(),
+            &world_journal_title_calculator_metadata(),
+        );
+
+        assert_eq!(result.referenced_markdown.len(), 1);
+        assert_eq!(
+            result.referenced_markdown[0].content.prepared_markdown,
+            "Deploy  Block ((0000000000000000)) not found "
+        );
+        assert_eq!(
+            result.referenced_markdown[0].content.original_text,
+            "Deploy ((0000000000000000))"
+        );
+    }
+
+    #[test]
+    fn should_link_alias_to_canonical_page() {
+        let mut render_context = create_empty_render_context();
+        render_context.block_properties_index = block_properties_index_with(
+            block_property_key(ALIAS_PROPERTY),
+            vec![block_property_occurance(
+                "K8s",
+                user_page_id("Kubernetes").block_reference(0),
+            )],
+        );
+
+        let result = render_block(
+            &block_with_link_content("K8s"),
+            &render_context.to_static(),
+            &mut create_empty_asset_cache(),
+            &empty_data_root_location(),
+            &world_journal_title_calculator_metadata(),
+        );
+
+        assert_eq!(result.content.prepared_markdown, "[K8s](page/Kubernetes)");
+    }
 }
@@ -6,7 +6,9 @@ use crate::looksyk::renderer::atomics::{
     combine_text_content, render_journal_link, render_user_link,
 };
 use crate::looksyk::renderer::title::JournalTitleCalculatorMetadata;
-use crate::looksyk::syntax::looksyk_markdown::{render_as_todo_without_padding, render_property};
+use crate::looksyk::syntax::looksyk_markdown::{
+    render_as_block_reference, render_as_todo_without_padding, render_property,
+};
 
 pub fn render_tokens_flat(
     tokens: &Vec<BlockToken>,
@@ -40,6 +42,9 @@ pub fn render_tokens_flat(
             BlockTokenType::Property => {
                 inline_markdown_result_list.push(render_property(token));
             }
+            BlockTokenType::BlockReference => {
+                inline_markdown_result_list.push(render_as_block_reference(&token.payload));
+            }
         }
     }
     inline_markdown_result_list.join(" ")
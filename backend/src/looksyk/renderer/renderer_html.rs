@@ -0,0 +1,117 @@
+use crate::looksyk::renderer::html_sanitizer::{is_safe_url, sanitize_html};
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag};
+
+const TODO_OPEN_MARKER: &str = "[ ]";
+const TODO_DONE_MARKERS: [&str; 2] = ["[x]", "[X]"];
+
+pub fn render_prepared_markdown_as_html(prepared_markdown: &str) -> String {
+    let markdown = render_todo_marker_as_html(prepared_markdown);
+
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let events = Parser::new_ext(&markdown, options).map(|event| match event {
+        Event::Html(raw) => Event::Html(CowStr::from(sanitize_html(&raw))),
+        Event::InlineHtml(raw) => Event::InlineHtml(CowStr::from(sanitize_html(&raw))),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        other => other,
+    });
+
+    let mut result = String::new();
+    html::push_html(&mut result, events);
+    result
+}
+
+fn safe_url(url: CowStr) -> CowStr {
+    if is_safe_url(&url) {
+        url
+    } else {
+        CowStr::from("")
+    }
+}
+
+fn render_todo_marker_as_html(prepared_markdown: &str) -> String {
+    if let Some(rest) = prepared_markdown.strip_prefix(TODO_OPEN_MARKER) {
+        return format!("<input type=\"checkbox\" class=\"todo\" disabled>{rest}");
+    }
+    for done_marker in TODO_DONE_MARKERS {
+        if let Some(rest) = prepared_markdown.strip_prefix(done_marker) {
+            return format!("<input type=\"checkbox\" class=\"todo\" checked disabled>{rest}");
+        }
+    }
+    prepared_markdown.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_prepared_markdown_as_html_should_render_links() {
+        assert_eq!(
+            render_prepared_markdown_as_html("see [Test/Page](page/Test%2FPage)"),
+            "<p>see <a href=\"page/Test%2FPage\">Test/Page</a></p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_prepared_markdown_as_html_should_render_todos() {
+        assert_eq!(
+            render_prepared_markdown_as_html("[ ] open"),
+            "<p><input type=\"checkbox\" class=\"todo\" disabled> open</p>\n"
+        );
+        assert_eq!(
+            render_prepared_markdown_as_html("[x] done"),
+            "<p><input type=\"checkbox\" class=\"todo\" checked disabled> done</p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_prepared_markdown_as_html_should_keep_properties() {
+        assert_eq!(
+            render_prepared_markdown_as_html("<code class=\"inline-property\">due:: today</code>"),
+            "<p><code class=\"inline-property\">due:: today</code></p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_prepared_markdown_as_html_should_sanitize() {
+        assert_eq!(
+            render_prepared_markdown_as_html(
+                "text <img src=x onerror=alert(1)> [click](javascript:alert(1))"
+            ),
+            "<p>text <img src=\"x\"> <a href=\"\">click</a></p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_prepared_markdown_as_html_should_render_tables() {
+        assert_eq!(
+            render_prepared_markdown_as_html("| a |\n| --- |\n| b |"),
+            "<table><thead><tr><th>a</th></tr></thead><tbody>\n<tr><td>b</td></tr>\n</tbody></table>\n"
+        );
+    }
+}
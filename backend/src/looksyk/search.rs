@@ -1,4 +1,5 @@
-use crate::looksyk::model::{PageType, ParsedMarkdownFile, SimplePageName};
+use crate::looksyk::model::{PageId, PageType, ParsedMarkdownFile, SimplePageName};
+use crate::state::asset_cache::{AssetTextUnit, ExtractedAssetText};
 use crate::state::block::BlockReference;
 use crate::state::journal::JournalPageIndex;
 use crate::state::userpage::UserPageIndex;
@@ -10,6 +11,7 @@ pub struct SearchTerm {
 pub struct SearchResult {
     pub journal: Vec<SearchFinding>,
     pub page: Vec<SearchFinding>,
+    pub asset: Vec<AssetSearchFinding>,
 }
 
 pub struct SearchFinding {
@@ -17,12 +19,33 @@ pub struct SearchFinding {
     pub text_line: String,
 }
 
+pub struct SearchableAsset<'a> {
+    pub file_name: &'a str,
+    pub text: &'a ExtractedAssetText,
+    pub referenced_by: Vec<PageId>,
+}
+
+pub struct AssetSearchFinding {
+    pub file_name: String,
+    pub location: AssetTextLocation,
+    pub text_line: String,
+    pub referenced_by: Vec<PageId>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AssetTextLocation {
+    Line(usize),
+    Page(usize),
+}
+
 pub fn search(
     search_term: SearchTerm,
     journal_page_index: &JournalPageIndex,
     user_page_index: &UserPageIndex,
+    assets: &[SearchableAsset],
 ) -> SearchResult {
     SearchResult {
+        asset: search_in_assets(&search_term, assets),
         page: search_in_index(
             &search_term,
             PageType::UserPage,
@@ -64,10 +87,39 @@ where
     result
 }
 
+fn search_in_assets(
+    search_term: &SearchTerm,
+    assets: &[SearchableAsset],
+) -> Vec<AssetSearchFinding> {
+    let mut result = vec![];
+
+    for asset in assets {
+        for (segment_number, segment) in asset.text.segments.iter().enumerate() {
+            for line in segment.lines() {
+                if line.contains(&search_term.as_string) {
+                    result.push(AssetSearchFinding {
+                        file_name: asset.file_name.to_string(),
+                        location: match asset.text.unit {
+                            AssetTextUnit::Line => AssetTextLocation::Line(segment_number + 1),
+                            AssetTextUnit::Page => AssetTextLocation::Page(segment_number + 1),
+                        },
+                        text_line: line.trim().to_string(),
+                        referenced_by: asset.referenced_by.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::looksyk::builder::test_builder::user_page_id;
     use crate::looksyk::model::{PageType, ParsedBlock, ParsedMarkdownFile, SimplePageName};
-    use crate::looksyk::search::SearchTerm;
+    use crate::looksyk::search::{AssetTextLocation, SearchTerm, SearchableAsset};
+    use crate::state::asset_cache::{AssetTextUnit, ExtractedAssetText};
     use std::collections::HashMap;
 
     #[test]
@@ -77,7 +129,7 @@ mod tests {
         };
 
         let mut pages = HashMap::new();
-        let mut page = ParsedMarkdownFile { blocks: vec![] };
+        let mut page = ParsedMarkdownFile::empty();
         page.blocks
             .push(ParsedBlock::text_block_on_disk("asf search alkj"));
         pages.insert(
@@ -92,4 +144,53 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].text_line, "asf search alkj");
     }
+
+    #[test]
+    fn test_search_in_assets_should_report_line_numbers() {
+        let search_term = SearchTerm {
+            as_string: "needle".to_string(),
+        };
+        let text = ExtractedAssetText {
+            unit: AssetTextUnit::Line,
+            segments: vec!["hay".to_string(), "  a needle  ".to_string()],
+        };
+        let assets = vec![SearchableAsset {
+            file_name: "notes.txt",
+            text: &text,
+            referenced_by: vec![user_page_id("page")],
+        }];
+
+        let result = super::search_in_assets(&search_term, &assets);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].file_name, "notes.txt");
+        assert_eq!(result[0].location, AssetTextLocation::Line(2));
+        assert_eq!(result[0].text_line, "a needle");
+        assert_eq!(result[0].referenced_by, vec![user_page_id("page")]);
+    }
+
+    #[test]
+    fn test_search_in_assets_should_report_pdf_pages() {
+        let search_term = SearchTerm {
+            as_string: "needle".to_string(),
+        };
+        let text = ExtractedAssetText {
+            unit: AssetTextUnit::Page,
+            segments: vec![
+                "first page".to_string(),
+                "second page\nwith a needle".to_string(),
+            ],
+        };
+        let assets = vec![SearchableAsset {
+            file_name: "doc.pdf",
+            text: &text,
+            referenced_by: vec![],
+        }];
+
+        let result = super::search_in_assets(&search_term, &assets);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].location, AssetTextLocation::Page(2));
+        assert_eq!(result[0].text_line, "with a needle");
+    }
 }
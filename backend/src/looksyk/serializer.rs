@@ -1,8 +1,9 @@
 use crate::looksyk::model::{
     BlockToken, BlockTokenType, ParsedBlock, ParsedMarkdownFile, UpdateBlock,
 };
+use crate::looksyk::parser::is_in_code_fence_after;
 use crate::looksyk::syntax::looksyk_markdown::{
-    render_as_query, render_as_tag_str, render_as_todo,
+    render_as_block_reference, render_as_query, render_as_tag_str, render_as_todo,
 };
 
 pub fn update_and_serialize_page(
@@ -15,11 +16,7 @@ pub fn update_and_serialize_page(
             let indentation = block.indentation;
 
             if update_block.markdown.contains("\n") {
-                let mut first = true;
-                for line in update_block.markdown.split("\n") {
-                    result.push(generate_file_line(indentation, first, line));
-                    first = false;
-                }
+                generate_file_lines(&mut result, indentation, &update_block.markdown);
             } else {
                 result.push(format!(
                     "{}- {}",
@@ -44,21 +41,13 @@ pub fn serialize_page(parsed_markdown_file: &ParsedMarkdownFile) -> Vec<String>
 fn serialize_block_content(result: &mut Vec<String>, block: &&ParsedBlock) {
     let mut serialized_block_content = vec![];
     for block_content in &block.content {
-        let mut block_content_token = vec![];
-        for block_token in &block_content.as_tokens {
-            block_content_token.push(serialize_block_token(block_token));
-        }
-        serialized_block_content.push(block_content_token.join(""));
+        serialized_block_content.push(serialize_tokens(&block_content.as_tokens));
     }
     let serialized_block = serialized_block_content.join("\n");
     let indentation = block.indentation;
 
     if serialized_block.contains("\n") {
-        let mut first = true;
-        for line in serialized_block.split("\n") {
-            result.push(generate_file_line(indentation, first, line));
-            first = false;
-        }
+        generate_file_lines(result, indentation, &serialized_block);
     } else {
         result.push(format!(
             "{}- {}",
@@ -68,10 +57,24 @@ fn serialize_block_content(result: &mut Vec<String>, block: &&ParsedBlock) {
     }
 }
 
-fn generate_file_line(indentation: usize, first: bool, line: &str) -> String {
+fn generate_file_lines(result: &mut Vec<String>, indentation: usize, block: &str) {
+    let mut first = true;
+    let mut in_code_fence = false;
+    for line in block.split("\n") {
+        result.push(generate_file_line(indentation, first, in_code_fence, line));
+        in_code_fence = is_in_code_fence_after(in_code_fence, line);
+        first = false;
+    }
+}
+
+fn generate_file_line(indentation: usize, first: bool, in_code_fence: bool, line: &str) -> String {
     if first {
         return format!("{}- {}", generate_indentation(indentation), line);
     }
+    if in_code_fence {
+        // One level deeper than the bullet, so fenced lines starting with a bullet stay in the block
+        return format!("{}{}", generate_indentation(indentation + 1), line);
+    }
     format!(
         "{}{}",
         generate_indentation(indentation),
@@ -91,6 +94,14 @@ fn generate_indentation(depth: usize) -> String {
     result
 }
 
+pub fn serialize_tokens(tokens: &[BlockToken]) -> String {
+    let mut block_content_token = vec![];
+    for block_token in tokens {
+        block_content_token.push(serialize_block_token(block_token));
+    }
+    block_content_token.join("")
+}
+
 fn serialize_block_token(block_token: &BlockToken) -> String {
     match block_token.block_token_type {
         BlockTokenType::Text => block_token.payload.clone(),
@@ -101,6 +112,7 @@ fn serialize_block_token(block_token: &BlockToken) -> String {
         }
         BlockTokenType::Query => render_as_query(block_token),
         BlockTokenType::Todo => render_as_todo(block_token),
+        BlockTokenType::BlockReference => render_as_block_reference(&block_token.payload),
     }
 }
 
@@ -111,8 +123,12 @@ mod tests {
     use crate::looksyk::model::{
         BlockContent, BlockToken, BlockTokenType, ParsedBlock, ParsedMarkdownFile, UpdateBlock,
     };
+    use crate::looksyk::parser::parse_markdown_file;
     use crate::looksyk::parser::BlockProperties;
-    use crate::looksyk::serializer::{serialize_block_token, update_and_serialize_page};
+    use crate::looksyk::reader::read_file_contents;
+    use crate::looksyk::serializer::{
+        serialize_block_token, serialize_page, update_and_serialize_page,
+    };
     use crate::state::block::BlockReference;
 
     #[test]
@@ -169,12 +185,10 @@ mod tests {
                     page_id: any_page_id(),
                 },
             },
-            &ParsedMarkdownFile {
-                blocks: vec![
-                    parsed_text_block("This is Line 1", 0),
-                    parsed_text_block("This is line 2", 0),
-                ],
-            },
+            &ParsedMarkdownFile::from_blocks(vec![
+                parsed_text_block("This is Line 1", 0),
+                parsed_text_block("This is line 2", 0),
+            ]),
         );
 
         assert_eq!(result, vec!["- This is Line 1", "- this is a new content"])
@@ -190,12 +204,10 @@ mod tests {
                     page_id: any_page_id(),
                 },
             },
-            &ParsedMarkdownFile {
-                blocks: vec![
-                    parsed_text_block("This is Line 1", 1),
-                    parsed_text_block("This is line 2\nthis is line 3", 2),
-                ],
-            },
+            &ParsedMarkdownFile::from_blocks(vec![
+                parsed_text_block("This is Line 1", 1),
+                parsed_text_block("This is line 2\nthis is line 3", 2),
+            ]),
         );
 
         assert_eq!(
@@ -219,16 +231,14 @@ mod tests {
                     page_id: any_page_id(),
                 },
             },
-            &ParsedMarkdownFile {
-                blocks: vec![
-                    parsed_text_block("This is Line 1", 1),
-                    ParsedBlock::from_tokens(vec![
-                        todo_block("x"),
-                        text_token_str("mytodo "),
-                        link_block("my link"),
-                    ]),
-                ],
-            },
+            &ParsedMarkdownFile::from_blocks(vec![
+                parsed_text_block("This is Line 1", 1),
+                ParsedBlock::from_tokens(vec![
+                    todo_block("x"),
+                    text_token_str("mytodo "),
+                    link_block("my link"),
+                ]),
+            ]),
         );
 
         assert_eq!(
@@ -247,25 +257,23 @@ mod tests {
                     page_id: any_page_id(),
                 },
             },
-            &ParsedMarkdownFile {
-                blocks: vec![
-                    parsed_text_block("This is Line 1", 1),
-                    ParsedBlock {
-                        indentation: 1,
-                        content: vec![
-                            BlockContent {
-                                as_text: "".to_string(),
-                                as_tokens: vec![text_token_str("my text")],
-                            },
-                            BlockContent {
-                                as_text: "".to_string(),
-                                as_tokens: vec![text_token_str("2my text2")],
-                            },
-                        ],
-                        properties: BlockProperties::empty(),
-                    },
-                ],
-            },
+            &ParsedMarkdownFile::from_blocks(vec![
+                parsed_text_block("This is Line 1", 1),
+                ParsedBlock {
+                    indentation: 1,
+                    content: vec![
+                        BlockContent {
+                            as_text: "".to_string(),
+                            as_tokens: vec![text_token_str("my text")],
+                        },
+                        BlockContent {
+                            as_text: "".to_string(),
+                            as_tokens: vec![text_token_str("2my text2")],
+                        },
+                    ],
+                    properties: BlockProperties::empty(),
+                },
+            ]),
         );
 
         assert_eq!(
@@ -284,25 +292,23 @@ mod tests {
                     page_id: any_page_id(),
                 },
             },
-            &ParsedMarkdownFile {
-                blocks: vec![
-                    parsed_text_block("This is Line 1", 1),
-                    ParsedBlock {
-                        indentation: 1,
-                        content: vec![
-                            BlockContent {
-                                as_text: "".to_string(),
-                                as_tokens: vec![text_token_str("my text")],
-                            },
-                            BlockContent {
-                                as_text: "".to_string(),
-                                as_tokens: vec![text_token_str("-2my text2")],
-                            },
-                        ],
-                        properties: BlockProperties::empty(),
-                    },
-                ],
-            },
+            &ParsedMarkdownFile::from_blocks(vec![
+                parsed_text_block("This is Line 1", 1),
+                ParsedBlock {
+                    indentation: 1,
+                    content: vec![
+                        BlockContent {
+                            as_text: "".to_string(),
+                            as_tokens: vec![text_token_str("my text")],
+                        },
+                        BlockContent {
+                            as_text: "".to_string(),
+                            as_tokens: vec![text_token_str("-2my text2")],
+                        },
+                    ],
+                    properties: BlockProperties::empty(),
+                },
+            ]),
         );
 
         assert_eq!(
@@ -326,33 +332,31 @@ mod tests {
                     page_id: any_page_id(),
                 },
             },
-            &ParsedMarkdownFile {
-                blocks: vec![
-                    parsed_text_block("This is Line 1", 1),
-                    ParsedBlock {
-                        indentation: 0,
-                        content: vec![BlockContent {
+            &ParsedMarkdownFile::from_blocks(vec![
+                parsed_text_block("This is Line 1", 1),
+                ParsedBlock {
+                    indentation: 0,
+                    content: vec![BlockContent {
+                        as_text: "".to_string(),
+                        as_tokens: vec![text_token_str("")],
+                    }],
+                    properties: BlockProperties::empty(),
+                },
+                ParsedBlock {
+                    indentation: 1,
+                    content: vec![
+                        BlockContent {
                             as_text: "".to_string(),
-                            as_tokens: vec![text_token_str("")],
-                        }],
-                        properties: BlockProperties::empty(),
-                    },
-                    ParsedBlock {
-                        indentation: 1,
-                        content: vec![
-                            BlockContent {
-                                as_text: "".to_string(),
-                                as_tokens: vec![text_token_str("my text")],
-                            },
-                            BlockContent {
-                                as_text: "".to_string(),
-                                as_tokens: vec![text_token_str("-2my text2\n\n")],
-                            },
-                        ],
-                        properties: BlockProperties::empty(),
-                    },
-                ],
-            },
+                            as_tokens: vec![text_token_str("my text")],
+                        },
+                        BlockContent {
+                            as_text: "".to_string(),
+                            as_tokens: vec![text_token_str("-2my text2\n\n")],
+                        },
+                    ],
+                    properties: BlockProperties::empty(),
+                },
+            ]),
         );
 
         assert_eq!(
@@ -400,4 +404,14 @@ mod tests {
             payload: text.to_string(),
         }
     }
+
+    #[test]
+    fn should_serialize_code_losslessly() {
+        let file_content = "- see `[[no link]]` and \\[[escaped]]\n\t- ```rust\n\t\tlet x = a::b;\n\t\t- not a block\n\t\t\tindented:: code\n\t\t```";
+
+        let parsed = parse_markdown_file(read_file_contents(file_content));
+
+        assert_eq!(parsed.blocks.len(), 2);
+        assert_eq!(serialize_page(&parsed).join("\n"), file_content);
+    }
 }
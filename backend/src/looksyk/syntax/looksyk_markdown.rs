@@ -1,4 +1,6 @@
 use crate::looksyk::model::{BlockToken, SimplePageName};
+use crate::looksyk::property_schema::{PropertySchema, PropertyValueError};
+use crate::looksyk::renderer::html_sanitizer::escape_attribute;
 use crate::state::block_properties::{BlockPropertyKey, BlockPropertyValue};
 
 pub fn render_as_tag(simple_page_name: &SimplePageName) -> String {
@@ -21,6 +23,10 @@ pub fn render_as_query(token: &BlockToken) -> String {
     format!("{{query: {} }}", token.payload)
 }
 
+pub fn render_as_block_reference(block_id: &str) -> String {
+    format!("(({block_id}))")
+}
+
 pub fn serialize_property(key: &BlockPropertyKey, value: &BlockPropertyValue) -> String {
     format!("{}:: {}", key.value, value.value)
 }
@@ -29,10 +35,33 @@ pub fn render_property(token: &BlockToken) -> String {
     format!("<code class=\"inline-property\">{}</code>", token.payload).to_string()
 }
 
+pub fn render_property_with_schema(token: &BlockToken, schema: &PropertySchema) -> String {
+    match validate_property_token(token, schema) {
+        Err(error) => format!(
+            "<code class=\"inline-property invalid-property\" title=\"{}\">{}</code>",
+            escape_attribute(&error.message),
+            token.payload
+        ),
+        Ok(()) => render_property(token),
+    }
+}
+
+fn validate_property_token(
+    token: &BlockToken,
+    schema: &PropertySchema,
+) -> Result<(), PropertyValueError> {
+    match token.payload.split_once("::") {
+        Some((key, value)) => schema.parse_value(key.trim(), value.trim()).map(|_| ()),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::looksyk::model::{BlockTokenType, SimplePageName};
+    use crate::looksyk::property_schema::builder::property_schema_with;
+    use crate::looksyk::property_schema::PropertyType;
     use crate::state::block_properties::builder::{block_property_key, block_property_value};
 
     #[test]
@@ -106,4 +135,26 @@ mod tests {
 
         assert_eq!(result, "<code class=\"inline-property\">key:: value</code>");
     }
+
+    #[test]
+    fn test_render_property_with_schema_should_flag_invalid_values() {
+        let token = BlockToken {
+            payload: "hours:: 3h".to_string(),
+            block_token_type: BlockTokenType::Property,
+        };
+
+        let result = render_property_with_schema(
+            &token,
+            &property_schema_with("hours", PropertyType::Number),
+        );
+
+        assert_eq!(
+            result,
+            "<code class=\"inline-property invalid-property\" title=\"Value '3h' of property 'hours' is not a valid number\">hours:: 3h</code>"
+        );
+        assert_eq!(
+            render_property_with_schema(&token, &PropertySchema::default()),
+            render_property(&token)
+        );
+    }
 }
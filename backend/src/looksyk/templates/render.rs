@@ -26,14 +26,14 @@ fn append_to_page(
 
     if block_index >= target.blocks.len() {
         // If the block index is greater than the number of blocks, append at the end
-        return ParsedMarkdownFile {
-            blocks: target
+        return ParsedMarkdownFile::from_blocks(
+            target
                 .blocks
                 .iter()
                 .cloned()
                 .chain(template.blocks)
                 .collect(),
-        };
+        );
     }
 
     for block in &target.blocks {
@@ -72,7 +72,7 @@ fn append_to_page(
         iteration_index += 1;
     }
 
-    ParsedMarkdownFile { blocks: result }
+    ParsedMarkdownFile::from_blocks(result)
 }
 
 fn render_template(
@@ -86,9 +86,7 @@ fn render_template(
         rendered_blocks.push(rendered_block);
     }
 
-    ParsedMarkdownFile {
-        blocks: rendered_blocks,
-    }
+    ParsedMarkdownFile::from_blocks(rendered_blocks)
 }
 
 fn render_template_block(
@@ -112,13 +110,11 @@ mod tests {
 
     #[test]
     fn should_append_template_to_page_should_append_last_line() {
-        let target = ParsedMarkdownFile {
-            blocks: vec![block_with_text_content("existing content")],
-        };
+        let target =
+            ParsedMarkdownFile::from_blocks(vec![block_with_text_content("existing content")]);
 
-        let template = ParsedMarkdownFile {
-            blocks: vec![block_with_text_content("template content")],
-        };
+        let template =
+            ParsedMarkdownFile::from_blocks(vec![block_with_text_content("template content")]);
 
         let updated_page = append_template_to_page(0, &template, &target);
 
@@ -139,20 +135,17 @@ mod tests {
 
     #[test]
     fn should_append_template_to_page_should_append_last_line_and_following_lines_in_own_line() {
-        let target = ParsedMarkdownFile {
-            blocks: vec![block_with_text_content("existing content")],
-        };
-
-        let template = ParsedMarkdownFile {
-            blocks: vec![ParsedBlock {
-                indentation: 0,
-                content: vec![
-                    block_content("template content"),
-                    block_content("more template content"),
-                ],
-                properties: BlockProperties::empty(),
-            }],
-        };
+        let target =
+            ParsedMarkdownFile::from_blocks(vec![block_with_text_content("existing content")]);
+
+        let template = ParsedMarkdownFile::from_blocks(vec![ParsedBlock {
+            indentation: 0,
+            content: vec![
+                block_content("template content"),
+                block_content("more template content"),
+            ],
+            properties: BlockProperties::empty(),
+        }]);
 
         let updated_page = append_template_to_page(0, &template, &target);
 
@@ -181,25 +174,21 @@ mod tests {
 
     #[test]
     fn should_adjust_indentation_of_folliwing_blocks() {
-        let target = ParsedMarkdownFile {
-            blocks: vec![ParsedBlock {
-                indentation: 1,
-                content: vec![block_content("existing content")],
-
+        let target = ParsedMarkdownFile::from_blocks(vec![ParsedBlock {
+            indentation: 1,
+            content: vec![block_content("existing content")],
+
+            properties: BlockProperties::empty(),
+        }]);
+
+        let template = ParsedMarkdownFile::from_blocks(vec![
+            ParsedBlock::text_block_on_disk("template content"),
+            ParsedBlock {
+                indentation: 2,
+                content: vec![block_content("template content block 2")],
                 properties: BlockProperties::empty(),
-            }],
-        };
-
-        let template = ParsedMarkdownFile {
-            blocks: vec![
-                ParsedBlock::text_block_on_disk("template content"),
-                ParsedBlock {
-                    indentation: 2,
-                    content: vec![block_content("template content block 2")],
-                    properties: BlockProperties::empty(),
-                },
-            ],
-        };
+            },
+        ]);
         let updated_page = append_template_to_page(0, &template, &target);
 
         assert_eq!(updated_page.blocks.len(), 2);
@@ -0,0 +1,293 @@
+use crate::looksyk::model::{
+    BlockToken, BlockTokenType, PageType, ParsedBlock, ParsedMarkdownFile, SimplePageName,
+};
+use crate::looksyk::parser::{find_code_spans, is_code_fence, is_in_code_fence_after};
+use crate::looksyk::serializer::serialize_tokens;
+use crate::looksyk::syntax::looksyk_markdown::render_as_tag;
+use crate::state::block::BlockReference;
+use crate::state::journal::JournalPageIndex;
+use crate::state::userpage::UserPageIndex;
+
+pub struct UnlinkedReference {
+    pub reference: BlockReference,
+    pub text_line: String,
+    pub matched_text: String,
+    pub linked_markdown: String,
+}
+
+struct Mention {
+    line: usize,
+    token: usize,
+    start: usize,
+    end: usize,
+}
+
+pub fn find_unlinked_references(
+    page_names: &[SimplePageName],
+    user_page_index: &UserPageIndex,
+    journal_page_index: &JournalPageIndex,
+) -> Vec<UnlinkedReference> {
+    let Some(link_target) = page_names.first() else {
+        return vec![];
+    };
+    let mut result = vec![];
+    result.extend(find_in_pages(
+        page_names,
+        link_target,
+        PageType::UserPage,
+        user_page_index.iter_entries(),
+    ));
+    result.extend(find_in_pages(
+        page_names,
+        link_target,
+        PageType::JournalPage,
+        journal_page_index.iter_entries(),
+    ));
+    result
+}
+
+fn find_in_pages<'a, I>(
+    page_names: &[SimplePageName],
+    link_target: &SimplePageName,
+    page_type: PageType,
+    pages: I,
+) -> Vec<UnlinkedReference>
+where
+    I: IntoIterator<Item = (&'a SimplePageName, &'a ParsedMarkdownFile)>,
+{
+    let mut sorted_pages: Vec<_> = pages
+        .into_iter()
+        .filter(|(name, _)| page_type == PageType::JournalPage || !page_names.contains(name))
+        .collect();
+    sorted_pages.sort_by(|a, b| a.0.name.cmp(&b.0.name));
+
+    let mut result = vec![];
+    for (simple_page_name, page) in sorted_pages {
+        for (block_number, block) in page.blocks.iter().enumerate() {
+            for mention in find_mentions_in_block(block, page_names) {
+                let token = &block.content[mention.line].as_tokens[mention.token];
+                result.push(UnlinkedReference {
+                    reference: simple_page_name
+                        .as_page_id(&page_type)
+                        .block_reference(block_number),
+                    text_line: block.content[mention.line].as_text.clone(),
+                    matched_text: token.payload[mention.start..mention.end].to_string(),
+                    linked_markdown: link_mention(block, &mention, link_target),
+                });
+            }
+        }
+    }
+    result
+}
+
+fn find_mentions_in_block(block: &ParsedBlock, page_names: &[SimplePageName]) -> Vec<Mention> {
+    let mut result = vec![];
+    let mut in_code_fence = false;
+    for (line, content) in block.content.iter().enumerate() {
+        if in_code_fence || is_code_fence(&content.as_text) {
+            in_code_fence = is_in_code_fence_after(in_code_fence, &content.as_text);
+            continue;
+        }
+        for (token, block_token) in content.as_tokens.iter().enumerate() {
+            if block_token.block_token_type != BlockTokenType::Text {
+                continue;
+            }
+            for (start, end) in find_mentions_in_text(&block_token.payload, page_names) {
+                result.push(Mention {
+                    line,
+                    token,
+                    start,
+                    end,
+                });
+            }
+        }
+    }
+    result
+}
+
+fn find_mentions_in_text(text: &str, page_names: &[SimplePageName]) -> Vec<(usize, usize)> {
+    let mut mentions: Vec<(usize, usize)> = page_names
+        .iter()
+        .flat_map(|page_name| find_word(text, &page_name.name))
+        .collect();
+    mentions.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+    let code_spans = find_code_spans(text);
+    let mut result: Vec<(usize, usize)> = vec![];
+    for mention in mentions {
+        let in_code_span = code_spans
+            .iter()
+            .any(|(start, end)| mention.0 < *end && *start < mention.1);
+        if !in_code_span && result.last().is_none_or(|last| last.1 <= mention.0) {
+            result.push(mention);
+        }
+    }
+    result
+}
+
+fn find_word(text: &str, word: &str) -> Vec<(usize, usize)> {
+    let text_chars: Vec<(usize, char)> = text.char_indices().collect();
+    let word_chars: Vec<char> = word.chars().collect();
+    let mut result = vec![];
+    if word_chars.is_empty() {
+        return result;
+    }
+
+    let mut index = 0;
+    while index + word_chars.len() <= text_chars.len() {
+        let end = index + word_chars.len();
+        let starts_at_boundary = index == 0 || !is_word_char(text_chars[index - 1].1);
+        let ends_at_boundary = end == text_chars.len() || !is_word_char(text_chars[end].1);
+        let matches = word_chars
+            .iter()
+            .zip(&text_chars[index..end])
+            .all(|(a, (_, b))| a.to_lowercase().eq(b.to_lowercase()));
+
+        if starts_at_boundary && ends_at_boundary && matches {
+            let end_byte = text_chars.get(end).map(|c| c.0).unwrap_or(text.len());
+            result.push((text_chars[index].0, end_byte));
+            index = end;
+        } else {
+            index += 1;
+        }
+    }
+    result
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn link_mention(block: &ParsedBlock, mention: &Mention, link_target: &SimplePageName) -> String {
+    let mut lines = vec![];
+    for (line, content) in block.content.iter().enumerate() {
+        if line != mention.line {
+            lines.push(serialize_tokens(&content.as_tokens));
+            continue;
+        }
+        let mut tokens = content.as_tokens.clone();
+        let payload = &tokens[mention.token].payload;
+        tokens[mention.token] = BlockToken {
+            block_token_type: BlockTokenType::Text,
+            payload: format!(
+                "{}{}{}",
+                &payload[..mention.start],
+                render_as_tag(link_target),
+                &payload[mention.end..]
+            ),
+        };
+        lines.push(serialize_tokens(&tokens));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::looksyk::builder::page_name_str;
+    use crate::looksyk::builder::test_builder::empty_journal_index;
+    use crate::looksyk::model::RawBlock;
+    use crate::looksyk::parser::parse_block;
+    use crate::state::journal::builder::journal_page_index;
+    use crate::state::userpage::builder::user_page_index;
+
+    fn page_with_text(text: &str) -> ParsedMarkdownFile {
+        ParsedMarkdownFile::from_blocks(vec![parse_block(&RawBlock {
+            indentation: 0,
+            text_content: vec![text.to_string()],
+        })])
+    }
+
+    #[test]
+    fn test_find_word_should_match_case_insensitive_on_word_boundaries() {
+        assert_eq!(
+            find_word("Kubernetes and kubernetes-cluster", "kubernetes"),
+            vec![(0, 10), (15, 25)]
+        );
+        assert_eq!(find_word("mykubernetes kubernetes2", "kubernetes"), vec![]);
+        assert_eq!(find_word("Über über", "über"), vec![(0, 5), (6, 11)]);
+    }
+
+    #[test]
+    fn test_find_mentions_in_text_should_prefer_longer_names() {
+        let names = vec![
+            page_name_str("Kubernetes"),
+            page_name_str("Kubernetes Cluster"),
+        ];
+
+        assert_eq!(
+            find_mentions_in_text("a Kubernetes Cluster", &names),
+            vec![(2, 20)]
+        );
+    }
+
+    #[test]
+    fn test_find_unlinked_references_should_ignore_existing_links() {
+        let journal = journal_page_index(
+            "2024_01_01",
+            page_with_text("deployed [[Kubernetes]] and kubernetes again"),
+        );
+
+        let result = find_unlinked_references(
+            &[page_name_str("Kubernetes")],
+            &user_page_index("Kubernetes", page_with_text("kubernetes itself")),
+            &journal,
+        );
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].reference,
+            page_name_str("2024_01_01")
+                .as_journal_page()
+                .block_reference(0)
+        );
+        assert_eq!(result[0].matched_text, "kubernetes");
+        assert_eq!(
+            result[0].linked_markdown,
+            "deployed [[Kubernetes]] and [[Kubernetes]] again"
+        );
+    }
+
+    #[test]
+    fn test_find_unlinked_references_should_offer_one_link_per_occurrence() {
+        let result = find_unlinked_references(
+            &[page_name_str("Rust")],
+            &user_page_index("notes", page_with_text("rust is rust")),
+            &empty_journal_index(),
+        );
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|r| r.linked_markdown.as_str())
+                .collect::<Vec<_>>(),
+            vec!["[[Rust]] is rust", "rust is [[Rust]]"]
+        );
+    }
+
+    #[test]
+    fn test_find_unlinked_references_should_skip_code() {
+        let page = ParsedMarkdownFile::from_blocks(vec![parse_block(&RawBlock {
+            indentation: 0,
+            text_content: vec![
+                "run `rust build` with Rust".to_string(),
+                "```".to_string(),
+                "rust".to_string(),
+                "```".to_string(),
+            ],
+        })]);
+
+        let result = find_unlinked_references(
+            &[page_name_str("Rust")],
+            &user_page_index("notes", page),
+            &empty_journal_index(),
+        );
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].matched_text, "Rust");
+        assert_eq!(
+            result[0].linked_markdown,
+            "run `rust build` with [[Rust]]\n```\nrust\n```"
+        );
+    }
+}
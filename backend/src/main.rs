@@ -3,6 +3,7 @@ use std::str::FromStr;
 
 use self::looksyk::data::config::init::graph::init_graph_if_needed;
 use self::looksyk::data::config::startup_configuration;
+use crate::io::cli::check::check_graph_health;
 use crate::io::cli::endpoints::get_cli_args;
 use crate::io::fs::basic_folder::config_directory;
 use crate::io::fs::env;
@@ -21,7 +22,7 @@ use crate::io::http::page::userpage;
 use crate::io::http::page::{journalpage, templates};
 use crate::io::http::r#static;
 use crate::io::http::{block_properties, plot};
-use crate::io::http::{design, kanban};
+use crate::io::http::{design, encryption, kanban, link_graph};
 use crate::io::state::convert_to_app_state;
 use crate::looksyk::data::graph::load_graph_data;
 use actix_web::middleware::Logger;
@@ -66,6 +67,11 @@ async fn main() -> std::io::Result<()> {
         })
     });
 
+    if config.check_graph {
+        let healthy = check_graph_health(&graph_root_location);
+        std::process::exit(if healthy { 0 } else { 1 });
+    }
+
     init_graph_if_needed(&graph_root_location);
 
     let current_application_version = get_current_application_version();
@@ -110,8 +116,10 @@ async fn main() -> std::io::Result<()> {
 
     let git_config = Data::new(load_git_config(&graph_root_location));
 
-    let app_state =
-        convert_to_app_state(load_graph_data(&graph_root_location), &config.static_path);
+    let app_state = convert_to_app_state(
+        load_graph_data(&graph_root_location, None),
+        &config.static_path,
+    );
 
     let changes_state = Data::new(GraphChangesState::default());
 
@@ -130,13 +138,19 @@ async fn main() -> std::io::Result<()> {
             .app_data(changes_state.clone())
             .service(markdown::endpoints::parse)
             .service(page::endpoints::update_block)
+            .service(page::endpoints::create_block_id)
+            .service(page::endpoints::resolve_block_id)
             .service(userpage::endpoints::get_overview_page)
+            .service(userpage::endpoints::get_graph_health_page)
+            .service(userpage::endpoints::get_property_validation_page)
             .service(journalpage::endpoints::get_journal)
             .service(journalpage::endpoints::update_journal)
             .service(journalpage::endpoints::journal_overview)
             .service(userpage::endpoints::get_page)
             .service(userpage::endpoints::update_page)
             .service(userpage::endpoints::get_backlinks)
+            .service(userpage::endpoints::get_unlinked_references)
+            .service(link_graph::endpoints::get_link_graph)
             .service(userpage::endpoints::delete_page)
             .service(userpage::endpoints::rename_page)
             .service(userpage::endpoints::append_page)
@@ -161,10 +175,13 @@ async fn main() -> std::io::Result<()> {
             .service(r#static::endpoints::emoji)
             .service(r#static::endpoints::asset_js)
             .service(r#static::endpoints::user_css)
+            .service(media::endpoints::thumbnail)
             .service(media::endpoints::assets)
             .service(media::endpoints::generate_assets_overview)
             .service(media::endpoints::get_asset_preview)
             .service(media::endpoints::get_metadata)
+            .service(media::endpoints::move_unused_media_to_trash)
+            .service(media::endpoints::rename_media_file)
             .service(templates::endpoints::list_all_templates)
             .service(templates::endpoints::insert_template_into_page)
             .service(search::endpoints::search_in_files)
@@ -173,6 +190,11 @@ async fn main() -> std::io::Result<()> {
             .service(kanban::endpoints::get_kanban)
             .service(kanban::endpoints::move_card)
             .service(block_properties::get_block_properties)
+            .service(block_properties::get_block_property_values)
+            .service(block_properties::rename_block_property)
+            .service(encryption::endpoints::get_encryption_status)
+            .service(encryption::endpoints::unlock_graph)
+            .service(encryption::endpoints::lock_graph)
             .service(sync::git::io::git_controller::get_current_git_status)
             .service(sync::git::io::git_controller::update_current_data)
             .service(sync::git::io::git_controller::post_create_checkpoint)
@@ -183,6 +205,10 @@ async fn main() -> std::io::Result<()> {
             .service(sync::git::io::git_controller::get_shutdown_status)
             .service(config::endpoints::get_journal_title_format)
             .service(config::endpoints::set_journal_title_format)
+            .service(config::endpoints::get_upload_policy)
+            .service(config::endpoints::set_upload_policy)
+            .service(config::endpoints::get_property_schema)
+            .service(config::endpoints::set_property_schema)
             .service(plot::endpoints::example_plot_svg)
             .service(favourites::endpoints::insert_page_favourite)
             .service(favourites::endpoints::delete_favourite_page)
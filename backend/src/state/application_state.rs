@@ -5,7 +5,9 @@ use crate::looksyk::data::config::runtime_graph_configuration::Config;
 use crate::looksyk::index::media::MediaIndex;
 use crate::state::asset_cache::AssetCache;
 use crate::state::block_properties::BlockPropertiesIndex;
+use crate::state::encryption::EncryptionState;
 use crate::state::journal::JournalPageIndex;
+use crate::state::page_properties::PagePropertiesIndex;
 use crate::state::tag::TagIndex;
 use crate::state::todo::TodoIndex;
 use crate::state::userpage::UserPageIndex;
@@ -21,6 +23,8 @@ pub struct AppState {
     pub f_media_index: Mutex<MediaIndex>,
     pub g_config: Mutex<Config>,
     pub h_block_properties: Mutex<BlockPropertiesIndex>,
+    pub i_page_properties: Mutex<PagePropertiesIndex>,
+    pub j_encryption: Mutex<EncryptionState>,
 }
 
 pub struct PureAppState {
@@ -33,6 +37,8 @@ pub struct PureAppState {
     pub f_media_index: MediaIndex,
     pub g_config: Config,
     pub h_block_properties: BlockPropertiesIndex,
+    pub i_page_properties: PagePropertiesIndex,
+    pub j_encryption: EncryptionState,
 }
 
 #[derive(Clone, Debug)]
@@ -58,6 +64,7 @@ pub struct CurrentPageAssociatedState<'a> {
     pub todo_index: &'a TodoIndex,
     pub tag_index: &'a TagIndex,
     pub block_properties_index: &'a BlockPropertiesIndex,
+    pub page_properties_index: &'a PagePropertiesIndex,
 }
 
 pub struct NewPageAssociatedState {
@@ -66,6 +73,7 @@ pub struct NewPageAssociatedState {
     pub todo_index: TodoIndex,
     pub tag_index: TagIndex,
     pub block_properties_index: BlockPropertiesIndex,
+    pub page_properties_index: PagePropertiesIndex,
 }
 
 pub struct CurrentPageOnDiskState<'a> {
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 
 pub struct AssetCache {
     asset_cache: HashMap<String, AssetState>,
+    text_cache: HashMap<String, AssetTextState>,
 }
 
 #[derive(Clone, PartialEq)]
@@ -19,6 +20,24 @@ pub enum AssetState {
     Found(AssetFileContent),
 }
 
+#[derive(Clone, PartialEq)]
+pub enum AssetTextState {
+    NotSearchable,
+    Extracted(ExtractedAssetText),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExtractedAssetText {
+    pub unit: AssetTextUnit,
+    pub segments: Vec<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AssetTextUnit {
+    Line,
+    Page,
+}
+
 #[derive(Clone, PartialEq)]
 pub struct FileSizeViolation {
     pub file_size: u64,
@@ -35,6 +54,7 @@ impl AssetCache {
     pub fn new() -> AssetCache {
         AssetCache {
             asset_cache: HashMap::new(),
+            text_cache: HashMap::new(),
         }
     }
 
@@ -50,4 +70,60 @@ impl AssetCache {
     pub fn insert(&mut self, file_name: &MediaOnDisk, asset_state: AssetState) {
         self.asset_cache.insert(file_name.name.clone(), asset_state);
     }
+
+    /// Texts are keyed by the sha3 hash of the file, a renamed or replaced file never
+    /// returns the text of another file.
+    pub fn has_text(&self, sha3: &str) -> bool {
+        self.text_cache.contains_key(sha3)
+    }
+
+    pub fn find_extracted_text(&self, sha3: &str) -> Option<&ExtractedAssetText> {
+        match self.text_cache.get(sha3) {
+            Some(AssetTextState::Extracted(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn insert_text(&mut self, sha3: &str, text_state: AssetTextState) {
+        self.text_cache.insert(sha3.to_string(), text_state);
+    }
+
+    /// Forgets the cached content of the file, e.g. after it was renamed, trashed or replaced.
+    pub fn remove(&mut self, media_on_disk: &MediaOnDisk) {
+        self.asset_cache.remove(media_on_disk.name.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_should_forget_content() {
+        let media = MediaOnDisk {
+            name: "notes.txt".to_string(),
+        };
+        let mut asset_cache = AssetCache::new();
+        asset_cache.insert(&media, AssetState::NotText);
+
+        asset_cache.remove(&media);
+
+        assert!(asset_cache.get(&media) == AssetState::Miss);
+    }
+
+    #[test]
+    fn test_text_should_be_keyed_by_hash() {
+        let mut asset_cache = AssetCache::new();
+        asset_cache.insert_text(
+            "old-hash",
+            AssetTextState::Extracted(ExtractedAssetText {
+                unit: AssetTextUnit::Line,
+                segments: vec!["old text".to_string()],
+            }),
+        );
+
+        assert!(asset_cache.has_text("old-hash"));
+        assert!(!asset_cache.has_text("new-hash"));
+        assert_eq!(asset_cache.find_extracted_text("new-hash"), None);
+    }
 }
@@ -1,21 +1,29 @@
+use crate::looksyk::model::SimplePageName;
 use crate::looksyk::parser::BlockProperty;
+use crate::looksyk::property_schema::{PropertySchema, PropertyValueError, TypedPropertyValue};
 use crate::state::block::BlockReference;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
 #[derive(Default)]
 pub struct BlockPropertiesIndex {
     pub entries: HashMap<BlockPropertyKey, Vec<BlockPropertyOccurence>>,
+    pub schema: PropertySchema,
+    /// Alias to the page declaring it, rebuilt whenever pages are added or removed.
+    pub aliases: HashMap<SimplePageName, SimplePageName>,
 }
 
-#[derive(Eq, PartialEq, Hash, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
 pub struct BlockPropertyKey {
     pub value: String,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct BlockPropertyOccurence {
     pub value: BlockPropertyValue,
+    pub typed_value: Result<TypedPropertyValue, PropertyValueError>,
     pub block: BlockReference,
+    pub is_page_property: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -23,43 +31,100 @@ pub struct BlockPropertyValue {
     pub value: String,
 }
 
+impl BlockPropertyOccurence {
+    /// Numeric value of the occurence. Values violating the schema have none.
+    pub fn numeric_value(&self) -> Option<f64> {
+        self.typed_value.as_ref().ok()?.as_number()
+    }
+}
+
 impl BlockPropertiesIndex {
     pub fn get_all_keys(&self) -> Vec<BlockPropertyKey> {
         self.entries.keys().cloned().collect()
     }
 
     pub fn append_elements(&mut self, block_reference: BlockReference, property: BlockProperty) {
+        self.append_occurence(block_reference, property, false);
+    }
+
+    pub fn append_page_property(
+        &mut self,
+        block_reference: BlockReference,
+        property: BlockProperty,
+    ) {
+        self.append_occurence(block_reference, property, true);
+    }
+
+    fn append_occurence(
+        &mut self,
+        block_reference: BlockReference,
+        property: BlockProperty,
+        is_page_property: bool,
+    ) {
+        let typed_value = self.schema.parse_value(&property.key, &property.value);
         let key = BlockPropertyKey {
             value: property.key,
         };
-
-        if let Some(value) = self.entries.get_mut(&key) {
-            value.push(BlockPropertyOccurence {
+        self.entries
+            .entry(key)
+            .or_default()
+            .push(BlockPropertyOccurence {
                 value: BlockPropertyValue {
                     value: property.value,
                 },
-                block: block_reference.clone(),
-            })
-        } else {
-            self.entries.insert(
-                key,
-                vec![BlockPropertyOccurence {
-                    value: BlockPropertyValue {
-                        value: property.value,
-                    },
-                    block: block_reference.clone(),
-                }],
-            );
+                typed_value,
+                block: block_reference,
+                is_page_property,
+            });
+    }
+
+    /// Replaces the schema and parses all indexed values again.
+    pub fn apply_schema(&mut self, schema: PropertySchema) {
+        for (key, occurences) in self.entries.iter_mut() {
+            for occurence in occurences.iter_mut() {
+                occurence.typed_value = schema.parse_value(&key.value, &occurence.value.value);
+            }
         }
+        self.schema = schema;
     }
 
     pub fn find(&self, key: &BlockPropertyKey) -> Option<&Vec<BlockPropertyOccurence>> {
         self.entries.get(key)
     }
+
+    /// Occurences on regular blocks. Page properties of a page's first block are left out,
+    /// they are served by the page properties index.
+    pub fn find_block_properties(
+        &self,
+        key: &BlockPropertyKey,
+    ) -> impl Iterator<Item = &BlockPropertyOccurence> {
+        self.find(key)
+            .into_iter()
+            .flatten()
+            .filter(|occurence| !occurence.is_page_property)
+    }
+
+    /// Block occurences whose value equals the given value, compared as typed values.
+    pub fn find_block_properties_with_value<'a>(
+        &'a self,
+        key: &BlockPropertyKey,
+        value: &BlockPropertyValue,
+    ) -> impl Iterator<Item = &'a BlockPropertyOccurence> {
+        let expected = self.schema.parse_value(&key.value, &value.value);
+        let value = value.clone();
+        self.find_block_properties(key).filter(move |occurence| {
+            match (&occurence.typed_value, &expected) {
+                (Ok(actual), Ok(expected)) => actual.compare(expected) == Some(Ordering::Equal),
+                _ => occurence.value == value,
+            }
+        })
+    }
 }
 
 #[cfg(test)]
 pub mod builder {
+    use crate::looksyk::index::alias::create_alias_index;
+    use crate::looksyk::property_schema::TypedPropertyValue;
     use crate::state::block::BlockReference;
     use crate::state::block_properties::{
         BlockPropertyKey, BlockPropertyOccurence, BlockPropertyValue,
@@ -77,7 +142,19 @@ pub mod builder {
     ) -> BlockPropertyOccurence {
         BlockPropertyOccurence {
             value: block_property_value(value),
+            typed_value: Ok(TypedPropertyValue::Text(value.to_string())),
             block: reference,
+            is_page_property: false,
+        }
+    }
+
+    pub fn page_property_occurance(
+        value: &str,
+        reference: BlockReference,
+    ) -> BlockPropertyOccurence {
+        BlockPropertyOccurence {
+            is_page_property: true,
+            ..block_property_occurance(value, reference)
         }
     }
 
@@ -91,9 +168,12 @@ pub mod builder {
         key: BlockPropertyKey,
         occurences: Vec<BlockPropertyOccurence>,
     ) -> super::BlockPropertiesIndex {
-        super::BlockPropertiesIndex {
+        let mut index = super::BlockPropertiesIndex {
             entries: vec![(key, occurences)].into_iter().collect(),
-        }
+            ..Default::default()
+        };
+        index.aliases = create_alias_index(&index);
+        index
     }
 }
 
@@ -101,13 +181,14 @@ pub mod builder {
 pub mod tests {
     use super::BlockPropertyKey;
     use crate::looksyk::builder::test_builder::user_page_id;
+    use crate::looksyk::parser::builder::block_property;
+    use crate::looksyk::property_schema::builder::property_schema_with;
+    use crate::looksyk::property_schema::PropertyType;
     use crate::state::block_properties::builder::{block_property_key, block_property_value};
 
     #[test]
     fn test_append_elements() {
-        let mut index = super::BlockPropertiesIndex {
-            entries: std::collections::HashMap::new(),
-        };
+        let mut index = super::BlockPropertiesIndex::default();
 
         let block_ref = super::BlockReference {
             page_id: user_page_id("testabc"),
@@ -155,9 +236,7 @@ pub mod tests {
 
     #[test]
     fn test_get_all_keys() {
-        let mut index = super::BlockPropertiesIndex {
-            entries: std::collections::HashMap::new(),
-        };
+        let mut index = super::BlockPropertiesIndex::default();
 
         index.entries.insert(block_property_key("priority"), vec![]);
 
@@ -168,4 +247,28 @@ pub mod tests {
         assert!(keys.contains(&block_property_key("priority")));
         assert!(keys.contains(&block_property_key("status")));
     }
+
+    #[test]
+    fn test_find_block_properties_with_value_should_compare_typed_values() {
+        let mut index = super::BlockPropertiesIndex::default();
+        index.apply_schema(property_schema_with("done", PropertyType::Boolean));
+        index.append_elements(
+            user_page_id("a").block_reference(1),
+            block_property("done", "Yes"),
+        );
+        index.append_elements(
+            user_page_id("b").block_reference(1),
+            block_property("done", "no"),
+        );
+
+        let result: Vec<_> = index
+            .find_block_properties_with_value(
+                &block_property_key("done"),
+                &block_property_value("true"),
+            )
+            .map(|occurence| occurence.block.clone())
+            .collect();
+
+        assert_eq!(result, vec![user_page_id("a").block_reference(1)]);
+    }
 }
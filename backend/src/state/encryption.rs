@@ -0,0 +1,24 @@
+#[derive(Clone)]
+pub struct EncryptionKey {
+    pub bytes: [u8; 32],
+}
+
+pub struct EncryptionState {
+    pub key: Option<EncryptionKey>,
+    pub encrypted_namespaces: Vec<String>,
+}
+
+impl EncryptionState {
+    pub fn is_locked(&self) -> bool {
+        self.key.is_none()
+    }
+}
+
+#[cfg(test)]
+pub mod builder {
+    use crate::state::encryption::EncryptionKey;
+
+    pub fn any_encryption_key() -> EncryptionKey {
+        EncryptionKey { bytes: [7; 32] }
+    }
+}
@@ -2,8 +2,10 @@ pub mod application_state;
 pub mod asset_cache;
 pub mod block;
 pub mod block_properties;
+pub mod encryption;
 pub mod journal;
 pub mod markdown_file;
+pub mod page_properties;
 pub mod tag;
 pub mod todo;
 pub mod userpage;
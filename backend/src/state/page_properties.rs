@@ -0,0 +1,127 @@
+use crate::looksyk::model::PageId;
+use crate::looksyk::parser::BlockProperty;
+use crate::looksyk::property_schema::{values_equal, PropertySchema};
+use crate::state::block_properties::{BlockPropertyKey, BlockPropertyValue};
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub struct PagePropertiesIndex {
+    pub entries: HashMap<BlockPropertyKey, Vec<PagePropertyOccurence>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PagePropertyOccurence {
+    pub value: BlockPropertyValue,
+    pub page_id: PageId,
+}
+
+impl PagePropertiesIndex {
+    pub fn append_elements(&mut self, page_id: PageId, property: BlockProperty) {
+        let key = BlockPropertyKey {
+            value: property.key,
+        };
+        self.entries
+            .entry(key)
+            .or_default()
+            .push(PagePropertyOccurence {
+                value: BlockPropertyValue {
+                    value: property.value,
+                },
+                page_id,
+            });
+    }
+
+    pub fn find(&self, key: &BlockPropertyKey) -> Option<&Vec<PagePropertyOccurence>> {
+        self.entries.get(key)
+    }
+
+    pub fn find_pages(
+        &self,
+        key: &BlockPropertyKey,
+        value: Option<&BlockPropertyValue>,
+        schema: &PropertySchema,
+    ) -> Vec<PageId> {
+        let mut result: Vec<PageId> = self
+            .find(key)
+            .map(|occurences| {
+                occurences
+                    .iter()
+                    .filter(|occurence| {
+                        value.is_none_or(|value| {
+                            values_equal(schema, &key.value, &occurence.value.value, &value.value)
+                        })
+                    })
+                    .map(|occurence| occurence.page_id.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        result.sort();
+        result.dedup();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::looksyk::builder::test_builder::{journal_page_id, user_page_id};
+    use crate::looksyk::parser::BlockProperty;
+    use crate::looksyk::property_schema::builder::property_schema_with;
+    use crate::looksyk::property_schema::{PropertySchema, PropertyType};
+    use crate::state::block_properties::builder::{block_property_key, block_property_value};
+    use crate::state::page_properties::PagePropertiesIndex;
+
+    #[test]
+    fn test_find_pages_should_filter_by_value() {
+        let mut index = PagePropertiesIndex::default();
+        index.append_elements(user_page_id("b"), property("type", "project"));
+        index.append_elements(user_page_id("a"), property("type", "project"));
+        index.append_elements(journal_page_id("c"), property("type", "person"));
+
+        assert_eq!(
+            index.find_pages(
+                &block_property_key("type"),
+                None,
+                &PropertySchema::default()
+            ),
+            vec![user_page_id("a"), user_page_id("b"), journal_page_id("c")]
+        );
+        assert_eq!(
+            index.find_pages(
+                &block_property_key("type"),
+                Some(&block_property_value("project")),
+                &PropertySchema::default()
+            ),
+            vec![user_page_id("a"), user_page_id("b")]
+        );
+        assert!(index
+            .find_pages(
+                &block_property_key("status"),
+                None,
+                &PropertySchema::default()
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn test_find_pages_should_compare_typed_values() {
+        let mut index = PagePropertiesIndex::default();
+        index.append_elements(user_page_id("a"), property("done", "Yes"));
+        index.append_elements(user_page_id("b"), property("done", "no"));
+
+        assert_eq!(
+            index.find_pages(
+                &block_property_key("done"),
+                Some(&block_property_value("true")),
+                &property_schema_with("done", PropertyType::Boolean)
+            ),
+            vec![user_page_id("a")]
+        );
+    }
+
+    fn property(key: &str, value: &str) -> BlockProperty {
+        BlockProperty {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
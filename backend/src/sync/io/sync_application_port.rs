@@ -50,6 +50,9 @@ pub enum GraphChangeType {
     UserPageDeleted,
     GraphUpdated,
     MediaAdded,
+    MediaMovedToTrash,
+    MediaRenamed,
+    PropertyRenamed,
     ConfigChanged,
 }
 
@@ -62,6 +65,9 @@ impl GraphChangeType {
             GraphChangeType::UserPageDeleted => "Page deleted".to_string(),
             GraphChangeType::GraphUpdated => "Graph updated".to_string(),
             GraphChangeType::MediaAdded => "Media added".to_string(),
+            GraphChangeType::MediaMovedToTrash => "Media moved to trash".to_string(),
+            GraphChangeType::MediaRenamed => "Media renamed".to_string(),
+            GraphChangeType::PropertyRenamed => "Property renamed".to_string(),
             GraphChangeType::ConfigChanged => "Configuration changed".to_string(),
         }
     }
@@ -90,6 +96,27 @@ impl GraphChange {
         }
     }
 
+    pub fn media_moved_to_trash(target: String) -> Self {
+        GraphChange {
+            change_type: GraphChangeType::MediaMovedToTrash,
+            target,
+        }
+    }
+
+    pub fn media_renamed(old_name: String, new_name: String) -> Self {
+        GraphChange {
+            change_type: GraphChangeType::MediaRenamed,
+            target: format!("{} -> {}", old_name, new_name),
+        }
+    }
+
+    pub fn property_renamed(old_property: String, new_property: String) -> Self {
+        GraphChange {
+            change_type: GraphChangeType::PropertyRenamed,
+            target: format!("{} -> {}", old_property, new_property),
+        }
+    }
+
     pub fn journal_page_changed(target: String) -> Self {
         GraphChange {
             change_type: GraphChangeType::JournalPageChanged,